@@ -0,0 +1,81 @@
+//! Checks that `calendar_duration` builds and works without `std` or `alloc`, with the `time`
+//! implementation.
+//!
+//! Build it on its own, with `cargo build -p no_std_check` or `cargo test -p no_std_check`: in a
+//! workspace build, the root crate's default features are enabled for every package.
+
+#![no_std]
+
+use calendar_duration::{CalendarDuration, CalendarDurationExt};
+use core::fmt::{self, Write};
+use time::Date;
+
+/// A fixed-size buffer to format into, since there's no `String`.
+pub struct Buffer {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl Buffer {
+    /// Make an empty buffer.
+    pub fn new() -> Self {
+        Self { bytes: [0; 64], len: 0 }
+    }
+
+    /// Return what has been written so far.
+    pub fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.bytes[.. self.len]).unwrap()
+    }
+}
+
+impl Default for Buffer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        self.bytes.get_mut(self.len .. end).ok_or(fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Compute the duration between two dates and write it in English and in ISO 8601 form.
+pub fn describe(a: Date, b: Date, out: &mut Buffer) -> Option<CalendarDuration> {
+    let dur = a.try_calendar_duration_from(b).ok()?;
+    write!(out, "{dur} ({dur:#})").ok()?;
+    Some(dur)
+}
+
+/// Add a month to the date.
+pub fn next_month(date: Date) -> Option<Date> {
+    date.add_calendar_duration(&CalendarDuration::new(0, 1, 0))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::Month;
+
+    fn date(y: i32, m: Month, d: u8) -> Date {
+        Date::from_calendar_date(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn describe_duration() {
+        let mut out = Buffer::new();
+        let dur = describe(date(2020, Month::May, 9), date(1988, Month::July, 16), &mut out);
+        assert_eq!(Some(CalendarDuration::new(31, 9, 23)), dur);
+        assert_eq!("31 years, 9 months, 23 days (P31Y9M23D)", out.as_str());
+    }
+
+    #[test]
+    fn add_month() {
+        let date = date(2024, Month::January, 15);
+        assert_eq!(Some(date.replace_month(Month::February).unwrap()), next_month(date));
+        assert_eq!(None, next_month(Date::MAX));
+    }
+}
@@ -0,0 +1,284 @@
+use crate::{CalendarDuration, CalendarDurationExt};
+
+impl CalendarDuration {
+    /// Return the number of days from the start date to the date this duration after it, which
+    /// is the absolute length of the duration from that date.
+    ///
+    /// The duration is added with
+    /// [`add_calendar_duration`](CalendarDurationExt::add_calendar_duration), which resolves
+    /// days past the end of a month the same way as
+    /// [`calendar_duration_from`](CalendarDurationExt::calendar_duration_from), so for any two
+    /// dates `a` before `b`, `b.calendar_duration_from(a).total_days_from(a)` is the number of
+    /// days from `a` to `b`.
+    ///
+    /// Panics if the end date is out of the range of the date type. See
+    /// [`checked_total_days_from`](Self::checked_total_days_from) for a version which doesn't.
+    #[cfg(not(feature = "strict"))]
+    pub fn total_days_from<D: CalendarDurationExt>(&self, start: D) -> i64 {
+        self.checked_total_days_from(start).expect("date out of range")
+    }
+
+    /// Return the number of days from the start date to the date this duration after it, as
+    /// with [`total_days_from`](Self::total_days_from), or `None` if that date is out of the
+    /// range of the date type.
+    pub fn checked_total_days_from<D: CalendarDurationExt>(&self, start: D) -> Option<i64> {
+        Some(start.add_calendar_duration(self)?.days_since(start))
+    }
+
+    /// Return the normalized duration from the anchor date to the date this duration after it,
+    /// with fewer than 12 months and fewer days than are in a month, like a duration computed
+    /// from two dates. For example, 18 months is 1 year and 6 months from any date, and 45 days
+    /// from January 31st, 2020 is March 16th, which is 1 month and 15 days after it, since a
+    /// month after January 31st is March 1st.
+    ///
+    /// Days are converted to months only as far as the months from the anchor date go, so 31 days
+    /// is 1 month from January 1st, but 1 month and 3 days from February 1st in a common year.
+    ///
+    /// Panics if the end date is out of the range of the date type. See
+    /// [`checked_normalize_at`](Self::checked_normalize_at) for a version which doesn't.
+    #[cfg(not(feature = "strict"))]
+    pub fn normalize_at<D: CalendarDurationExt>(&self, anchor: D) -> CalendarDuration {
+        self.checked_normalize_at(anchor).expect("date out of range")
+    }
+
+    /// Return the normalized duration from the anchor date, as with
+    /// [`normalize_at`](Self::normalize_at), or `None` if the date this duration after the
+    /// anchor is out of the range of the date type.
+    pub fn checked_normalize_at<D: CalendarDurationExt>(&self, anchor: D)
+        -> Option<CalendarDuration>
+    {
+        let end = anchor.add_calendar_duration(self)?;
+        end.try_calendar_duration_from(anchor).ok()
+    }
+
+    /// Round the duration to a whole number of months, measured from the anchor date.
+    ///
+    /// The duration is first normalized from the anchor, as with
+    /// [`normalize_at`](Self::normalize_at). The days are then rounded half up: they round up to
+    /// another month if they are at least half the length of the month they are in, from the
+    /// date the whole months reach to the date one more month reaches. So from January 15th, 2023,
+    /// 1 month and 14 days rounds up, since February 15th to March 15th is 28 days, but from
+    /// January 15th, 2024, it rounds down, since that month is 29 days. A rounded-up 12th month
+    /// carries into the years.
+    ///
+    /// Panics if a date needed is out of the range of the date type. See
+    /// [`checked_round_to_months_at`](Self::checked_round_to_months_at) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    pub fn round_to_months_at<D: CalendarDurationExt>(&self, anchor: D) -> CalendarDuration {
+        self.checked_round_to_months_at(anchor).expect("date out of range")
+    }
+
+    /// Round the duration to a whole number of months, measured from the anchor date, as with
+    /// [`round_to_months_at`](Self::round_to_months_at), or return `None` if a date needed is
+    /// out of the range of the date type.
+    pub fn checked_round_to_months_at<D: CalendarDurationExt>(&self, anchor: D)
+        -> Option<CalendarDuration>
+    {
+        let dur = self.checked_normalize_at(anchor)?;
+        let whole = CalendarDuration { days: 0, ..dur };
+        let mut rounded = whole.clone();
+        if dur.days > 0 {
+            let start = anchor.add_calendar_duration(&whole)?;
+            let next = whole.checked_add(&CalendarDuration::new(0, 1, 0))?;
+            let month = anchor.add_calendar_duration(&next)?.days_since(start);
+            if 2 * i64::from(dur.days) >= month {
+                rounded = next;
+            }
+        }
+        Some(rounded)
+    }
+
+    /// Round the duration to a whole number of years, measured from the anchor date.
+    ///
+    /// The duration is first normalized from the anchor, as with
+    /// [`normalize_at`](Self::normalize_at). The months and days are then rounded half up: they
+    /// round up to another year if they are at least half the length of the year they are in,
+    /// in days, from the date the whole years reach to the date one more year reaches. So from
+    /// January 1st, 6 months rounds down, since January through June is 181 or 182 days, but
+    /// from July 1st it rounds up, since July through December is 184 days. 11 months and 20
+    /// days rounds up to 1 year from any date.
+    ///
+    /// Panics if a date needed is out of the range of the date type. See
+    /// [`checked_round_to_years_at`](Self::checked_round_to_years_at) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    pub fn round_to_years_at<D: CalendarDurationExt>(&self, anchor: D) -> CalendarDuration {
+        self.checked_round_to_years_at(anchor).expect("date out of range")
+    }
+
+    /// Round the duration to a whole number of years, measured from the anchor date, as with
+    /// [`round_to_years_at`](Self::round_to_years_at), or return `None` if a date needed is out
+    /// of the range of the date type.
+    pub fn checked_round_to_years_at<D: CalendarDurationExt>(&self, anchor: D)
+        -> Option<CalendarDuration>
+    {
+        let dur = self.checked_normalize_at(anchor)?;
+        let whole = CalendarDuration::new(dur.years, 0, 0);
+        let mut rounded = whole.clone();
+        if dur.months > 0 || dur.days > 0 {
+            let start = anchor.add_calendar_duration(&whole)?;
+            let elapsed = anchor.add_calendar_duration(&dur)?.days_since(start);
+            let next = CalendarDuration::new(dur.years.checked_add(1)?, 0, 0);
+            let year = anchor.add_calendar_duration(&next)?.days_since(start);
+            if 2 * elapsed >= year {
+                rounded = next;
+            }
+        }
+        Some(rounded)
+    }
+
+    /// Return the absolute length of the duration from the start date, as with
+    /// [`total_days_from`](Self::total_days_from).
+    ///
+    /// Panics if the end date is out of the range of `NaiveDate`.
+    #[cfg(all(feature = "chrono", not(feature = "strict")))]
+    pub fn to_chrono_duration_from(&self, start: chrono::NaiveDate) -> chrono::Duration {
+        chrono::Duration::days(self.total_days_from(start))
+    }
+
+    /// Return the absolute length of the duration from the start date, as with
+    /// [`total_days_from`](Self::total_days_from).
+    ///
+    /// Panics if the end date is out of the range of `time::Date`.
+    #[cfg(all(feature = "time", not(feature = "strict")))]
+    pub fn to_time_duration_from(&self, start: time::Date) -> time::Duration {
+        time::Duration::days(self.total_days_from(start))
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::PlainDate;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    /// Call the function with 1000 random pairs of day numbers within 200 years of 1970, earlier
+    /// first.
+    fn random_pairs(mut f: impl FnMut(i64, i64)) {
+        // A simple xorshift generator, so the test is repeatable.
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % 146_097) as i64 - 73_048
+        };
+        for _ in 0 .. 1000 {
+            let (a, b) = (random(), random());
+            f(a.min(b), a.max(b));
+        }
+    }
+
+    #[test]
+    fn total_days() {
+        let date = |y, m, d| PlainDate::new(y, m, d).unwrap();
+        assert_eq!(28, dur(0, 1, 0).total_days_from(date(2021, 2, 1)));
+        assert_eq!(31, dur(0, 1, 0).total_days_from(date(2021, 3, 1)));
+        assert_eq!(366, dur(1, 0, 0).total_days_from(date(2020, 1, 1)));
+        assert_eq!(0, CalendarDuration::ZERO.total_days_from(date(2020, 1, 1)));
+        // January 31st plus a month is March 1st.
+        assert_eq!(29, dur(0, 1, 0).total_days_from(date(2021, 1, 31)));
+        assert_eq!(None, dur(0, 0, 1).checked_total_days_from(PlainDate::max_value()));
+    }
+
+    #[test]
+    fn normalize() {
+        let date = |y, m, d| PlainDate::new(y, m, d).unwrap();
+        assert_eq!(dur(1, 6, 0), dur(0, 18, 0).normalize_at(date(2020, 1, 15)));
+        assert_eq!("1 year, 6 months", dur(0, 18, 0).normalize_at(date(2020, 1, 15)).to_string());
+        assert_eq!(dur(0, 1, 15), dur(0, 0, 45).normalize_at(date(2020, 1, 31)));
+        assert_eq!(dur(0, 1, 0), dur(0, 0, 31).normalize_at(date(2021, 1, 1)));
+        assert_eq!(dur(0, 1, 3), dur(0, 0, 31).normalize_at(date(2021, 2, 1)));
+        assert_eq!(dur(1, 1, 3), dur(0, 0, 400).normalize_at(date(2020, 1, 1)));
+        assert_eq!(dur(2, 1, 0), dur(1, 13, 0).normalize_at(date(2020, 1, 1)));
+        assert_eq!(dur(31, 9, 23), dur(31, 9, 23).normalize_at(date(1988, 6, 16)));
+        assert_eq!(CalendarDuration::ZERO, CalendarDuration::ZERO.normalize_at(date(2020, 1, 1)));
+        assert_eq!(dur(0, 1, 0), dur(0, 1, 0).normalize_at(date(2020, 1, 31)));
+
+        assert_eq!(None, dur(0, 18, 0).checked_normalize_at(PlainDate::max_value()));
+    }
+
+    #[test]
+    fn round_to_months() {
+        let date = |y, m, d| PlainDate::new(y, m, d).unwrap();
+        // February 15th to March 15th is 28 days in 2023, and 29 in 2024.
+        let common = date(2023, 1, 15);
+        let leap = date(2024, 1, 15);
+        assert_eq!(dur(0, 1, 0), dur(0, 1, 13).round_to_months_at(common));
+        assert_eq!(dur(0, 2, 0), dur(0, 1, 14).round_to_months_at(common));
+        assert_eq!(dur(0, 1, 0), dur(0, 1, 14).round_to_months_at(leap));
+        assert_eq!(dur(0, 2, 0), dur(0, 1, 15).round_to_months_at(leap));
+        // Half of a 31-day month is 15.5 days.
+        assert_eq!(dur(0, 0, 0), dur(0, 0, 15).round_to_months_at(date(2023, 3, 1)));
+        assert_eq!(dur(0, 1, 0), dur(0, 0, 16).round_to_months_at(date(2023, 3, 1)));
+
+        assert_eq!(dur(1, 0, 0), dur(0, 11, 20).round_to_months_at(date(2023, 1, 1)));
+        assert_eq!(dur(32, 10, 0), dur(32, 9, 23).round_to_months_at(date(1988, 6, 16)));
+        assert_eq!(dur(2, 0, 0), dur(0, 23, 29).round_to_months_at(date(2023, 1, 1)));
+        assert_eq!(dur(1, 3, 0), dur(1, 3, 0).round_to_months_at(date(2023, 1, 1)));
+        assert_eq!(CalendarDuration::ZERO, CalendarDuration::ZERO.round_to_months_at(common));
+        assert_eq!(None, dur(0, 0, 20).checked_round_to_months_at(PlainDate::max_value()));
+    }
+
+    #[test]
+    fn round_to_years() {
+        let date = |y, m, d| PlainDate::new(y, m, d).unwrap();
+        assert_eq!(dur(1, 0, 0), dur(0, 11, 20).round_to_years_at(date(2023, 1, 1)));
+        assert_eq!(dur(32, 0, 0), dur(31, 9, 23).round_to_years_at(date(1988, 6, 16)));
+        assert_eq!(dur(31, 0, 0), dur(31, 5, 0).round_to_years_at(date(1988, 6, 16)));
+        assert_eq!(dur(3, 0, 0), dur(3, 0, 0).round_to_years_at(date(1988, 6, 16)));
+        assert_eq!(dur(1, 0, 0), dur(0, 6, 0).round_to_years_at(date(2023, 7, 1)));
+
+        // Half of 2023 is 182.5 days, and January 1st to July 2nd is 182 days, but half of 2024 is
+        // 183 days, which is January 1st to July 2nd in a leap year.
+        assert_eq!(dur(0, 0, 0), dur(0, 6, 1).round_to_years_at(date(2023, 1, 1)));
+        assert_eq!(dur(1, 0, 0), dur(0, 6, 2).round_to_years_at(date(2023, 1, 1)));
+        assert_eq!(dur(1, 0, 0), dur(0, 6, 1).round_to_years_at(date(2024, 1, 1)));
+        assert_eq!(dur(0, 0, 0), dur(0, 6, 0).round_to_years_at(date(2024, 1, 1)));
+        // From a leap day, the year is 366 days, to March 1st.
+        assert_eq!(dur(0, 0, 0), dur(0, 0, 182).round_to_years_at(date(2024, 2, 29)));
+        assert_eq!(dur(1, 0, 0), dur(0, 0, 183).round_to_years_at(date(2024, 2, 29)));
+        assert_eq!(None, dur(1, 0, 0).checked_round_to_years_at(PlainDate::max_value()));
+    }
+
+    #[test]
+    fn plain_round_trip() {
+        random_pairs(|a, b| {
+            let a = PlainDate::from_epoch_day(a).unwrap();
+            let b = PlainDate::from_epoch_day(b).unwrap();
+            assert_eq!(b.days_since(a), b.calendar_duration_from(a).total_days_from(a),
+                "{a} to {b}");
+        });
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_round_trip() {
+        let epoch = chrono::NaiveDate::from_ymd_opt(1970, 1, 1).unwrap();
+        random_pairs(|a, b| {
+            let a = epoch + chrono::Duration::days(a);
+            let b = epoch + chrono::Duration::days(b);
+            let dur = b.calendar_duration_from(a);
+            assert_eq!((b - a).num_days(), dur.total_days_from(a), "{a} to {b}");
+            assert_eq!(b - a, dur.to_chrono_duration_from(a), "{a} to {b}");
+        });
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_round_trip() {
+        let epoch = time::Date::from_calendar_date(1970, time::Month::January, 1).unwrap();
+        random_pairs(|a, b| {
+            let a = epoch + time::Duration::days(a);
+            let b = epoch + time::Duration::days(b);
+            let dur = b.calendar_duration_from(a);
+            assert_eq!((b - a).whole_days(), dur.total_days_from(a), "{a} to {b}");
+            assert_eq!(b - a, dur.to_time_duration_from(a), "{a} to {b}");
+        });
+    }
+}
@@ -0,0 +1,143 @@
+use crate::{duration_between, CalendarDuration, CalendarDurationExt};
+
+/// The period for which a fixed amount accrues. See [`CalendarDurationExt::accrued`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AccrualPeriod {
+    /// Each completed month since the start date, counting months the same way
+    /// [`calendar_duration_from`] does.
+    ///
+    /// [`calendar_duration_from`]: CalendarDurationExt::calendar_duration_from
+    CompletedMonth,
+
+    /// Each completed year since the start date.
+    CompletedYear,
+}
+
+impl AccrualPeriod {
+    fn duration(self, n: u64) -> Option<CalendarDuration> {
+        let n = u32::try_from(n).ok()?;
+        Some(match self {
+            AccrualPeriod::CompletedMonth => CalendarDuration { years: 0, months: n, days: 0 },
+            AccrualPeriod::CompletedYear => CalendarDuration { years: n, months: 0, days: 0 },
+        })
+    }
+}
+
+/// Return the number of completed periods between the dates.
+fn completed<D: CalendarDurationExt>(start: D, as_of: D, per: AccrualPeriod) -> u64 {
+    if as_of <= start {
+        return 0;
+    }
+    let dur = duration_between(as_of, start);
+    match per {
+        AccrualPeriod::CompletedMonth => {
+            // Each whole year has as many months as the year it starts in.
+            let y = i64::from(start.ymd().0);
+            (y .. y + i64::from(dur.years))
+                .map(|y| u64::from(D::months_in_year(y as i32)))
+                .sum::<u64>() + u64::from(dur.months)
+        }
+        AccrualPeriod::CompletedYear => u64::from(dur.years),
+    }
+}
+
+fn capped(amount: f64, cap: Option<f64>) -> f64 {
+    match cap {
+        Some(cap) => amount.min(cap),
+        None => amount,
+    }
+}
+
+pub(crate) fn accrued<D: CalendarDurationExt>(
+    start: D,
+    as_of: D,
+    per: AccrualPeriod,
+    rate: f64,
+    cap: Option<f64>,
+) -> f64 {
+    capped(completed(start, as_of, per) as f64 * rate, cap)
+}
+
+pub(crate) fn accrued_prorated<D: CalendarDurationExt>(
+    start: D,
+    as_of: D,
+    per: AccrualPeriod,
+    rate: f64,
+    cap: Option<f64>,
+) -> f64 {
+    let n = completed(start, as_of, per);
+    let mut periods = n as f64;
+    if as_of > start {
+        // The current period runs from the anniversary of the start date for the completed periods
+        // to the next one, so its length depends on the months it spans.
+        let bounds = per.duration(n)
+            .zip(per.duration(n + 1))
+            .and_then(|(a, b)| Some((
+                start.add_calendar_duration(&a)?,
+                start.add_calendar_duration(&b)?,
+            )));
+        if let Some((period_start, period_end)) = bounds {
+            let elapsed = as_of.to_day_number() - period_start.to_day_number();
+            let length = period_end.to_day_number() - period_start.to_day_number();
+            if length > 0 {
+                periods += elapsed as f64 / length as f64;
+            }
+        }
+    }
+    capped(periods * rate, cap)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn completed_months() {
+        let start = date(2024, 1, 15);
+        let month = AccrualPeriod::CompletedMonth;
+        assert_eq!(0., start.accrued(date(2024, 2, 14), month, 1.25, None));
+        assert_eq!(1.25, start.accrued(date(2024, 2, 15), month, 1.25, None));
+        assert_eq!(15., start.accrued(date(2025, 1, 15), month, 1.25, None));
+        assert_eq!(0., start.accrued(date(2023, 1, 15), month, 1.25, None));
+    }
+
+    #[test]
+    fn completed_years() {
+        let start = date(2020, 2, 29);
+        let year = AccrualPeriod::CompletedYear;
+        assert_eq!(0., start.accrued(date(2021, 2, 28), year, 5., None));
+        assert_eq!(5., start.accrued(date(2021, 3, 1), year, 5., None));
+        assert_eq!(20., start.accrued(date(2024, 2, 29), year, 5., None));
+    }
+
+    #[test]
+    fn cap() {
+        let start = date(2024, 1, 15);
+        let month = AccrualPeriod::CompletedMonth;
+        assert_eq!(10., start.accrued(date(2024, 9, 15), month, 1.25, Some(10.)));
+        assert_eq!(10., start.accrued(date(2026, 1, 1), month, 1.25, Some(10.)));
+        assert_eq!(8.75, start.accrued(date(2024, 8, 15), month, 1.25, Some(10.)));
+        assert_eq!(10., start.accrued_prorated(date(2024, 9, 20), month, 1.25, Some(10.)));
+    }
+
+    #[test]
+    fn prorated() {
+        let month = AccrualPeriod::CompletedMonth;
+        // February 2023 has 28 days, so half of it is 14 days.
+        let start = date(2023, 2, 1);
+        assert_eq!(0.5, start.accrued_prorated(date(2023, 2, 15), month, 1., None));
+        assert_eq!(1., start.accrued_prorated(date(2023, 3, 1), month, 1., None));
+        // March has 31 days.
+        assert_eq!(1. + 3. / 31., start.accrued_prorated(date(2023, 3, 4), month, 1., None));
+
+        // 2024 is a leap year.
+        let start = date(2024, 1, 1);
+        assert_eq!(1.5 * 183. / 366., start.accrued_prorated(date(2024, 7, 2),
+            AccrualPeriod::CompletedYear, 1.5, None));
+    }
+}
@@ -0,0 +1,115 @@
+use crate::{duration_between, CalendarDuration, HijriDate, PlainDate};
+
+/// A date in any calendar which can be converted to a count of days, so that dates in different
+/// calendars can be compared. See [`calendar_duration_across`].
+pub trait EpochDay: Copy {
+    /// Return the number of days from 1970-01-01 (in the proleptic Gregorian calendar) to the
+    /// date. Dates before then give negative numbers.
+    fn epoch_day(self) -> i64;
+}
+
+impl EpochDay for PlainDate {
+    fn epoch_day(self) -> i64 {
+        self.to_epoch_day()
+    }
+}
+
+impl EpochDay for HijriDate {
+    fn epoch_day(self) -> i64 {
+        self.to_epoch_day()
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl EpochDay for chrono::NaiveDate {
+    fn epoch_day(self) -> i64 {
+        crate::CalendarDurationExt::to_day_number(self)
+    }
+}
+
+#[cfg(feature = "time")]
+impl EpochDay for time::Date {
+    fn epoch_day(self) -> i64 {
+        crate::CalendarDurationExt::to_day_number(self)
+    }
+}
+
+/// The calendar in which [`calendar_duration_across`] measures a duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarChoice {
+    /// The proleptic Gregorian calendar.
+    Gregorian,
+
+    /// The tabular Islamic calendar of [`HijriDate`].
+    Hijri,
+}
+
+/// Compute the calendar duration between two dates which may be in different calendars, by
+/// converting both to the chosen calendar.
+///
+/// The answer depends on the calendar chosen: months and years have different lengths in
+/// different calendars, so the same two days can be a different number of months and days apart.
+/// The total number of days is the same in any calendar.
+///
+/// Returns `None` if either date can't be represented in the chosen calendar.
+pub fn calendar_duration_across<A: EpochDay, B: EpochDay>(
+    a: A,
+    b: B,
+    compute_in: CalendarChoice,
+) -> Option<CalendarDuration> {
+    let (a, b) = (a.epoch_day(), b.epoch_day());
+    match compute_in {
+        CalendarChoice::Gregorian => {
+            Some(duration_between(PlainDate::from_epoch_day(a)?, PlainDate::from_epoch_day(b)?))
+        }
+        CalendarChoice::Hijri => {
+            Some(duration_between(HijriDate::from_epoch_day(a)?, HijriDate::from_epoch_day(b)?))
+        }
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use crate::CalendarDurationExt;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn ramadan() {
+        // 1 Ramadan 1444 to 1 Shawwal 1444 is a month in the Hijri calendar, but 30 days in the
+        // Gregorian calendar.
+        let start = HijriDate::new(1444, 9, 1).unwrap();
+        let end = date(2023, 4, 22);
+        assert_eq!(HijriDate::new(1444, 10, 1), HijriDate::from_epoch_day(end.epoch_day()));
+        assert_eq!(Some(dur(0, 0, 30)),
+            calendar_duration_across(start, end, CalendarChoice::Gregorian));
+        assert_eq!(Some(dur(0, 1, 0)),
+            calendar_duration_across(start, end, CalendarChoice::Hijri));
+    }
+
+    #[test]
+    fn years() {
+        // A Hijri year is about 11 days shorter than a Gregorian one.
+        let a = date(2000, 1, 1);
+        let b = date(2010, 1, 1);
+        assert_eq!(Some(dur(10, 0, 0)), calendar_duration_across(a, b, CalendarChoice::Gregorian));
+        assert_eq!(Some(dur(10, 3, 20)), calendar_duration_across(a, b, CalendarChoice::Hijri));
+        assert_eq!(Some(dur(10, 0, 0)), calendar_duration_across(b, a, CalendarChoice::Gregorian));
+    }
+
+    #[test]
+    fn same_calendar() {
+        let a = date(1988, 6, 16);
+        let b = date(2020, 4, 8);
+        assert_eq!(b.try_calendar_duration_from(a).ok(),
+            calendar_duration_across(a, b, CalendarChoice::Gregorian));
+    }
+}
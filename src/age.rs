@@ -0,0 +1,259 @@
+use crate::overflow::duration_from;
+use crate::{
+    duration_between, AddOptions, CalendarDuration, CalendarDurationExt, OverflowPolicy, Years,
+};
+
+/// A person's age on a date by two different reckonings, for display side by side. See
+/// [`CalendarDurationExt::age_report`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct AgeReport {
+    /// The age in whole years since birth, as given by [`CalendarDurationExt::age_on`].
+    pub international: u32,
+
+    /// The East Asian age, as given by [`CalendarDurationExt::east_asian_age`].
+    pub east_asian: u32,
+}
+
+/// When an anniversary of February 29th is observed in common years. See
+/// [`CalendarDurationExt::anniversary_span`] and [`CalendarDurationExt::has_elapsed`].
+///
+/// For durations with months, the same applies to other days which don't exist in a month: with
+/// [`February28`](Self::February28), a month after January 31st is the last day of February.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum LeapDayPolicy {
+    /// On March 1st, the day after February 28th, the same as [`CalendarDurationExt::age_on`].
+    #[default]
+    March1,
+
+    /// On February 28th, the last day of February.
+    February28,
+}
+
+impl LeapDayPolicy {
+    fn overflow(self) -> OverflowPolicy {
+        match self {
+            LeapDayPolicy::March1 => OverflowPolicy::NextValid,
+            LeapDayPolicy::February28 => OverflowPolicy::ClampToEndOfMonth,
+        }
+    }
+}
+
+pub(crate) fn age_on<D: CalendarDurationExt>(birth: D, as_of: D) -> u32 {
+    if as_of < birth {
+        return 0;
+    }
+    duration_between(as_of, birth).years
+}
+
+pub(crate) fn east_asian_age<D: CalendarDurationExt>(birth: D, as_of: D) -> u32 {
+    if as_of < birth {
+        return 0;
+    }
+    let (birth_year, _, _) = birth.ymd();
+    let (year, _, _) = as_of.ymd();
+    // Dates are in order, so the difference is not negative and fits in a u32.
+    (i64::from(year) - i64::from(birth_year) + 1) as u32
+}
+
+/// Return the day number of the latest birth date for which the age on the given date is at least
+/// the given number of years.
+fn latest_birth_with_age<D: CalendarDurationExt>(years: u32, as_of: D) -> Option<i64> {
+    let (y, m, d) = as_of.ymd();
+    let y = i32::try_from(i64::from(y) - i64::from(years)).ok()?;
+    let mut day = D::checked_from_ymd_or_next(y, m, d)?.to_day_number();
+    // Age is monotonic in the birth date, and the estimate is within a day or two of the answer.
+    let age = |day: i64| D::from_day_number(day).map(|birth| age_on(birth, as_of));
+    while age(day + 1)? >= years {
+        day += 1;
+    }
+    while age(day)? < years {
+        day -= 1;
+    }
+    Some(day)
+}
+
+pub(crate) fn birthdate_range_for_age<D: CalendarDurationExt>(years: u32, as_of: D)
+    -> Option<(D, D)>
+{
+    let latest = if years == 0 {
+        as_of.to_day_number()
+    } else {
+        latest_birth_with_age(years, as_of)?
+    };
+    let earliest = latest_birth_with_age(years.checked_add(1)?, as_of)? + 1;
+    Some((D::from_day_number(earliest)?, D::from_day_number(latest)?))
+}
+
+pub(crate) fn has_elapsed<D: CalendarDurationExt>(
+    start: D,
+    required: &CalendarDuration,
+    as_of: D,
+    policy: LeapDayPolicy,
+) -> bool {
+    let options = AddOptions::new().overflow_policy(policy.overflow());
+    start.add_calendar_duration_with(required, &options).is_some_and(|reached| reached <= as_of)
+}
+
+/// Return the number of anniversaries of the original date on or before the given date, not
+/// counting the original date itself.
+fn anniversaries<D: CalendarDurationExt>(original: D, as_of: D, policy: LeapDayPolicy)
+    -> Option<u32>
+{
+    Some(duration_from(original, as_of, policy.overflow()).ok()?.years)
+}
+
+pub(crate) fn next_anniversary<D: CalendarDurationExt>(
+    original: D,
+    as_of: D,
+    policy: LeapDayPolicy,
+) -> Option<D> {
+    if as_of < original {
+        return Some(original);
+    }
+    let years = anniversaries(original, as_of, policy)?.checked_add(1)?;
+    original.add_years_with(Years(years), policy.overflow())
+}
+
+#[cfg(not(feature = "strict"))]
+pub(crate) fn anniversary_span<D: CalendarDurationExt>(
+    original: D,
+    as_of: D,
+    policy: LeapDayPolicy,
+) -> Option<(CalendarDuration, CalendarDuration)> {
+    let next = next_anniversary(original, as_of, policy)?;
+    let last = if as_of < original {
+        as_of
+    } else {
+        let years = anniversaries(original, as_of, policy)?;
+        original.add_years_with(Years(years), policy.overflow())?
+    };
+    Some((
+        duration_from(last, as_of, policy.overflow()).ok()?,
+        duration_from(as_of, next, policy.overflow()).ok()?,
+    ))
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn born_december_31() {
+        let birth = date(2023, 12, 31);
+        assert_eq!(1, birth.east_asian_age(birth));
+        assert_eq!(2, birth.east_asian_age(date(2024, 1, 1)));
+        assert_eq!(0, birth.age_on(date(2024, 1, 1)));
+        assert_eq!(AgeReport { international: 1, east_asian: 3 },
+            birth.age_report(date(2025, 1, 1)));
+    }
+
+    #[test]
+    fn born_january_1() {
+        let birth = date(2024, 1, 1);
+        assert_eq!(1, birth.east_asian_age(birth));
+        assert_eq!(1, birth.east_asian_age(date(2024, 12, 31)));
+        assert_eq!(2, birth.east_asian_age(date(2025, 1, 1)));
+        assert_eq!(AgeReport { international: 1, east_asian: 2 },
+            birth.age_report(date(2025, 1, 1)));
+    }
+
+    #[test]
+    fn leap_day() {
+        let birth = date(2000, 2, 29);
+        assert_eq!(22, birth.age_on(date(2023, 2, 28)));
+        assert_eq!(23, birth.age_on(date(2023, 3, 1)));
+        assert_eq!(24, birth.age_on(date(2024, 2, 29)));
+        assert_eq!(24, birth.east_asian_age(date(2023, 2, 28)));
+    }
+
+    #[test]
+    fn birthdate_range() {
+        let range = |years, as_of| NaiveDate::birthdate_range_for_age(years, as_of).unwrap();
+        assert_eq!((date(1989, 6, 2), date(1990, 6, 1)), range(34, date(2024, 6, 1)));
+        assert_eq!((date(1989, 3, 1), date(1990, 2, 28)), range(34, date(2024, 2, 28)));
+        assert_eq!((date(1989, 3, 1), date(1990, 2, 28)), range(34, date(2024, 2, 29)));
+        assert_eq!((date(1989, 3, 2), date(1990, 3, 1)), range(34, date(2024, 3, 1)));
+        assert_eq!((date(1999, 3, 1), date(2000, 2, 29)), range(24, date(2024, 2, 29)));
+
+        // Someone born on a leap day turns a year older on March 1st in common years.
+        assert_eq!((date(2000, 2, 29), date(2001, 2, 28)), range(22, date(2023, 2, 28)));
+        assert_eq!((date(1999, 3, 2), date(2000, 3, 1)), range(23, date(2023, 3, 1)));
+
+        assert_eq!((date(2023, 6, 2), date(2024, 6, 1)), range(0, date(2024, 6, 1)));
+        assert_eq!((date(2023, 3, 1), date(2024, 2, 29)), range(0, date(2024, 2, 29)));
+        assert_eq!((date(2023, 3, 2), date(2024, 3, 1)), range(0, date(2024, 3, 1)));
+
+        // The bounds are exact: a day either side gives a different age.
+        let as_of = date(2024, 3, 1);
+        let (earliest, latest) = range(34, as_of);
+        assert_eq!(35, earliest.pred_opt().unwrap().age_on(as_of));
+        assert_eq!(34, earliest.age_on(as_of));
+        assert_eq!(34, latest.age_on(as_of));
+        assert_eq!(33, latest.succ_opt().unwrap().age_on(as_of));
+    }
+
+    #[test]
+    fn next_anniversary() {
+        let birth = date(1990, 6, 1);
+        let next = |as_of| birth.next_anniversary(as_of, LeapDayPolicy::default());
+        assert_eq!(Some(date(2025, 6, 1)), next(date(2024, 6, 1)));
+        assert_eq!(Some(date(2024, 6, 1)), next(date(2024, 5, 31)));
+        assert_eq!(Some(birth), next(date(1980, 1, 1)));
+        assert_eq!(None, NaiveDate::MAX.next_anniversary(NaiveDate::MAX, LeapDayPolicy::March1));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn anniversary_span() {
+        let dur = |years, months, days| CalendarDuration { years, months, days };
+        let birth = date(1988, 6, 16);
+        let span = |as_of| birth.anniversary_span(as_of, LeapDayPolicy::default());
+        assert_eq!((dur(0, 0, 0), dur(1, 0, 0)), span(date(2024, 6, 16)));
+        assert_eq!((dur(0, 11, 30), dur(0, 0, 1)), span(date(2024, 6, 15)));
+        assert_eq!((dur(0, 2, 0), dur(0, 10, 0)), span(date(2024, 8, 16)));
+        assert_eq!((dur(0, 0, 0), dur(0, 0, 1)), span(date(1988, 6, 15)));
+
+        let leap = date(2000, 2, 29);
+        let span = |as_of, policy| leap.anniversary_span(as_of, policy);
+        assert_eq!((dur(0, 11, 27), dur(0, 0, 1)), span(date(2023, 2, 28), LeapDayPolicy::March1));
+        assert_eq!((dur(0, 0, 0), dur(0, 11, 28)), span(date(2023, 3, 1), LeapDayPolicy::March1));
+        assert_eq!((dur(0, 0, 0), dur(1, 0, 1)),
+            span(date(2023, 2, 28), LeapDayPolicy::February28));
+        assert_eq!((dur(0, 0, 1), dur(0, 11, 28)),
+            span(date(2023, 3, 1), LeapDayPolicy::February28));
+    }
+
+    #[test]
+    fn has_elapsed() {
+        let eighteen = CalendarDuration { years: 18, months: 0, days: 0 };
+        let birth = date(2006, 6, 16);
+        let elapsed = |as_of| birth.has_elapsed(&eighteen, as_of, LeapDayPolicy::default());
+        assert!(!elapsed(date(2024, 6, 15)));
+        assert!(elapsed(date(2024, 6, 16)));
+        assert!(elapsed(date(2030, 1, 1)));
+        assert!(!elapsed(date(2000, 1, 1)));
+
+        let leap = date(2004, 2, 29);
+        let elapsed = |as_of, policy| leap.has_elapsed(&eighteen, as_of, policy);
+        assert!(!elapsed(date(2022, 2, 27), LeapDayPolicy::February28));
+        assert!(elapsed(date(2022, 2, 28), LeapDayPolicy::February28));
+        assert!(!elapsed(date(2022, 2, 28), LeapDayPolicy::March1));
+        assert!(elapsed(date(2022, 3, 1), LeapDayPolicy::March1));
+
+        // The requirement ends after the last date the type can represent.
+        assert!(!birth.has_elapsed(&CalendarDuration { years: u32::MAX, months: 0, days: 0 },
+            NaiveDate::MAX, LeapDayPolicy::default()));
+    }
+
+    #[test]
+    fn before_birth() {
+        let birth = date(2024, 6, 1);
+        assert_eq!(0, birth.east_asian_age(date(2024, 5, 31)));
+        assert_eq!(0, birth.age_on(date(2020, 1, 1)));
+    }
+}
@@ -0,0 +1,246 @@
+use crate::{CalendarDuration, CalendarDurationExt};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+
+/// The average length of a month in the Gregorian calendar's 400-year cycle is 146097 / 4800
+/// days, so lengths in these units are whole numbers.
+pub(crate) const MONTH_UNITS: u64 = 146097;
+pub(crate) const DAY_UNITS: u64 = 4800;
+
+/// Return the approximate length of the duration, in units of 1/4800 of a day.
+pub(crate) fn approximate_length(d: &CalendarDuration) -> u64 {
+    (u64::from(d.years) * 12 + u64::from(d.months)) * MONTH_UNITS + u64::from(d.days) * DAY_UNITS
+}
+
+impl CalendarDuration {
+    /// Return whether the two durations are the same length to within the given number of days,
+    /// when both are added to the given date. For example, 1 year, 11 months, and 29 days is
+    /// within a day of 2 years from most dates.
+    ///
+    /// Returns `false` if either duration goes past the range of the date type.
+    pub fn approx_eq_at<D: CalendarDurationExt>(
+        &self,
+        other: &CalendarDuration,
+        anchor: D,
+        tolerance_days: u32,
+    ) -> bool {
+        let (Some(a), Some(b)) = (
+            anchor.add_calendar_duration(self),
+            anchor.add_calendar_duration(other),
+        ) else {
+            return false;
+        };
+        (a.to_day_number() - b.to_day_number()).unsigned_abs() <= u64::from(tolerance_days)
+    }
+
+    /// Compare the lengths of the two durations by adding both to the given date, since which is
+    /// longer can depend on the date: 1 month is shorter than 30 days from February 1st, but
+    /// longer from March 1st.
+    ///
+    /// A duration which goes past the range of the date type is longer than one which doesn't,
+    /// and two which both do are equal.
+    ///
+    /// ```
+    /// # use calendar_duration::{CalendarDuration, PlainDate};
+    /// # use core::cmp::Ordering;
+    /// let month = CalendarDuration::new(0, 1, 0);
+    /// let anchor = PlainDate::new(2021, 2, 1).unwrap();
+    /// assert_eq!(Ordering::Less, month.cmp_at(&CalendarDuration::new(0, 0, 30), anchor));
+    /// ```
+    pub fn cmp_at<D: CalendarDurationExt>(&self, other: &CalendarDuration, anchor: D)
+        -> Ordering
+    {
+        match (anchor.add_calendar_duration(self), anchor.add_calendar_duration(other)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }
+    }
+
+    /// Return whether the two durations are the same length to within the given number of days,
+    /// without reference to any particular date.
+    ///
+    /// Months are taken to be the average length of a month in the Gregorian calendar, 30.436875
+    /// days, and years twelve times that, 365.2425 days. See
+    /// [`approx_eq_at`](Self::approx_eq_at) to compare them exactly from a date.
+    pub fn approx_eq(&self, other: &CalendarDuration, tolerance_days: u32) -> bool {
+        approximate_length(self).abs_diff(approximate_length(other))
+            <= u64::from(tolerance_days) * DAY_UNITS
+    }
+}
+
+impl CalendarDuration {
+    /// Return the duration rounded to its largest unit, for display like "about 32 years",
+    /// "about 3 months", "about 5 days", or "today".
+    ///
+    /// The rounding uses only the next smaller unit:
+    ///   - With any years, 6 months or more rounds up to the next year, and days are ignored. So
+    ///     1 year, 5 months, and 30 days is "about 1 year", and 31 years and 9 months is "about
+    ///     32 years".
+    ///   - With no years but some months, 15 days or more rounds up to the next month, and 12
+    ///     months carries into "about 1 year". So 1 month and 20 days is "about 2 months", and
+    ///     11 months and 15 days is "about 1 year".
+    ///   - With only days, they are written as they are: "about 5 days".
+    ///   - The zero duration is "today".
+    ///
+    /// Months beyond 11 are counted as years first, so 18 months is "about 2 years".
+    ///
+    /// ```
+    /// # use calendar_duration::CalendarDuration;
+    /// let member_for = CalendarDuration { years: 31, months: 9, days: 23 };
+    /// assert_eq!("about 32 years", member_for.approx().to_string());
+    /// ```
+    pub fn approx(&self) -> ApproxDuration {
+        let months = u64::from(self.years) * 12 + u64::from(self.months);
+        let (years, months) = (months / 12, months % 12);
+        let (count, unit) = if years > 0 {
+            (years + u64::from(months >= 6), ApproxUnit::Years)
+        } else if months > 0 {
+            match months + u64::from(self.days >= 15) {
+                12 => (1, ApproxUnit::Years),
+                months => (months, ApproxUnit::Months),
+            }
+        } else {
+            (u64::from(self.days), ApproxUnit::Days)
+        };
+        ApproxDuration { count, unit }
+    }
+}
+
+/// A [`CalendarDuration`] rounded to its largest unit, as given by [`CalendarDuration::approx`].
+/// It implements [`Display`] in English.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ApproxDuration {
+    count: u64,
+    unit: ApproxUnit,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum ApproxUnit {
+    Years,
+    Months,
+    Days,
+}
+
+impl Display for ApproxDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [singular, plural] = match self.unit {
+            ApproxUnit::Years => ["year", "years"],
+            ApproxUnit::Months => ["month", "months"],
+            ApproxUnit::Days => ["day", "days"],
+        };
+        match self.count {
+            0 => f.write_str("today"),
+            1 => write!(f, "about 1 {singular}"),
+            n => write!(f, "about {n} {plural}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::ToString};
+    use crate::PlainDate;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn anchored() {
+        let anchor = chrono::NaiveDate::from_ymd_opt(2022, 5, 15).unwrap();
+        let tenure = dur(1, 11, 29);
+        assert_ne!(dur(2, 0, 0), tenure);
+        assert!(tenure.approx_eq_at(&dur(2, 0, 0), anchor, 1));
+        assert!(!tenure.approx_eq_at(&dur(2, 0, 0), anchor, 0));
+
+        // February 15th to March 15th is 28 days in a common year.
+        let anchor = chrono::NaiveDate::from_ymd_opt(2023, 2, 15).unwrap();
+        assert!(dur(0, 1, 0).approx_eq_at(&dur(0, 0, 28), anchor, 0));
+        assert!(!dur(0, 1, 0).approx_eq_at(&dur(0, 0, 30), anchor, 1));
+        assert!(dur(0, 1, 0).approx_eq_at(&dur(0, 0, 30), anchor, 2));
+
+        assert!(!dur(0, 0, 0).approx_eq_at(&dur(u32::MAX, 0, 0), anchor, u32::MAX));
+    }
+
+    #[test]
+    fn anchor_free() {
+        // The average month is about 30.44 days, so this is about 1.44 days short.
+        assert!(dur(1, 11, 29).approx_eq(&dur(2, 0, 0), 2));
+        assert!(!dur(1, 11, 29).approx_eq(&dur(2, 0, 0), 1));
+        assert!(!dur(1, 11, 28).approx_eq(&dur(2, 0, 0), 2));
+        // A month is about 30.44 days.
+        assert!(dur(0, 1, 0).approx_eq(&dur(0, 0, 30), 1));
+        assert!(!dur(0, 1, 0).approx_eq(&dur(0, 0, 29), 1));
+        assert!(dur(1, 0, 0).approx_eq(&dur(0, 0, 365), 1));
+        assert!(dur(0, 12, 0).approx_eq(&dur(1, 0, 0), 0));
+        assert!(dur(5, 0, 0).approx_eq(&dur(4, 0, 0), 366));
+        assert!(!dur(5, 0, 0).approx_eq(&dur(4, 0, 0), 365));
+    }
+
+    #[test]
+    fn compare_at() {
+        let date = |y, m, d| PlainDate::new(y, m, d).unwrap();
+        let (month, thirty_days) = (dur(0, 1, 0), dur(0, 0, 30));
+        assert_eq!(Ordering::Less, month.cmp_at(&thirty_days, date(2021, 2, 1)));
+        assert_eq!(Ordering::Greater, month.cmp_at(&thirty_days, date(2021, 3, 1)));
+        assert_eq!(Ordering::Equal, month.cmp_at(&thirty_days, date(2021, 4, 1)));
+        assert_eq!(Ordering::Greater, thirty_days.cmp_at(&month, date(2021, 2, 1)));
+        assert_eq!(Ordering::Equal, dur(1, 0, 0).cmp_at(&dur(0, 12, 0), date(2020, 2, 29)));
+
+        let max = PlainDate::max_value();
+        assert_eq!(Ordering::Equal, CalendarDuration::ZERO.cmp_at(&dur(0, 0, 0), max));
+        assert_eq!(Ordering::Less, CalendarDuration::ZERO.cmp_at(&thirty_days, max));
+        assert_eq!(Ordering::Equal, month.cmp_at(&thirty_days, max));
+    }
+
+    #[test]
+    fn constructors() {
+        assert_eq!(dur(31, 9, 23), CalendarDuration::new(31, 9, 23));
+        assert_eq!(dur(0, 18, 45), CalendarDuration::new(0, 18, 45));
+        assert_eq!(dur(0, 0, 0), CalendarDuration::ZERO);
+        assert_eq!(CalendarDuration::ZERO, CalendarDuration::default());
+        assert!(CalendarDuration::ZERO.is_zero());
+        assert!(!dur(0, 0, 1).is_zero());
+        assert!(!dur(1, 0, 0).is_zero());
+
+        #[cfg(feature = "std")]
+        {
+            let set = [dur(0, 1, 0), dur(0, 0, 30), CalendarDuration::new(0, 1, 0)]
+                .into_iter()
+                .collect::<std::collections::HashSet<_>>();
+            assert_eq!(2, set.len());
+        }
+    }
+
+    #[test]
+    fn rounded_display() {
+        let approx = |years, months, days| dur(years, months, days).approx().to_string();
+        assert_eq!("about 32 years", approx(31, 9, 23));
+        assert_eq!("about 31 years", approx(31, 5, 30));
+        assert_eq!("about 2 years", approx(1, 7, 0));
+        assert_eq!("about 1 year", approx(1, 0, 0));
+        assert_eq!("about 2 months", approx(0, 1, 20));
+        assert_eq!("about 1 month", approx(0, 1, 14));
+        assert_eq!("about 3 months", approx(0, 3, 0));
+        assert_eq!("about 5 days", approx(0, 0, 5));
+        assert_eq!("about 1 day", approx(0, 0, 1));
+        assert_eq!("today", approx(0, 0, 0));
+    }
+
+    #[test]
+    fn rounding_carries() {
+        let approx = |years, months, days| dur(years, months, days).approx().to_string();
+        assert_eq!("about 1 year", approx(0, 11, 15));
+        assert_eq!("about 11 months", approx(0, 11, 14));
+        assert_eq!("about 1 year", approx(0, 12, 0));
+        assert_eq!("about 2 years", approx(0, 18, 0));
+        assert_eq!("about 2 years", approx(1, 6, 0));
+        assert_eq!("about 2 years", approx(0, 23, 29));
+        assert_eq!("about 45 days", approx(0, 0, 45));
+        assert_eq!(format!("about {} years", u64::from(u32::MAX) + 1), approx(u32::MAX, 11, 0));
+    }
+}
@@ -0,0 +1,53 @@
+//! Generating [`CalendarDuration`]s for fuzzing with `arbitrary`.
+
+use crate::CalendarDuration;
+use arbitrary::{size_hint, Arbitrary, Unstructured};
+
+impl<'a> Arbitrary<'a> for CalendarDuration {
+    /// Generate a normalized duration, like those given by
+    /// [`calendar_duration_from`](crate::CalendarDurationExt::calendar_duration_from): fewer than
+    /// 12 months and 31 days, and any number of years.
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        Ok(CalendarDuration {
+            years: u.arbitrary()?,
+            months: u.int_in_range(0 ..= 11)?,
+            days: u.int_in_range(0 ..= 30)?,
+        })
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        size_hint::and_all(&[<u32 as Arbitrary<'_>>::size_hint(depth); 3])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn normalized() {
+        let mut seed = 0x2545_f491_4f6c_dd1d_u64;
+        let bytes = (0 .. 4096)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                seed as u8
+            })
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&bytes);
+        let mut years_seen = 0;
+        while !u.is_empty() {
+            let d = CalendarDuration::arbitrary(&mut u).unwrap();
+            assert!(d.months < 12 && d.days < 31, "{d:?}");
+            years_seen |= d.years;
+        }
+        assert_ne!(0, years_seen);
+    }
+
+    #[test]
+    fn exhausted_input() {
+        let mut u = Unstructured::new(&[]);
+        assert_eq!(CalendarDuration::ZERO, CalendarDuration::arbitrary(&mut u).unwrap());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::{CalendarDuration, CalendarDurationExt, OverflowPolicy};
+
+/// Options controlling how a [`CalendarDuration`] is added to a date by
+/// [`CalendarDurationExt::add_calendar_duration_with`].
+///
+/// The default options give the same result as
+/// [`add_calendar_duration`](CalendarDurationExt::add_calendar_duration).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct AddOptions {
+    round_to: PeriodEnd,
+    overflow: OverflowPolicy,
+    order: AdditionOrder,
+}
+
+impl AddOptions {
+    /// Default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// After adding the duration, move the result forward to the end of the period containing it.
+    ///
+    /// This is how, for example, notice periods which run "until the end of the calendar month"
+    /// are computed.
+    pub fn then_round_to(mut self, round_to: PeriodEnd) -> Self {
+        self.round_to = round_to;
+        self
+    }
+
+    /// Resolve a day of the month which doesn't exist in the month the years and months are added
+    /// to with the given policy, instead of moving to the next valid date.
+    pub fn overflow_policy(mut self, overflow: OverflowPolicy) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Add the parts of the duration in the given order.
+    pub fn order(mut self, order: AdditionOrder) -> Self {
+        self.order = order;
+        self
+    }
+}
+
+/// The order in which the parts of a [`CalendarDuration`] are added to a date. See
+/// [`AddOptions::order`].
+///
+/// The order matters when a day of the month is moved past the end of a month. For example, adding
+/// 1 month and 30 days to January 30th, 2023 gives March 31st when the month is added first
+/// ("February 30th" is March 1st, and 30 days later is March 31st), but April 1st when the days are
+/// added first (30 days after January 30th is March 1st, and a month later is April 1st).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum AdditionOrder {
+    /// Add the years and months, and then the days. This is what most date libraries do.
+    #[default]
+    CalendarFirst,
+
+    /// Add the days, and then the years and months.
+    DaysFirst,
+}
+
+/// A period whose last day a date can be rounded forward to. See [`AddOptions::then_round_to`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum PeriodEnd {
+    /// Don't round the date.
+    #[default]
+    None,
+
+    /// Round to the last day of the calendar month.
+    EndOfMonth,
+
+    /// Round to the last day of the calendar quarter (March 31, June 30, September 30, or December
+    /// 31). In a year with a 13th month, that month is a quarter on its own.
+    EndOfQuarter,
+}
+
+pub(crate) fn add<D: CalendarDurationExt>(
+    date: D,
+    dur: &CalendarDuration,
+    options: &AddOptions,
+) -> Option<D> {
+    let add_days = |date: D| D::from_day_number(date.to_day_number() + i64::from(dur.days));
+    let date = match options.order {
+        AdditionOrder::CalendarFirst => {
+            add_days(add_months::<D>(date.ymd(), dur, 1, options.overflow)?)?
+        }
+        AdditionOrder::DaysFirst => {
+            add_months::<D>(add_days(date)?.ymd(), dur, 1, options.overflow)?
+        }
+    };
+
+    let (y, m, _) = date.ymd();
+    match options.round_to {
+        PeriodEnd::None => Some(date),
+        PeriodEnd::EndOfMonth => D::from_ymd(y, m, D::last_day_of_month(y, m)),
+        PeriodEnd::EndOfQuarter => {
+            let m = (m.div_ceil(3) * 3).min(D::months_in_year(y));
+            D::from_ymd(y, m, D::last_day_of_month(y, m))
+        }
+    }
+}
+
+pub(crate) fn sub<D: CalendarDurationExt>(date: D, dur: &CalendarDuration) -> Option<D> {
+    sub_with(date, dur, OverflowPolicy::NextValid)
+}
+
+pub(crate) fn sub_with<D: CalendarDurationExt>(
+    date: D,
+    dur: &CalendarDuration,
+    overflow: OverflowPolicy,
+) -> Option<D> {
+    let date = D::from_day_number(date.to_day_number() - i64::from(dur.days))?;
+    add_months::<D>(date.ymd(), dur, -1, overflow)
+}
+
+/// Add (or subtract, if `sign` is negative) the years and months of the duration to the date,
+/// keeping the day of the month, and resolve the day with the overflow policy.
+fn add_months<D: CalendarDurationExt>(
+    (y, m, d): (i32, u8, u8),
+    dur: &CalendarDuration,
+    sign: i64,
+    overflow: OverflowPolicy,
+) -> Option<D> {
+    let y = i32::try_from(i64::from(y) + sign * i64::from(dur.years)).ok()?;
+    let (y, m) = step_months::<D>(y, m, sign * i64::from(dur.months))?;
+
+    // Make sure the target month is representable before letting the policy resolve the day. A
+    // leap month which the target year doesn't have is left to the policy.
+    if m <= D::months_in_year(y) {
+        D::from_ymd(y, m, 1)?;
+    }
+    overflow.resolve(y, m, d)
+}
+
+/// Move the year and month forward by the given number of months, or back if it is negative,
+/// counting the months in each year with
+/// [`months_in_year`](CalendarDurationExt::months_in_year).
+///
+/// A month past the end of the year, like a leap month in a year without one, counts as the last
+/// month of the year, unless the number of months is 0. Returns `None` if the year passes the
+/// range of the date type.
+pub(crate) fn step_months<D: CalendarDurationExt>(y: i32, m: u8, months: i64)
+    -> Option<(i32, u8)>
+{
+    if months == 0 {
+        return Some((y, m));
+    }
+    let (min_year, max_year) = (D::min_value().ymd().0, D::max_value().ymd().0);
+    let (mut y, mut m) = (y, m.min(D::months_in_year(y)));
+    let mut left = months.unsigned_abs();
+    if months > 0 {
+        loop {
+            let to_next_year = u64::from(D::months_in_year(y) - m) + 1;
+            if left < to_next_year {
+                break;
+            }
+            left -= to_next_year;
+            y = y.checked_add(1).filter(|&y| y <= max_year)?;
+            m = 1;
+        }
+        // Less than the months left in the year, so it fits.
+        m += left as u8;
+    } else {
+        while left >= u64::from(m) {
+            left -= u64::from(m);
+            y = y.checked_sub(1).filter(|&y| y >= min_year)?;
+            m = D::months_in_year(y);
+        }
+        m -= left as u8;
+    }
+    Some((y, m))
+}
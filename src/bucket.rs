@@ -0,0 +1,132 @@
+use crate::gregorian::{iso_week_from_days, iso_week_start, iso_weeks_in_year};
+use crate::CalendarDurationExt;
+
+/// The size of the calendar buckets that dates are grouped into by
+/// [`CalendarDurationExt::bucket`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Granularity {
+    /// ISO 8601 weeks, which start on Monday and are numbered within the ISO week-numbering year.
+    /// The first few days of January can be in the last week of the previous year, and the last
+    /// few days of December can be in week 1 of the next year.
+    Week,
+
+    /// Calendar months.
+    Month,
+
+    /// Calendar quarters: January through March, April through June, and so on. In a year with a
+    /// 13th month, that month is a fifth quarter on its own.
+    Quarter,
+
+    /// Calendar years.
+    Year,
+}
+
+/// Identifies the calendar bucket a date falls into. See [`CalendarDurationExt::bucket`].
+///
+/// Keys of the same granularity are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BucketKey {
+    /// The size of the bucket.
+    pub granularity: Granularity,
+
+    /// The year of the bucket. For [`Granularity::Week`] this is the ISO week-numbering year,
+    /// which may differ from the calendar year near the start and end of the year.
+    pub year: i32,
+
+    /// The one-based number of the bucket within the year: the ISO week number, month number, or
+    /// quarter number. Always 1 for [`Granularity::Year`].
+    pub index: u8,
+}
+
+pub(crate) fn bucket<D: CalendarDurationExt>(date: D, granularity: Granularity) -> BucketKey {
+    let (year, index) = match granularity {
+        Granularity::Week => iso_week_from_days(date.to_day_number()),
+        Granularity::Month => {
+            let (y, m, _) = date.ymd();
+            (y, m)
+        }
+        Granularity::Quarter => {
+            let (y, m, _) = date.ymd();
+            (y, m.div_ceil(3))
+        }
+        Granularity::Year => (date.ymd().0, 1),
+    };
+    BucketKey { granularity, year, index }
+}
+
+pub(crate) fn bucket_start_date<D: CalendarDurationExt>(key: BucketKey) -> Option<D> {
+    let BucketKey { granularity, year, index } = key;
+    match granularity {
+        Granularity::Week if (1 ..= iso_weeks_in_year(year)).contains(&index) =>
+            D::from_day_number(iso_week_start(year, index)),
+        Granularity::Month if (1 ..= D::months_in_year(year)).contains(&index) =>
+            D::from_ymd(year, index, 1),
+        Granularity::Quarter if (1 ..= D::months_in_year(year).div_ceil(3)).contains(&index) =>
+            D::from_ymd(year, index * 3 - 2, 1),
+        Granularity::Year if index == 1 => D::from_ymd(year, 1, 1),
+        _ => None,
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use Granularity::{Month, Quarter, Week, Year};
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn key(granularity: Granularity, year: i32, index: u8) -> BucketKey {
+        BucketKey { granularity, year, index }
+    }
+
+    #[test]
+    fn weeks_around_new_year() {
+        assert_eq!(key(Week, 2020, 1), date(2019, 12, 30).bucket(Week));
+        assert_eq!(key(Week, 2020, 53), date(2020, 12, 31).bucket(Week));
+        assert_eq!(key(Week, 2020, 53), date(2021, 1, 3).bucket(Week));
+        assert_eq!(key(Week, 2021, 1), date(2021, 1, 4).bucket(Week));
+        assert_eq!(key(Week, 2025, 1), date(2024, 12, 30).bucket(Week));
+
+        assert_eq!(Some(date(2020, 12, 28)), NaiveDate::bucket_start_date(key(Week, 2020, 53)));
+        assert_eq!(None, NaiveDate::bucket_start_date(key(Week, 2021, 53)));
+    }
+
+    #[test]
+    fn quarters_around_new_year() {
+        assert_eq!(key(Quarter, 2020, 4), date(2020, 12, 31).bucket(Quarter));
+        assert_eq!(key(Quarter, 2021, 1), date(2021, 1, 1).bucket(Quarter));
+        assert_eq!(key(Quarter, 2021, 2), date(2021, 4, 1).bucket(Quarter));
+        assert!(date(2020, 12, 31).bucket(Quarter) < date(2021, 1, 1).bucket(Quarter));
+
+        assert_eq!(Some(date(2020, 10, 1)), NaiveDate::bucket_start_date(key(Quarter, 2020, 4)));
+        assert_eq!(None, NaiveDate::bucket_start_date(key(Quarter, 2020, 5)));
+    }
+
+    #[test]
+    fn months_and_years() {
+        assert_eq!(key(Month, 2024, 2), date(2024, 2, 29).bucket(Month));
+        assert_eq!(key(Year, 2024, 1), date(2024, 2, 29).bucket(Year));
+        assert_eq!(Some(date(2024, 1, 1)), NaiveDate::bucket_start_date(key(Year, 2024, 1)));
+    }
+
+    #[test]
+    fn round_trip() {
+        let granularities = [Week, Month, Quarter, Year];
+        let mut d = date(2019, 12, 1);
+        while d < date(2021, 2, 1) {
+            for g in granularities {
+                let k = d.bucket(g);
+                let start = NaiveDate::bucket_start_date(k).unwrap();
+                assert!(start <= d);
+                assert_eq!(k, start.bucket(g));
+                if start > date(2019, 12, 1) {
+                    assert_ne!(k, start.pred_opt().unwrap().bucket(g));
+                }
+            }
+            d = d.succ_opt().unwrap();
+        }
+    }
+}
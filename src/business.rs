@@ -0,0 +1,232 @@
+use crate::gregorian::{civil_from_days, weekday_from_days};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// A calendar of non-working days.
+///
+/// Dates are given as proleptic Gregorian (year, month, day) tuples, the same as
+/// [`CalendarDurationExt::ymd`](crate::CalendarDurationExt::ymd) returns for Gregorian date types,
+/// so a single calendar works with any date type, including ones in other calendars.
+///
+/// A few commonly needed calendars are available in the
+#[cfg_attr(feature = "holidays", doc = "[`holidays`](crate::holidays)")]
+#[cfg_attr(not(feature = "holidays"), doc = "`holidays`")]
+/// module when the `holidays` feature is enabled.
+pub trait HolidayCalendar {
+    /// Return whether the given (year, month, day) is a holiday in this calendar.
+    fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool;
+}
+
+impl<T: HolidayCalendar + ?Sized> HolidayCalendar for &T {
+    fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+        (**self).is_holiday(ymd)
+    }
+}
+
+/// Which days of the week are non-working days, for counting business days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Weekend {
+    /// Saturday and Sunday are non-working days.
+    #[default]
+    SaturdaySunday,
+
+    /// Friday and Saturday are non-working days.
+    FridaySaturday,
+
+    /// Only Sunday is a non-working day.
+    Sunday,
+
+    /// Every day of the week is a working day.
+    None,
+}
+
+impl Weekend {
+    /// Return whether the given day of the week (0 = Monday through 6 = Sunday) is part of the
+    /// weekend.
+    pub fn contains(self, weekday: u8) -> bool {
+        match self {
+            Weekend::SaturdaySunday => weekday == 5 || weekday == 6,
+            Weekend::FridaySaturday => weekday == 4 || weekday == 5,
+            Weekend::Sunday => weekday == 6,
+            Weekend::None => false,
+        }
+    }
+
+    fn working_days_per_week(self) -> i64 {
+        (0..7).filter(|&wd| !self.contains(wd)).count() as i64
+    }
+}
+
+/// Which way to move a date which is not a business day to make it one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BusinessDayRoll {
+    /// Move back to the last business day before the date.
+    Preceding,
+
+    /// Move forward to the first business day after the date.
+    Following,
+}
+
+/// Move the day number to a business day in the direction given, if it isn't one already.
+/// Returns `None` if there's no business day within a year of it.
+#[cfg(feature = "alloc")]
+pub(crate) fn roll(
+    mut day: i64,
+    roll: BusinessDayRoll,
+    weekend: Weekend,
+    holidays: &impl HolidayCalendar,
+) -> Option<i64> {
+    let step = match roll {
+        BusinessDayRoll::Preceding => -1,
+        BusinessDayRoll::Following => 1,
+    };
+    for _ in 0..366 {
+        let weekday = weekday_from_days(day);
+        if !weekend.contains(weekday) && !holidays.is_holiday(civil_from_days(day)) {
+            return Some(day);
+        }
+        day = day.checked_add(step)?;
+    }
+    None
+}
+
+/// Count the working days in the half-open range of day numbers `(earlier, later]`, ignoring
+/// holidays.
+pub(crate) fn weekdays_between(earlier: i64, later: i64, weekend: Weekend) -> i64 {
+    let total = later - earlier;
+    let full_weeks = total / 7;
+    let remainder = (earlier + full_weeks * 7 + 1 ..= later)
+        .filter(|&day| !weekend.contains(weekday_from_days(day)))
+        .count() as i64;
+    full_weeks * weekend.working_days_per_week() + remainder
+}
+
+/// Count the business days in the half-open range of day numbers `(earlier, later]`.
+pub(crate) fn business_days_between(
+    earlier: i64,
+    later: i64,
+    weekend: Weekend,
+    holidays: &impl HolidayCalendar,
+) -> u32 {
+    let holiday_count = (earlier + 1 ..= later)
+        .filter(|&day| {
+            !weekend.contains(weekday_from_days(day)) && holidays.is_holiday(civil_from_days(day))
+        })
+        .count() as i64;
+    (weekdays_between(earlier, later, weekend) - holiday_count) as u32
+}
+
+/// Count the business days in the half-open range of day numbers `(earlier, later]`, with the
+/// holidays given as a list of day numbers within the range.
+#[cfg(feature = "alloc")]
+pub(crate) fn business_days_between_listed(
+    earlier: i64,
+    later: i64,
+    weekend: Weekend,
+    mut holidays: Vec<i64>,
+) -> u32 {
+    holidays.retain(|&day| !weekend.contains(weekday_from_days(day)));
+    holidays.sort_unstable();
+    holidays.dedup();
+    (weekdays_between(earlier, later, weekend) - holidays.len() as i64) as u32
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use crate::CalendarDurationExt;
+    use alloc::{vec, vec::Vec};
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    struct Holidays(Vec<(i32, u8, u8)>);
+
+    impl HolidayCalendar for Holidays {
+        fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+            self.0.contains(&ymd)
+        }
+    }
+
+    #[test]
+    fn weekdays() {
+        // 2024-03-01 is a Friday.
+        let friday = date(2024, 3, 1);
+        let none = Holidays(vec![]);
+        assert_eq!(4, friday.weekday_from_monday());
+        assert_eq!(0, friday.business_days_from_with(friday, Weekend::SaturdaySunday, &none));
+        assert_eq!(0, friday.business_days_from_with(date(2024, 3, 3), Weekend::SaturdaySunday, &none));
+        assert_eq!(1, friday.business_days_from_with(date(2024, 3, 4), Weekend::SaturdaySunday, &none));
+        assert_eq!(1, date(2024, 3, 4).business_days_from_with(friday, Weekend::SaturdaySunday, &none));
+        assert_eq!(2, friday.business_days_from_with(date(2024, 3, 4), Weekend::FridaySaturday, &none));
+        assert_eq!(3, friday.business_days_from_with(date(2024, 3, 4), Weekend::None, &none));
+        // All of March 2024: 21 weekdays, minus Friday the 1st which is excluded.
+        assert_eq!(20, friday.business_days_from_with(date(2024, 3, 31), Weekend::SaturdaySunday, &none));
+    }
+
+    #[test]
+    fn bus_252() {
+        // Hand-counted: 2024-01-02 (Tue) to 2024-01-31 (Wed) has 21 weekdays after the start date.
+        // Two holidays fall on weekdays and one on a Saturday, which doesn't count twice.
+        let holidays = Holidays(vec![(2024, 1, 15), (2024, 1, 25), (2024, 1, 27)]);
+        let start = date(2024, 1, 2);
+        let end = date(2024, 1, 31);
+        assert_eq!(19, start.business_days_from_with(end, Weekend::SaturdaySunday, &holidays));
+        assert_eq!(19. / 252., start.year_fraction_bus_252(end, Weekend::SaturdaySunday, &holidays));
+
+        // A holiday on the start date is excluded anyway; one on the end date is counted out.
+        let holidays = Holidays(vec![(2024, 1, 2), (2024, 1, 31)]);
+        assert_eq!(20, start.business_days_from_with(end, Weekend::SaturdaySunday, &holidays));
+
+        // A whole year: 2023-12-29 (Fri) to 2024-12-31 (Tue) has 262 weekdays, less 2 holidays.
+        let holidays = Holidays(vec![(2024, 1, 1), (2024, 12, 25)]);
+        assert_eq!(260. / 252.,
+            date(2023, 12, 29).year_fraction_bus_252(date(2024, 12, 31), Weekend::SaturdaySunday, &holidays));
+    }
+
+    #[test]
+    fn default_weekend() {
+        // 2024-03-01 is a Friday.
+        let friday = date(2024, 3, 1);
+        let (saturday, sunday) = (date(2024, 3, 2), date(2024, 3, 3));
+        assert_eq!(0, friday.business_days_from(friday));
+        assert_eq!(0, saturday.business_days_from(sunday));
+        assert_eq!(0, sunday.business_days_from(sunday));
+        assert_eq!(5, saturday.business_days_from(date(2024, 3, 10)));
+        assert_eq!(1, friday.business_days_from(date(2024, 3, 4)));
+        assert_eq!(3, date(2024, 3, 4).business_days_from(date(2024, 3, 7)));
+        assert_eq!(20, date(2024, 3, 31).business_days_from(friday));
+        assert_eq!(262, date(2023, 12, 29).business_days_from(date(2024, 12, 31)));
+
+        let none = Holidays(vec![]);
+        let mut day = date(2023, 12, 20);
+        while day < date(2024, 2, 1) {
+            for end in [date(2024, 1, 1), date(2024, 1, 6), date(2024, 1, 7), date(2024, 1, 31)] {
+                assert_eq!(day.business_days_from_with(end, Weekend::SaturdaySunday, &none),
+                    day.business_days_from(end), "{day} to {end}");
+            }
+            day = day.succ_opt().unwrap();
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn holiday_list() {
+        let start = date(2024, 1, 2);
+        let end = date(2024, 1, 31);
+        // A Saturday, the excluded start date, and a day after the end don't count, and a
+        // repeated holiday only counts once.
+        let holidays = [date(2024, 1, 15), date(2024, 1, 25), date(2024, 1, 27), date(2024, 1, 2),
+            date(2024, 2, 1), date(2024, 1, 15)];
+        assert_eq!(19, start.business_days_from_with_holidays(end, &holidays));
+        assert_eq!(19, end.business_days_from_with_holidays(start, &holidays));
+        assert_eq!(20, start.business_days_from_with_holidays(end, &[end]));
+        assert_eq!(0, end.business_days_from_with_holidays(end, &[end]));
+
+        let calendar = Holidays(vec![(2024, 1, 15), (2024, 1, 25), (2024, 1, 27)]);
+        assert_eq!(start.business_days_from_with(end, Weekend::SaturdaySunday, &calendar),
+            start.business_days_from_with_holidays(end, &holidays));
+    }
+}
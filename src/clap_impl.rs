@@ -0,0 +1,150 @@
+//! Parsing [`CalendarDuration`] command line arguments with `clap`.
+
+use crate::iso8601::parse_calendar_iso8601;
+use crate::parse::parse_shorthand;
+use crate::{CalendarDuration, ParseError};
+use clap::builder::{TypedValueParser, ValueParserFactory};
+use clap::error::ErrorKind;
+use clap::{Arg, Command};
+use std::ffi::OsStr;
+
+/// The forms accepted, for the error message.
+const ACCEPTED: &str = "an ISO 8601 duration (\"P1Y6M\"), shorthand (\"1y 6m\"), or English \
+    (\"1 year and 6 months\")";
+
+/// A `clap` value parser for [`CalendarDuration`] arguments, which accepts ISO 8601 durations like
+/// `P1Y6M`, shorthand like `18m` or `1y 6m`, and English like `2 years` or `a year and a day`.
+///
+/// Because [`CalendarDuration`] implements [`ValueParserFactory`], fields of that type in a
+/// derived parser use this without needing a `value_parser` attribute:
+///
+/// ```
+/// use calendar_duration::CalendarDuration;
+/// use clap::Parser;
+///
+/// #[derive(Parser)]
+/// struct Cli {
+///     #[arg(long)]
+///     retention: CalendarDuration,
+/// }
+///
+/// let cli = Cli::parse_from(["prune", "--retention", "18m"]);
+/// assert_eq!(CalendarDuration { years: 0, months: 18, days: 0 }, cli.retention);
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CalendarDurationValueParser;
+
+impl CalendarDurationValueParser {
+    /// Make the value parser.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+/// Whether the input looks like shorthand, so that its errors are more helpful than the English
+/// parser's.
+fn looks_like_shorthand(s: &str) -> bool {
+    s.split_whitespace().all(|part| {
+        part.starts_with(|c: char| c.is_ascii_digit())
+            && part.ends_with(|c: char| c.is_ascii_alphabetic())
+            && part[.. part.len() - 1].bytes().all(|b| b.is_ascii_digit())
+    })
+}
+
+fn parse(s: &str) -> Result<CalendarDuration, ParseError> {
+    if s.trim_start().starts_with(['P', 'p']) {
+        return parse_calendar_iso8601(s);
+    }
+    match parse_shorthand(s) {
+        Ok(dur) => Ok(dur),
+        Err(e) if looks_like_shorthand(s) => Err(e),
+        Err(_) => CalendarDuration::parse_english(s),
+    }
+}
+
+impl TypedValueParser for CalendarDurationValueParser {
+    type Value = CalendarDuration;
+
+    fn parse_ref(&self, cmd: &Command, arg: Option<&Arg>, value: &OsStr)
+        -> Result<CalendarDuration, clap::Error>
+    {
+        let Some(s) = value.to_str() else {
+            return Err(clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+        parse(s).map_err(|e| {
+            let arg = arg.map_or_else(|| "...".to_owned(), Arg::to_string);
+            let message = format!("invalid value '{s}' for '{arg}': {e}\n\n  \
+                expected {ACCEPTED}\n");
+            clap::Error::raw(ErrorKind::ValueValidation, message).with_cmd(cmd)
+        })
+    }
+}
+
+impl ValueParserFactory for CalendarDuration {
+    type Parser = CalendarDurationValueParser;
+
+    fn value_parser() -> CalendarDurationValueParser {
+        CalendarDurationValueParser
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use clap::Parser;
+
+    #[derive(Parser, Debug)]
+    #[command(name = "prune")]
+    struct Cli {
+        #[arg(long)]
+        retention: CalendarDuration,
+
+        #[arg(long, value_parser = CalendarDurationValueParser::new())]
+        lookback: Option<CalendarDuration>,
+    }
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn retention(arg: &str) -> Result<CalendarDuration, String> {
+        Cli::try_parse_from(["prune", "--retention", arg])
+            .map(|cli| cli.retention)
+            .map_err(|e| e.to_string())
+    }
+
+    #[test]
+    fn accepted_forms() {
+        assert_eq!(Ok(dur(0, 18, 0)), retention("18m"));
+        assert_eq!(Ok(dur(1, 6, 0)), retention("1y 6m"));
+        assert_eq!(Ok(dur(1, 6, 0)), retention("P1Y6M"));
+        assert_eq!(Ok(dur(0, 0, 14)), retention("P2W"));
+        assert_eq!(Ok(dur(2, 0, 0)), retention("2 years"));
+        assert_eq!(Ok(dur(1, 0, 1)), retention("a year and a day"));
+
+        let cli = Cli::try_parse_from(["prune", "--retention", "30d", "--lookback", "2 years"])
+            .unwrap();
+        assert_eq!(Some(dur(2, 0, 0)), cli.lookback);
+    }
+
+    #[test]
+    fn rendered_error() {
+        assert_eq!(Err("error: invalid value '18x' for '--retention <RETENTION>': unknown unit \
+            \"x\"\n\n  expected an ISO 8601 duration (\"P1Y6M\"), shorthand (\"1y 6m\"), or \
+            English (\"1 year and 6 months\")\n".to_owned()),
+            retention("18x"));
+    }
+
+    #[test]
+    fn specific_errors() {
+        let message = |arg| retention(arg).unwrap_err();
+        assert!(message("2 fortnights").contains(": unknown unit \"fortnights\"\n"));
+        assert!(message("P1DT12H").contains(": time of day \"P1DT12H\" not supported"));
+        assert!(message("1y 1y").contains(": unit \"years\" given more than once\n"));
+        assert!(message("six").contains(": number without a unit\n"));
+        assert!(message("").contains(": empty duration\n"));
+        for arg in ["2 fortnights", "P1DT12H", ""] {
+            assert!(message(arg).contains(ACCEPTED), "{arg:?}");
+        }
+    }
+}
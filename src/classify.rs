@@ -0,0 +1,175 @@
+use crate::{CalendarDuration, CalendarDurationExt, CalendarDurationFormatter, PlainDate};
+use alloc::{borrow::ToOwned, format, string::String, vec, vec::Vec};
+
+/// Sorts durations into buckets separated by a list of boundaries, such as "less than 1 month",
+/// "1 month to 6 months", and "5 years or more", for filtering records by tenure.
+///
+/// Durations can't be ordered on their own in general (1 month may be more or less than 30 days),
+/// so the buckets are built with a way of comparing them:
+///   - [`new`](Self::new) compares durations field by field, after carrying every 12 months into
+///     a year: first the years, then the months, and then the days. Days never carry into months,
+///     so 45 days is less than 1 month. This is exact for durations computed by
+///     [`calendar_duration_from`](CalendarDurationExt::calendar_duration_from), which are
+///     normalized.
+///   - [`anchored`](Self::anchored) compares durations by adding them to a date, so 45 days is
+///     more than 1 month. Durations which go past the end of the calendar are more than any that
+///     don't.
+///
+/// Bucket `0` holds durations less than the first boundary, bucket `i` holds those at least
+/// boundary `i - 1` and less than boundary `i`, and the last bucket is open-ended, holding
+/// everything at least the last boundary.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DurationBuckets {
+    boundaries: Vec<CalendarDuration>,
+    anchor: Option<PlainDate>,
+}
+
+impl DurationBuckets {
+    /// Make buckets with the given boundaries, comparing durations field by field. The boundaries
+    /// are sorted if they aren't already.
+    pub fn new(boundaries: Vec<CalendarDuration>) -> Self {
+        Self::with_anchor(boundaries, None)
+    }
+
+    /// Make buckets with the given boundaries, comparing durations by adding them to the given
+    /// date. The boundaries are sorted if they aren't already.
+    pub fn anchored<D: CalendarDurationExt>(boundaries: Vec<CalendarDuration>, anchor: D) -> Self {
+        let (y, m, d) = anchor.ymd();
+        Self::with_anchor(boundaries, PlainDate::new(y, m, d))
+    }
+
+    fn with_anchor(mut boundaries: Vec<CalendarDuration>, anchor: Option<PlainDate>) -> Self {
+        let mut buckets = Self { boundaries: vec![], anchor };
+        boundaries.sort_by_key(|b| buckets.key(b));
+        buckets.boundaries = boundaries;
+        buckets
+    }
+
+    /// Return a key for the duration which orders it according to the comparison strategy.
+    fn key(&self, d: &CalendarDuration) -> (u64, u64, u64) {
+        match self.anchor {
+            None => (
+                u64::from(d.years) + u64::from(d.months / 12),
+                u64::from(d.months % 12),
+                u64::from(d.days),
+            ),
+            Some(anchor) => match anchor.add_calendar_duration(d) {
+                // Offset so that every representable date sorts before the end of the calendar.
+                Some(date) => (0, (date.to_day_number() - i64::from(i32::MIN) * 366) as u64, 0),
+                None => (1, 0, 0),
+            },
+        }
+    }
+
+    /// Return the boundaries between the buckets, in increasing order.
+    pub fn boundaries(&self) -> &[CalendarDuration] {
+        &self.boundaries
+    }
+
+    /// Return the number of buckets, which is one more than the number of boundaries.
+    pub fn bucket_count(&self) -> usize {
+        self.boundaries.len() + 1
+    }
+
+    /// Return the index of the bucket the duration belongs to. A duration equal to a boundary
+    /// belongs to the bucket above it.
+    pub fn classify(&self, d: &CalendarDuration) -> usize {
+        let key = self.key(d);
+        self.boundaries.partition_point(|b| self.key(b) <= key)
+    }
+
+    /// Return English labels for the buckets, like `< 1 month`, `1 month – 6 months`, and
+    /// `≥ 5 years`, in the same format as the [`Display`](core::fmt::Display) implementation of
+    /// [`CalendarDuration`].
+    pub fn labels(&self) -> Vec<String> {
+        self.labels_with(&CalendarDurationFormatter::new())
+    }
+
+    /// Return labels for the buckets like [`labels`](Self::labels), formatting the boundaries with
+    /// the given formatter.
+    pub fn labels_with(&self, formatter: &CalendarDurationFormatter<'_>) -> Vec<String> {
+        let mut labels = Vec::with_capacity(self.bucket_count());
+        let mut lower = None;
+        for upper in &self.boundaries {
+            labels.push(match lower {
+                None => format!("< {}", formatter.format(upper)),
+                Some(lower) => {
+                    format!("{} – {}", formatter.format(lower), formatter.format(upper))
+                }
+            });
+            lower = Some(upper);
+        }
+        labels.push(match lower {
+            None => "any".to_owned(),
+            Some(lower) => format!("≥ {}", formatter.format(lower)),
+        });
+        labels
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn tenure() -> Vec<CalendarDuration> {
+        vec![dur(0, 1, 0), dur(0, 6, 0), dur(1, 0, 0), dur(5, 0, 0)]
+    }
+
+    #[test]
+    fn boundaries() {
+        let buckets = DurationBuckets::new(tenure());
+        assert_eq!(5, buckets.bucket_count());
+        assert_eq!(0, buckets.classify(&dur(0, 0, 0)));
+        assert_eq!(0, buckets.classify(&dur(0, 0, 30)));
+        assert_eq!(1, buckets.classify(&dur(0, 1, 0)));
+        assert_eq!(1, buckets.classify(&dur(0, 5, 30)));
+        assert_eq!(2, buckets.classify(&dur(0, 6, 0)));
+        assert_eq!(3, buckets.classify(&dur(1, 0, 0)));
+        assert_eq!(3, buckets.classify(&dur(0, 12, 0)));
+        assert_eq!(3, buckets.classify(&dur(4, 11, 30)));
+        assert_eq!(4, buckets.classify(&dur(5, 0, 0)));
+        assert_eq!(4, buckets.classify(&dur(u32::MAX, 0, 0)));
+
+        // Days don't carry into months when comparing field by field.
+        assert_eq!(0, buckets.classify(&dur(0, 0, 45)));
+    }
+
+    #[test]
+    fn anchored() {
+        let anchor = PlainDate::new(2024, 1, 31).unwrap();
+        let buckets = DurationBuckets::anchored(tenure(), anchor);
+        assert_eq!(1, buckets.classify(&dur(0, 0, 45)));
+        // There's no February 31st, so January 31st plus a month is March 1st, 30 days later.
+        assert_eq!(0, buckets.classify(&dur(0, 0, 29)));
+        assert_eq!(1, buckets.classify(&dur(0, 0, 30)));
+        assert_eq!(1, buckets.classify(&dur(0, 1, 0)));
+        assert_eq!(3, buckets.classify(&dur(0, 0, 366)));
+        assert_eq!(4, buckets.classify(&dur(u32::MAX, 0, 0)));
+    }
+
+    #[test]
+    fn unsorted() {
+        let mut boundaries = tenure();
+        boundaries.reverse();
+        let buckets = DurationBuckets::new(boundaries);
+        assert_eq!(tenure(), buckets.boundaries());
+        assert_eq!(2, buckets.classify(&dur(0, 7, 0)));
+    }
+
+    #[test]
+    fn labels() {
+        let buckets = DurationBuckets::new(tenure());
+        assert_eq!(
+            vec!["< 1 month", "1 month – 6 months", "6 months – 1 year", "1 year – 5 years",
+                "≥ 5 years"],
+            buckets.labels());
+        assert_eq!(vec!["any"], DurationBuckets::new(vec![]).labels());
+
+        let formatter = CalendarDurationFormatter::new().months("mes", "meses");
+        assert_eq!("1 mes – 6 meses", buckets.labels_with(&formatter)[1]);
+    }
+}
@@ -0,0 +1,201 @@
+use crate::arithmetic::step_months;
+use crate::gregorian::weekday_from_days;
+use crate::{CalendarDuration, CalendarDurationExt, OverflowPolicy};
+
+/// A relative offset combined with absolute replacements for the year, month, or day, and an
+/// optional adjustment to a day of the week, like Python dateutil's `relativedelta`.
+///
+/// When applied to a date, the parts are applied in this order:
+///  1. the year, month, and day are replaced by any absolute values given;
+///  2. the years and months of the duration are added (or subtracted);
+///  3. a day which doesn't exist in the resulting month is resolved by the [`OverflowPolicy`];
+///  4. the days of the duration are added (or subtracted);
+///  5. the date is moved to the requested day of the week.
+///
+/// A day of 31 with [`OverflowPolicy::ClampToEndOfMonth`] means "the last day of the month":
+///
+/// ```
+/// # #[cfg(feature = "chrono")] {
+/// # use calendar_duration::{CalendarDuration, OverflowPolicy, RelativeDelta};
+/// # use chrono::NaiveDate;
+/// let one_month = CalendarDuration { years: 0, months: 1, days: 0 };
+/// let last_day_of_next_month = RelativeDelta::after(one_month).day(31);
+/// let date = NaiveDate::from_ymd_opt(2024, 1, 15).unwrap();
+/// assert_eq!(NaiveDate::from_ymd_opt(2024, 2, 29),
+///     last_day_of_next_month.apply(date, OverflowPolicy::ClampToEndOfMonth));
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelativeDelta {
+    duration: CalendarDuration,
+    negative: bool,
+    year: Option<i32>,
+    month: Option<u8>,
+    day: Option<u8>,
+    weekday: Option<(u8, i32)>,
+}
+
+impl Default for RelativeDelta {
+    /// A delta which changes nothing.
+    fn default() -> Self {
+        Self {
+            duration: CalendarDuration { years: 0, months: 0, days: 0 },
+            negative: false,
+            year: None,
+            month: None,
+            day: None,
+            weekday: None,
+        }
+    }
+}
+
+impl RelativeDelta {
+    /// A delta which moves dates forward by the given duration.
+    pub fn after(duration: CalendarDuration) -> Self {
+        Self { duration, ..Self::default() }
+    }
+
+    /// A delta which moves dates back by the given duration.
+    pub fn before(duration: CalendarDuration) -> Self {
+        Self { duration, negative: true, ..Self::default() }
+    }
+
+    /// Replace the year.
+    pub fn year(mut self, year: i32) -> Self {
+        self.year = Some(year);
+        self
+    }
+
+    /// Replace the month (one-based).
+    pub fn month(mut self, month: u8) -> Self {
+        self.month = Some(month);
+        self
+    }
+
+    /// Replace the day of the month.
+    pub fn day(mut self, day: u8) -> Self {
+        self.day = Some(day);
+        self
+    }
+
+    /// Finally move to the `n`th given day of the week (0 being Monday and 6 being Sunday),
+    /// counting the date itself. Positive `n` counts forward and negative `n` counts backward, so
+    /// `weekday(4, 1)` is the next Friday on or after the date, and `weekday(4, -1)` the last
+    /// Friday on or before it. Zero is the same as 1.
+    pub fn weekday(mut self, weekday: u8, n: i32) -> Self {
+        self.weekday = Some((weekday, n));
+        self
+    }
+
+    /// Apply the delta to a date, resolving days which don't exist in their month with the given
+    /// policy.
+    ///
+    /// Returns `None` if the result can't be represented by the date type, or if a replaced month
+    /// or weekday is out of range.
+    pub fn apply<D: CalendarDurationExt>(&self, date: D, policy: OverflowPolicy) -> Option<D> {
+        let (y, m, d) = date.ymd();
+        let y = self.year.unwrap_or(y);
+        let m = self.month.unwrap_or(m);
+        let d = self.day.unwrap_or(d);
+        if m < 1 || m > D::months_in_year(y) {
+            return None;
+        }
+
+        let sign = if self.negative { -1 } else { 1 };
+        let y = i32::try_from(i64::from(y) + sign * i64::from(self.duration.years)).ok()?;
+        let (y, m) = step_months::<D>(y, m, sign * i64::from(self.duration.months))?;
+
+        // Make sure the target month is representable before letting the policy resolve the day. A
+        // leap month which the target year doesn't have is left to the policy.
+        if m <= D::months_in_year(y) {
+            D::from_ymd(y, m, 1)?;
+        }
+        let date = policy.resolve::<D>(y, m, d)?;
+        let mut n = date.to_day_number() + sign * i64::from(self.duration.days);
+
+        if let Some((weekday, nth)) = self.weekday {
+            if weekday > 6 {
+                return None;
+            }
+            let current = i64::from(weekday_from_days(n));
+            let weekday = i64::from(weekday);
+            if nth >= 0 {
+                n += (weekday - current).rem_euclid(7) + 7 * (i64::from(nth.max(1)) - 1);
+            } else {
+                n -= (current - weekday).rem_euclid(7) + 7 * (-i64::from(nth) - 1);
+            }
+        }
+
+        D::from_day_number(n)
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    const FRIDAY: u8 = 4;
+
+    #[test]
+    fn last_day_of_next_month() {
+        // relativedelta(months=+1, day=31)
+        let delta = RelativeDelta::after(dur(0, 1, 0)).day(31);
+        let clamp = OverflowPolicy::ClampToEndOfMonth;
+        assert_eq!(Some(date(2003, 10, 31)), delta.apply(date(2003, 9, 17), clamp));
+        assert_eq!(Some(date(2003, 2, 28)), delta.apply(date(2003, 1, 31), clamp));
+        assert_eq!(Some(date(2004, 2, 29)), delta.apply(date(2004, 1, 1), clamp));
+    }
+
+    #[test]
+    fn next_friday_after_a_month() {
+        // relativedelta(months=+1, weekday=FR)
+        let delta = RelativeDelta::after(dur(0, 1, 0)).weekday(FRIDAY, 1);
+        let policy = OverflowPolicy::NextValid;
+        // 2003-10-17 is a Friday itself.
+        assert_eq!(Some(date(2003, 10, 17)), delta.apply(date(2003, 9, 17), policy));
+        assert_eq!(Some(date(2003, 10, 24)), delta.apply(date(2003, 9, 18), policy));
+
+        // relativedelta(weekday=FR(+2)) and relativedelta(weekday=FR(-1))
+        let base = date(2003, 9, 17);
+        assert_eq!(Some(date(2003, 9, 26)),
+            RelativeDelta::default().weekday(FRIDAY, 2).apply(base, policy));
+        assert_eq!(Some(date(2003, 9, 12)),
+            RelativeDelta::default().weekday(FRIDAY, -1).apply(base, policy));
+    }
+
+    #[test]
+    fn absolute_and_relative() {
+        // relativedelta(years=+1, month=1, day=1): the replacements happen first.
+        let delta = RelativeDelta::after(dur(1, 0, 0)).month(1).day(1);
+        assert_eq!(Some(date(2004, 1, 1)),
+            delta.apply(date(2003, 9, 17), OverflowPolicy::NextValid));
+
+        let delta = RelativeDelta::before(dur(0, 1, 1)).year(2000);
+        assert_eq!(Some(date(2000, 2, 28)),
+            delta.apply(date(2003, 3, 29), OverflowPolicy::NextValid));
+
+        // February 30th rolls forward or clamps.
+        let delta = RelativeDelta::default().month(2).day(30);
+        assert_eq!(Some(date(2003, 3, 1)),
+            delta.apply(date(2003, 9, 17), OverflowPolicy::NextValid));
+        assert_eq!(Some(date(2003, 2, 28)),
+            delta.apply(date(2003, 9, 17), OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    #[test]
+    fn invalid() {
+        let policy = OverflowPolicy::NextValid;
+        assert_eq!(None, RelativeDelta::default().month(13).apply(date(2003, 9, 17), policy));
+        assert_eq!(None, RelativeDelta::default().weekday(7, 1).apply(date(2003, 9, 17), policy));
+        assert_eq!(None, RelativeDelta::after(dur(0, 0, 1)).apply(NaiveDate::MAX, policy));
+    }
+}
@@ -0,0 +1,171 @@
+#[cfg(feature = "alloc")]
+use crate::arithmetic::step_months;
+use crate::CalendarDurationExt;
+#[cfg(feature = "alloc")]
+use crate::{duration_between, CalendarDuration, OverflowPolicy};
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+
+/// The unit added in a [`Step`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StepUnit {
+    /// Years.
+    Years,
+
+    /// Months.
+    Months,
+
+    /// Days.
+    Days,
+}
+
+/// One step in computing a calendar duration: a number of one unit added to the previous date.
+#[cfg_attr(feature = "alloc", doc = "See [`CalendarDurationExt::calendar_duration_explained`].")]
+///
+/// The [`Display`] implementation writes it like "+ 9 months → 2020-03-16".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Step<D> {
+    /// The unit added.
+    pub unit: StepUnit,
+
+    /// The number of the unit added.
+    pub count: u32,
+
+    /// The date reached.
+    pub date: D,
+
+    /// The (year, month, day) the step would have reached if it existed, when it doesn't and was
+    /// resolved to [`date`](Self::date) instead, like February 30th resolving to March 1st.
+    pub missing_day: Option<(i32, u8, u8)>,
+}
+
+impl<D: CalendarDurationExt> Step<D> {
+    /// Return a value which displays the starting date and the steps from it, like
+    /// "1988-06-16 + 31 years → 2019-06-16; + 9 months → 2020-03-16; + 23 days → 2020-04-08".
+    pub fn trace(start: D, steps: &[Step<D>]) -> StepTrace<'_, D> {
+        StepTrace { start, steps }
+    }
+}
+
+/// The starting date and steps of computing a calendar duration. See [`Step::trace`].
+#[derive(Debug, Clone, Copy)]
+pub struct StepTrace<'a, D> {
+    start: D,
+    steps: &'a [Step<D>],
+}
+
+fn write_date(f: &mut Formatter<'_>, (y, m, d): (i32, u8, u8)) -> fmt::Result {
+    write!(f, "{y:04}-{m:02}-{d:02}")
+}
+
+impl<D: CalendarDurationExt> Display for Step<D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let (singular, plural) = match self.unit {
+            StepUnit::Years => ("year", "years"),
+            StepUnit::Months => ("month", "months"),
+            StepUnit::Days => ("day", "days"),
+        };
+        write!(f, "+ {} {} → ", self.count, if self.count == 1 { singular } else { plural })?;
+        write_date(f, self.date.ymd())?;
+        if let Some(missing) = self.missing_day {
+            f.write_str(" (for ")?;
+            write_date(f, missing)?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl<D: CalendarDurationExt> Display for StepTrace<'_, D> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_date(f, self.start.ymd())?;
+        for (i, step) in self.steps.iter().enumerate() {
+            f.write_str(if i == 0 { " " } else { "; " })?;
+            step.fmt(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub(crate) fn explained<D: CalendarDurationExt>(a: D, b: D) -> (CalendarDuration, Vec<Step<D>>) {
+    let dur = duration_between(a, b);
+    let (later, earlier) = if a > b { (a, b) } else { (b, a) };
+    let (y, m, d) = earlier.ymd();
+    let mut steps = vec![];
+
+    // Retrace the anniversaries the computation settled on. They are counted from the earlier
+    // date's month and day each time, so only the last one of each unit matters.
+    let y = (i64::from(y) + i64::from(dur.years)) as i32;
+    for (unit, count, months) in [
+        (StepUnit::Years, dur.years, 0),
+        (StepUnit::Months, dur.months, i64::from(dur.months)),
+    ] {
+        if count == 0 {
+            continue;
+        }
+        let Some((y, m)) = step_months::<D>(y, m, months) else {
+            break;
+        };
+        let ymd = (y, m, d);
+        let Some(date) = OverflowPolicy::NextValid.resolve::<D>(ymd.0, ymd.1, ymd.2) else {
+            break;
+        };
+        let missing_day = (date.ymd() != ymd).then_some(ymd);
+        steps.push(Step { unit, count, date, missing_day });
+    }
+
+    if dur.days != 0 {
+        steps.push(Step { unit: StepUnit::Days, count: dur.days, date: later, missing_day: None });
+    }
+    (dur, steps)
+}
+
+#[cfg(all(test, feature = "alloc", feature = "chrono"))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn readme_example() {
+        let (dur, steps) = date(2020, 4, 8).calendar_duration_explained(date(1988, 6, 16));
+        assert_eq!(CalendarDuration { years: 31, months: 9, days: 23 }, dur);
+        assert_eq!(vec![
+            Step { unit: StepUnit::Years, count: 31, date: date(2019, 6, 16), missing_day: None },
+            Step { unit: StepUnit::Months, count: 9, date: date(2020, 3, 16), missing_day: None },
+            Step { unit: StepUnit::Days, count: 23, date: date(2020, 4, 8), missing_day: None },
+        ], steps);
+        assert_eq!(
+            "1988-06-16 + 31 years → 2019-06-16; + 9 months → 2020-03-16; + 23 days → 2020-04-08",
+            Step::trace(date(1988, 6, 16), &steps).to_string());
+    }
+
+    #[test]
+    fn missing_day() {
+        // There's no February 30th, so the month ends on March 1st.
+        let (dur, steps) = date(2023, 1, 30).calendar_duration_explained(date(2023, 3, 5));
+        assert_eq!(CalendarDuration { years: 0, months: 1, days: 4 }, dur);
+        assert_eq!(Some((2023, 2, 30)), steps[0].missing_day);
+        assert_eq!(
+            "2023-01-30 + 1 month → 2023-03-01 (for 2023-02-30); + 4 days → 2023-03-05",
+            Step::trace(date(2023, 1, 30), &steps).to_string());
+
+        let (_, steps) = date(2020, 2, 29).calendar_duration_explained(date(2021, 3, 1));
+        assert_eq!("2020-02-29 + 1 year → 2021-03-01 (for 2021-02-29)",
+            Step::trace(date(2020, 2, 29), &steps).to_string());
+    }
+
+    #[test]
+    fn same_day() {
+        let (dur, steps) = date(2023, 1, 30).calendar_duration_explained(date(2023, 1, 30));
+        assert_eq!(CalendarDuration { years: 0, months: 0, days: 0 }, dur);
+        assert!(steps.is_empty());
+        assert_eq!("2023-01-30", Step::trace(date(2023, 1, 30), &steps).to_string());
+    }
+}
@@ -0,0 +1,71 @@
+//! Functions exported through UniFFI for use from Kotlin and Swift.
+
+use crate::{duration_between, CalendarDuration, CalendarDurationExt};
+use core::fmt::{self, Display, Formatter};
+
+/// Error returned to foreign-language callers.
+#[derive(Debug, Clone, PartialEq, Eq, uniffi::Error)]
+pub enum CalendarDurationFfiError {
+    /// The given year, month, and day don't make a valid date.
+    InvalidDate {
+        /// The year given.
+        year: i32,
+        /// The month given.
+        month: u8,
+        /// The day given.
+        day: u8,
+    },
+}
+
+impl Display for CalendarDurationFfiError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CalendarDurationFfiError::InvalidDate { year, month, day } => {
+                write!(f, "invalid date {year:04}-{month:02}-{day:02}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for CalendarDurationFfiError {}
+
+fn date(year: i32, month: u8, day: u8) -> Result<time::Date, CalendarDurationFfiError> {
+    time::Date::from_ymd(year, month, day)
+        .ok_or(CalendarDurationFfiError::InvalidDate { year, month, day })
+}
+
+/// Compute the calendar duration between two dates, given as year, month, and day. The order of
+/// the dates doesn't matter.
+#[uniffi::export]
+pub fn calendar_duration_between(
+    y1: i32, m1: u8, d1: u8,
+    y2: i32, m2: u8, d2: u8,
+) -> Result<CalendarDuration, CalendarDurationFfiError> {
+    Ok(duration_between(date(y1, m1, d1)?, date(y2, m2, d2)?))
+}
+
+/// Format a calendar duration in English, the same as its `Display` implementation.
+#[uniffi::export]
+pub fn format_calendar_duration(duration: CalendarDuration) -> String {
+    duration.to_string()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn between() {
+        let dur = calendar_duration_between(2020, 4, 8, 1988, 6, 16).unwrap();
+        assert_eq!(CalendarDuration { years: 31, months: 9, days: 23 }, dur);
+        assert_eq!("31 years, 9 months, 23 days", format_calendar_duration(dur));
+    }
+
+    #[test]
+    fn invalid_date() {
+        assert_eq!(Err(CalendarDurationFfiError::InvalidDate { year: 2023, month: 2, day: 29 }),
+            calendar_duration_between(2023, 2, 29, 2024, 1, 1));
+        assert_eq!("invalid date 2023-13-01",
+            calendar_duration_between(2020, 1, 1, 2023, 13, 1).unwrap_err().to_string());
+    }
+}
@@ -0,0 +1,597 @@
+use crate::approx::{approximate_length, DAY_UNITS, MONTH_UNITS};
+use crate::CalendarDuration;
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// The characters used to write the digits of numbers in formatted durations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Digits {
+    /// ASCII digits `0` through `9`.
+    #[default]
+    Ascii,
+
+    /// Arabic-Indic digits `٠` through `٩` (U+0660 through U+0669), as used with Arabic.
+    ArabicIndic,
+
+    /// Devanagari digits `०` through `९` (U+0966 through U+096F), as used with Hindi and other
+    /// languages written in Devanagari.
+    Devanagari,
+
+    /// Any other set of ten digits, from zero to nine.
+    Custom([char; 10]),
+}
+
+impl Digits {
+    /// Return the character for the given digit value, or `None` if it's not between 0 and 9.
+    pub fn digit(self, value: u8) -> Option<char> {
+        if value > 9 {
+            return None;
+        }
+        let base = match self {
+            Digits::Ascii => '0',
+            Digits::ArabicIndic => '\u{0660}',
+            Digits::Devanagari => '\u{0966}',
+            Digits::Custom(chars) => return Some(chars[usize::from(value)]),
+        };
+        char::from_u32(base as u32 + u32::from(value))
+    }
+
+    /// Write a number using these digits.
+    pub fn write_number(self, w: &mut impl Write, n: u64) -> fmt::Result {
+        if self == Digits::Ascii {
+            return write!(w, "{n}");
+        }
+        let mut buf = [0u8; 20];
+        let mut i = buf.len();
+        let mut n = n;
+        loop {
+            i -= 1;
+            buf[i] = (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        buf[i..].iter().try_for_each(|&d| w.write_char(self.digit(d).ok_or(fmt::Error)?))
+    }
+}
+
+/// Formats [`CalendarDuration`]s with configurable unit labels, separators, and digits, for
+/// languages other than English.
+///
+/// The default formatter gives the same output as the [`Display`] implementation of
+/// [`CalendarDuration`]: "31 years, 9 months, 23 days", "1 day", or "same day".
+///
+/// ```
+/// # use calendar_duration::{CalendarDuration, CalendarDurationFormatter, Digits};
+/// let fmt = CalendarDurationFormatter::new()
+///     .years("वर्ष", "वर्ष")
+///     .months("महीना", "महीने")
+///     .days("दिन", "दिन")
+///     .digits(Digits::Devanagari);
+/// let dur = CalendarDuration { years: 31, months: 9, days: 23 };
+/// assert_eq!("३१ वर्ष, ९ महीने, २३ दिन", fmt.format(&dur).to_string());
+/// ```
+///
+/// Durations can also be written in a single unit when they are short, like "18 months" rather
+/// than "1 year, 6 months", or "6 weeks" rather than "1 month, 11 days". See
+/// [`months_alone_below`](Self::months_alone_below) and the methods like it.
+#[derive(Debug, Clone)]
+pub struct CalendarDurationFormatter<'a> {
+    years: [&'a str; 2],
+    months: [&'a str; 2],
+    weeks: [&'a str; 2],
+    days: [&'a str; 2],
+    separator: &'a str,
+    conjunction: Option<&'a str>,
+    oxford_comma: bool,
+    zero: &'a str,
+    singular: fn(u64) -> bool,
+    digits: Digits,
+    // Approximate lengths of the single-unit thresholds.
+    days_below: Option<u64>,
+    weeks_below: Option<u64>,
+    months_below: Option<u64>,
+}
+
+impl Default for CalendarDurationFormatter<'_> {
+    fn default() -> Self {
+        Self {
+            years: ["year", "years"],
+            months: ["month", "months"],
+            weeks: ["week", "weeks"],
+            days: ["day", "days"],
+            separator: ", ",
+            conjunction: None,
+            oxford_comma: false,
+            zero: "same day",
+            singular: |n| n == 1,
+            digits: Digits::Ascii,
+            days_below: None,
+            weeks_below: None,
+            months_below: None,
+        }
+    }
+}
+
+impl<'a> CalendarDurationFormatter<'a> {
+    /// Make a formatter with the default English settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the singular and plural labels for years.
+    pub fn years(mut self, singular: &'a str, plural: &'a str) -> Self {
+        self.years = [singular, plural];
+        self
+    }
+
+    /// Set the singular and plural labels for months.
+    pub fn months(mut self, singular: &'a str, plural: &'a str) -> Self {
+        self.months = [singular, plural];
+        self
+    }
+
+    /// Set the singular and plural labels for weeks, which are only used for durations written in
+    /// weeks alone. See [`weeks_alone_below`](Self::weeks_alone_below).
+    pub fn weeks(mut self, singular: &'a str, plural: &'a str) -> Self {
+        self.weeks = [singular, plural];
+        self
+    }
+
+    /// Set the singular and plural labels for days.
+    pub fn days(mut self, singular: &'a str, plural: &'a str) -> Self {
+        self.days = [singular, plural];
+        self
+    }
+
+    /// Set the text written between components.
+    pub fn separator(mut self, separator: &'a str) -> Self {
+        self.separator = separator;
+        self
+    }
+
+    /// Set the text written between the last two components instead of the separator, like
+    /// `" and "` for "1 year, 2 months and 3 days", or `" et "` in French. By default the
+    /// separator is used throughout.
+    pub fn conjunction(mut self, conjunction: &'a str) -> Self {
+        self.conjunction = Some(conjunction);
+        self
+    }
+
+    /// Set whether the separator is kept before the conjunction when there are three
+    /// components, like "1 year, 2 months, and 3 days". The separator's trailing whitespace is
+    /// dropped, so that the conjunction's own is used. This is off by default, and does nothing
+    /// without a [`conjunction`](Self::conjunction).
+    pub fn oxford_comma(mut self, oxford_comma: bool) -> Self {
+        self.oxford_comma = oxford_comma;
+        self
+    }
+
+    /// Set which numbers take the singular label, for languages whose rule isn't English's,
+    /// where only 1 is singular. Zero components are never written, so it doesn't matter whether
+    /// a language treats 0 as singular or plural.
+    pub fn singular_when(mut self, singular: fn(u64) -> bool) -> Self {
+        self.singular = singular;
+        self
+    }
+
+    /// Set the text written for the zero duration.
+    pub fn zero(mut self, zero: &'a str) -> Self {
+        self.zero = zero;
+        self
+    }
+
+    /// Set the digits used to write numbers.
+    pub fn digits(mut self, digits: Digits) -> Self {
+        self.digits = digits;
+        self
+    }
+
+    /// Write durations shorter than the threshold in days alone, like "10 days" rather than
+    /// "1 week, 3 days" when weeks would otherwise be used.
+    ///
+    /// When more than one single-unit threshold applies, the smallest unit wins. Durations are
+    /// compared with the thresholds, and converted between units, using the average length of a
+    /// Gregorian month (about 30.44 days), rounding to the nearest whole unit. A duration which
+    /// would round to zero of the unit is written normally.
+    pub fn days_alone_below(mut self, threshold: CalendarDuration) -> Self {
+        self.days_below = Some(approximate_length(&threshold));
+        self
+    }
+
+    /// Write durations shorter than the threshold in weeks alone, like "6 weeks" rather than
+    /// "1 month, 11 days". See [`days_alone_below`](Self::days_alone_below) for how durations are
+    /// compared and converted.
+    pub fn weeks_alone_below(mut self, threshold: CalendarDuration) -> Self {
+        self.weeks_below = Some(approximate_length(&threshold));
+        self
+    }
+
+    /// Write durations shorter than the threshold in months alone, like "18 months" rather than
+    /// "1 year, 6 months". See [`days_alone_below`](Self::days_alone_below) for how durations are
+    /// compared and converted.
+    pub fn months_alone_below(mut self, threshold: CalendarDuration) -> Self {
+        self.months_below = Some(approximate_length(&threshold));
+        self
+    }
+
+    /// Return the number and labels of the single unit to write the duration in, if it is below
+    /// any of the thresholds.
+    fn single_unit(&self, d: &CalendarDuration) -> Option<(u64, [&'a str; 2])> {
+        let length = approximate_length(d);
+        let units = [
+            (self.days_below, DAY_UNITS, self.days),
+            (self.weeks_below, DAY_UNITS * 7, self.weeks),
+            (self.months_below, MONTH_UNITS, self.months),
+        ];
+        units.into_iter()
+            .find(|(threshold, _, _)| threshold.is_some_and(|t| length < t))
+            .map(|(_, unit, labels)| ((length + unit / 2) / unit, labels))
+            .filter(|&(n, _)| n > 0)
+    }
+
+    /// Format a duration. The result implements [`Display`].
+    pub fn format<'b>(&'b self, duration: &'b CalendarDuration) -> FormattedDuration<'b> {
+        FormattedDuration { formatter: self, duration }
+    }
+}
+
+/// A [`CalendarDuration`] being formatted with a [`CalendarDurationFormatter`].
+#[derive(Debug, Clone, Copy)]
+pub struct FormattedDuration<'a> {
+    formatter: &'a CalendarDurationFormatter<'a>,
+    duration: &'a CalendarDuration,
+}
+
+impl Display for FormattedDuration<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let fmt = self.formatter;
+        let d = self.duration;
+        if let Some((n, [singular, plural])) = fmt.single_unit(d) {
+            fmt.digits.write_number(f, n)?;
+            f.write_char(' ')?;
+            return f.write_str(if (fmt.singular)(n) { singular } else { plural });
+        }
+
+        let components = [
+            (u64::from(d.years), fmt.years),
+            (u64::from(d.months), fmt.months),
+            (u64::from(d.days), fmt.days),
+        ];
+        let count = components.iter().filter(|(n, _)| *n != 0).count();
+        if count == 0 {
+            return f.write_str(fmt.zero);
+        }
+
+        let components = components.into_iter().filter(|(n, _)| *n != 0);
+        for (i, (n, [singular, plural])) in components.enumerate() {
+            match (i, fmt.conjunction) {
+                (0, _) => (),
+                (i, Some(conjunction)) if i == count - 1 => {
+                    if fmt.oxford_comma && count > 2 {
+                        f.write_str(fmt.separator.trim_end())?;
+                    }
+                    f.write_str(conjunction)?;
+                }
+                _ => f.write_str(fmt.separator)?,
+            }
+            fmt.digits.write_number(f, n)?;
+            f.write_char(' ')?;
+            f.write_str(if (fmt.singular)(n) { singular } else { plural })?;
+        }
+        Ok(())
+    }
+}
+
+impl CalendarDuration {
+    /// Format the duration with every component zero-padded to a fixed width, like `031y 09m 23d`,
+    /// so that durations line up in columns of monospace text.
+    ///
+    /// Years are padded to three digits, and months and days to two. Zero components are always
+    /// included. See [`format_fixed_width`](Self::format_fixed_width) to choose the width of the
+    /// years.
+    #[cfg(feature = "alloc")]
+    pub fn format_fixed(&self) -> String {
+        self.format_fixed_width(3)
+    }
+
+    /// Format the duration with every component zero-padded to a fixed width, as with
+    /// [`format_fixed`](Self::format_fixed), with years padded to the given number of digits.
+    ///
+    /// Values with more digits than the width are written in full, so they won't line up.
+    #[cfg(feature = "alloc")]
+    pub fn format_fixed_width(&self, year_width: usize) -> String {
+        format!("{:0year_width$}y {:02}m {:02}d", self.years, self.months, self.days)
+    }
+}
+
+impl CalendarDuration {
+    /// Return the duration in a terse form for tables and status lines, like `31y 9m 23d`.
+    ///
+    /// Zero components are left out, except for the zero duration, which is `0d`. The result
+    /// implements [`Display`] without allocating, and honors width, fill, and alignment, which
+    /// default to the left as with strings:
+    ///
+    /// ```
+    /// # use calendar_duration::CalendarDuration;
+    /// let dur = CalendarDuration { years: 1, months: 0, days: 5 };
+    /// assert_eq!("1y 5d", dur.compact().to_string());
+    /// assert_eq!("[    1y 5d]", format!("[{:>9}]", dur.compact()));
+    /// ```
+    pub fn compact(&self) -> CompactDuration<'_> {
+        CompactDuration { duration: self }
+    }
+}
+
+/// A [`CalendarDuration`] written in the terse form given by [`CalendarDuration::compact`].
+#[derive(Debug, Clone, Copy)]
+pub struct CompactDuration<'a> {
+    duration: &'a CalendarDuration,
+}
+
+impl CompactDuration<'_> {
+    fn write_to(&self, w: &mut (impl Write + ?Sized)) -> fmt::Result {
+        let d = self.duration;
+        let components = [(d.years, 'y'), (d.months, 'm'), (d.days, 'd')];
+        let mut any = false;
+        for (n, unit) in components {
+            if n == 0 {
+                continue;
+            }
+            if any {
+                w.write_char(' ')?;
+            }
+            write!(w, "{n}{unit}")?;
+            any = true;
+        }
+        if !any {
+            w.write_str("0d")?;
+        }
+        Ok(())
+    }
+}
+
+/// Counts the characters written to it, to pad output without writing it to a buffer first.
+struct CharCount(usize);
+
+impl Write for CharCount {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.0 += s.chars().count();
+        Ok(())
+    }
+}
+
+/// Write to the formatter with its width, fill, and alignment, which default to the left as with
+/// strings. Unlike [`Formatter::pad`], this ignores the precision, so callers can give it their
+/// own meaning.
+pub(crate) fn pad(f: &mut Formatter<'_>, mut write: impl FnMut(&mut dyn Write) -> fmt::Result)
+    -> fmt::Result
+{
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let mut count = CharCount(0);
+    write(&mut count)?;
+    let padding = width.saturating_sub(count.0);
+    let (before, after) = match f.align() {
+        None | Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Right) => (padding, 0),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+    };
+    let fill = f.fill();
+    (0 .. before).try_for_each(|_| f.write_char(fill))?;
+    write(f)?;
+    (0 .. after).try_for_each(|_| f.write_char(fill))
+}
+
+impl Display for CompactDuration<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        pad(f, |w| self.write_to(w))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::ToString, vec::Vec};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn display_padding() {
+        let d = dur(31, 9, 23);
+        assert_eq!("[31 years, 9 months, 23 days   ]", format!("[{d:30}]"));
+        assert_eq!("[   31 years, 9 months, 23 days]", format!("[{d:>30}]"));
+        assert_eq!("[*31 years, 9 months, 23 days**]", format!("[{d:*^30}]"));
+        assert_eq!("[31 years, 9 months, 23 days]", format!("[{d:10}]"));
+        assert_eq!("[same day  ]", format!("[{:10}]", dur(0, 0, 0)));
+        assert_eq!("[   P31Y9M23D]", format!("[{d:>#12}]"));
+        assert_eq!("[    31 years]", format!("[{d:>12.1}]"));
+    }
+
+    #[test]
+    fn display_precision() {
+        let d = dur(31, 9, 23);
+        assert_eq!("31 years", format!("{d:.0}"));
+        assert_eq!("31 years", format!("{d:.1}"));
+        assert_eq!("31 years, 9 months", format!("{d:.2}"));
+        assert_eq!("31 years, 9 months, 23 days", format!("{d:.3}"));
+        assert_eq!("31 years, 9 months, 23 days", format!("{d:.4}"));
+        // The units left out are truncated, not rounded.
+        assert_eq!("1 year", format!("{:.1}", dur(1, 11, 30)));
+        // Zero units aren't counted.
+        assert_eq!("2 months", format!("{:.1}", dur(0, 2, 15)));
+        assert_eq!("1 year, 3 days", format!("{:.2}", dur(1, 0, 3)));
+        for precision in 0 .. 4 {
+            assert_eq!("same day", format!("{:.precision$}", dur(0, 0, 0)));
+        }
+        // The ISO 8601 form is always written in full.
+        assert_eq!("P31Y9M23D", format!("{d:#.1}"));
+    }
+
+    #[test]
+    fn default_matches_display() {
+        let fmt = CalendarDurationFormatter::new();
+        for d in [dur(31, 9, 23), dur(1, 0, 0), dur(0, 1, 1), dur(0, 0, 2), dur(0, 0, 0),
+            dur(0, 0, 45), dur(0, 18, 400)]
+        {
+            assert_eq!(d.to_string(), fmt.format(&d).to_string());
+        }
+        assert_eq!("45 days", dur(0, 0, 45).to_string());
+        assert_eq!("18 months, 400 days", dur(0, 18, 400).to_string());
+    }
+
+    #[test]
+    fn arabic_digits() {
+        let fmt = CalendarDurationFormatter::new()
+            .years("سنة", "سنة")
+            .months("شهر", "أشهر")
+            .days("يوم", "أيام")
+            .separator("، ")
+            .digits(Digits::ArabicIndic);
+        assert_eq!("\u{663}\u{661} سنة، \u{669} أشهر، \u{662}\u{663} أيام",
+            fmt.format(&dur(31, 9, 23)).to_string());
+        assert_eq!("\u{661}\u{660} سنة", fmt.format(&dur(10, 0, 0)).to_string());
+    }
+
+    #[test]
+    fn devanagari_digits() {
+        let fmt = CalendarDurationFormatter::new()
+            .years("वर्ष", "वर्ष")
+            .months("महीना", "महीने")
+            .days("दिन", "दिन")
+            .digits(Digits::Devanagari);
+        assert_eq!("\u{969}\u{967} वर्ष, \u{96F} महीने, \u{968}\u{969} दिन",
+            fmt.format(&dur(31, 9, 23)).to_string());
+        assert_eq!("\u{967}\u{966}\u{966} वर्ष", fmt.format(&dur(100, 0, 0)).to_string());
+    }
+
+    #[test]
+    fn custom_digits() {
+        let fullwidth = ['０', '１', '２', '３', '４', '５', '６', '７', '８', '９'];
+        let fmt = CalendarDurationFormatter::new().digits(Digits::Custom(fullwidth));
+        assert_eq!("３１ years, １ month", fmt.format(&dur(31, 1, 0)).to_string());
+        assert_eq!(Some('٠'), Digits::ArabicIndic.digit(0));
+        assert_eq!(Some('९'), Digits::Devanagari.digit(9));
+        assert_eq!(Some('９'), Digits::Custom(fullwidth).digit(9));
+        assert_eq!(None, Digits::Ascii.digit(10));
+        assert_eq!(None, Digits::Custom(fullwidth).digit(10));
+    }
+
+    #[test]
+    fn single_unit_thresholds() {
+        let fmt = CalendarDurationFormatter::new()
+            .weeks_alone_below(dur(0, 3, 0))
+            .months_alone_below(dur(2, 0, 0));
+        let format = |d| fmt.format(&d).to_string();
+
+        assert_eq!("18 months", format(dur(1, 6, 0)));
+        assert_eq!("23 months", format(dur(1, 11, 0)));
+        assert_eq!("24 months", format(dur(1, 11, 29)));
+        assert_eq!("2 years", format(dur(2, 0, 0)));
+        assert_eq!("2 years, 1 day", format(dur(2, 0, 1)));
+
+        assert_eq!("6 weeks", format(dur(0, 1, 11)));
+        assert_eq!("13 weeks", format(dur(0, 2, 30)));
+        assert_eq!("3 months", format(dur(0, 3, 0)));
+        assert_eq!("1 week", format(dur(0, 0, 7)));
+        assert_eq!("1 week", format(dur(0, 0, 10)));
+        assert_eq!("2 weeks", format(dur(0, 0, 11)));
+
+        // Too short to round to a whole week.
+        assert_eq!("3 days", format(dur(0, 0, 3)));
+        assert_eq!("same day", format(dur(0, 0, 0)));
+
+        // The smallest unit wins.
+        let fmt = fmt.days_alone_below(dur(0, 0, 14)).weeks("semaine", "semaines");
+        assert_eq!("13 days", fmt.format(&dur(0, 0, 13)).to_string());
+        assert_eq!("2 semaines", fmt.format(&dur(0, 0, 14)).to_string());
+        assert_eq!("1 day", fmt.format(&dur(0, 0, 1)).to_string());
+    }
+
+    #[test]
+    fn french() {
+        let fmt = CalendarDurationFormatter::new()
+            .years("an", "ans")
+            .months("mois", "mois")
+            .days("jour", "jours")
+            .conjunction(" et ")
+            .zero("le même jour")
+            .singular_when(|n| n <= 1);
+        let format = |d| fmt.format(&d).to_string();
+        assert_eq!("31 ans, 9 mois et 23 jours", format(dur(31, 9, 23)));
+        assert_eq!("1 an et 1 jour", format(dur(1, 0, 1)));
+        assert_eq!("2 mois", format(dur(0, 2, 0)));
+        assert_eq!("le même jour", format(dur(0, 0, 0)));
+    }
+
+    #[test]
+    fn conjunction() {
+        let fmt = CalendarDurationFormatter::new().conjunction(" and ");
+        assert_eq!("1 year, 2 months and 3 days", fmt.format(&dur(1, 2, 3)).to_string());
+        assert_eq!("1 year and 3 days", fmt.format(&dur(1, 0, 3)).to_string());
+        assert_eq!("3 days", fmt.format(&dur(0, 0, 3)).to_string());
+
+        let fmt = fmt.oxford_comma(true);
+        assert_eq!("1 year, 2 months, and 3 days", fmt.format(&dur(1, 2, 3)).to_string());
+        assert_eq!("2 months and 3 days", fmt.format(&dur(0, 2, 3)).to_string());
+        assert_eq!("same day", fmt.format(&dur(0, 0, 0)).to_string());
+
+        // Without a conjunction, the Oxford comma does nothing.
+        let fmt = CalendarDurationFormatter::new().oxford_comma(true).separator("; ");
+        assert_eq!("1 year; 2 months; 3 days", fmt.format(&dur(1, 2, 3)).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn fixed() {
+        assert_eq!("031y 09m 23d", dur(31, 9, 23).format_fixed());
+        assert_eq!("000y 00m 00d", dur(0, 0, 0).format_fixed());
+        assert_eq!("0001y 00m 05d", dur(1, 0, 5).format_fixed_width(4));
+
+        let values = [dur(0, 0, 0), dur(1, 0, 0), dur(0, 11, 30), dur(2024, 1, 1), dur(9999, 9, 9)];
+        let widths = values.iter()
+            .map(|d| d.format_fixed_width(4).len())
+            .collect::<Vec<_>>();
+        assert!(widths.iter().all(|&w| w == widths[0]), "{widths:?}");
+
+        let widths = values[..3].iter()
+            .map(|d| d.format_fixed().len())
+            .collect::<Vec<_>>();
+        assert!(widths.iter().all(|&w| w == widths[0]), "{widths:?}");
+    }
+
+    #[test]
+    fn compact() {
+        let compact = |years, months, days| dur(years, months, days).compact().to_string();
+        assert_eq!("31y 9m 23d", compact(31, 9, 23));
+        assert_eq!("31y 9m", compact(31, 9, 0));
+        assert_eq!("31y 23d", compact(31, 0, 23));
+        assert_eq!("9m 23d", compact(0, 9, 23));
+        assert_eq!("1y", compact(1, 0, 0));
+        assert_eq!("1m", compact(0, 1, 0));
+        assert_eq!("1d", compact(0, 0, 1));
+        assert_eq!("0d", compact(0, 0, 0));
+        assert_eq!("18m 400d", compact(0, 18, 400));
+    }
+
+    #[test]
+    fn compact_padding() {
+        let d = dur(1, 2, 3);
+        assert_eq!("1y 2m 3d", format!("{:4}", d.compact()));
+        assert_eq!("1y 2m 3d  ", format!("{:10}", d.compact()));
+        assert_eq!("  1y 2m 3d", format!("{:>10}", d.compact()));
+        assert_eq!("*1y 2m 3d**", format!("{:*^11}", d.compact()));
+        assert_eq!("    0d", format!("{:>6}", dur(0, 0, 0).compact()));
+
+        let column = [dur(31, 9, 23), dur(0, 0, 5), dur(2, 0, 0)]
+            .iter()
+            .map(|d| format!("{:>10}|", d.compact()))
+            .collect::<Vec<_>>();
+        assert_eq!(["31y 9m 23d|", "        5d|", "        2y|"], column.as_slice());
+    }
+}
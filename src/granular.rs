@@ -0,0 +1,50 @@
+use crate::{CalendarDuration, CalendarDurationError, CalendarDurationExt};
+
+/// A unit of a [`CalendarDuration`], for choosing which units a duration is expressed in. See
+/// [`CalendarDurationExt::calendar_duration_from_in`].
+///
+/// Units are ordered by size, so `DurationUnit::Days < DurationUnit::Years`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DurationUnit {
+    /// Days.
+    Days,
+
+    /// Months.
+    Months,
+
+    /// Years.
+    Years,
+}
+
+/// Compute the duration between two dates, in either order, with larger units than `largest`
+/// folded into it and smaller units than `smallest` truncated.
+pub(crate) fn duration_in<D: CalendarDurationExt>(
+    a: D,
+    b: D,
+    largest: DurationUnit,
+    smallest: DurationUnit,
+) -> Result<CalendarDuration, CalendarDurationError> {
+    let smallest = smallest.min(largest);
+    let mut dur = match largest {
+        DurationUnit::Years => a.try_calendar_duration_from(b)?,
+        DurationUnit::Months => {
+            let dur = a.try_calendar_duration_from(b)?;
+            let months = dur.years.checked_mul(12)
+                .and_then(|months| months.checked_add(dur.months))
+                .ok_or(CalendarDurationError::OutOfRange)?;
+            CalendarDuration { years: 0, months, days: dur.days }
+        }
+        DurationUnit::Days => {
+            let days = u32::try_from(a.days_since(b).unsigned_abs())
+                .map_err(|_| CalendarDurationError::OutOfRange)?;
+            CalendarDuration { years: 0, months: 0, days }
+        }
+    };
+    if smallest > DurationUnit::Days {
+        dur.days = 0;
+    }
+    if smallest > DurationUnit::Months {
+        dur.months = 0;
+    }
+    Ok(dur)
+}
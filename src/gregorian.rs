@@ -0,0 +1,189 @@
+//! Arithmetic on proleptic Gregorian calendar dates, independent of any date library.
+//!
+//! These are useful for implementing [`CalendarDurationExt`](crate::CalendarDurationExt) for date
+//! types which don't come with their own calendar math, such as a count of days since an epoch.
+//!
+//! Day numbers count days relative to 1970-01-01, which is day 0. The conversions use Howard
+//! Hinnant's `days_from_civil` / `civil_from_days` algorithms, which are exact for the whole `i32`
+//! year range.
+//!
+//! ```
+//! # use calendar_duration::gregorian::{civil_from_days, days_from_civil};
+//! assert_eq!(0, days_from_civil(1970, 1, 1));
+//! assert_eq!((2000, 3, 1), civil_from_days(days_from_civil(2000, 2, 29) + 1));
+//! ```
+
+/// Return whether the given year is a leap year in the proleptic Gregorian calendar.
+pub const fn is_leap_year(y: i32) -> bool {
+    y % 4 == 0 && (y % 100 != 0 || y % 400 == 0)
+}
+
+/// Return the number of days in the given month (one-based) of the given year.
+///
+/// Panics if the month is not between 1 and 12. See [`month_length`] for a version which doesn't.
+#[cfg(not(feature = "strict"))]
+pub fn days_in_month(y: i32, m: u8) -> u8 {
+    assert!((1 ..= 12).contains(&m), "month {m} out of range");
+    month_length(y, m)
+}
+
+/// Return the number of days in the given month (one-based) of the given year, without checking
+/// the month: months out of range have 31 days.
+pub const fn month_length(y: i32, m: u8) -> u8 {
+    match m {
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(y) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Return the number of days between 1970-01-01 and the given date. Dates before 1970 give
+/// negative numbers.
+///
+/// The date is not checked for validity: days past the end of the month count into the next one.
+pub fn days_from_civil(y: i32, m: u8, d: u8) -> i64 {
+    let y = i64::from(y) - i64::from(m <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let m = i64::from(m);
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + i64::from(d) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146097 + doe - 719468
+}
+
+/// Return the (year, month, day) for the given number of days since 1970-01-01.
+pub fn civil_from_days(z: i64) -> (i32, u8, u8) {
+    let z = z + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let d = (doy - (153 * mp + 2) / 5 + 1) as u8;
+    let m = if mp < 10 { mp + 3 } else { mp - 9 } as u8;
+    let y = yoe + era * 400 + i64::from(m <= 2);
+    (y as i32, m, d)
+}
+
+/// Return the (year, month, day) for the given number of days since 1970-01-01, or `None` if the
+/// year doesn't fit in an `i32`.
+pub(crate) fn checked_civil_from_days(z: i64) -> Option<(i32, u8, u8)> {
+    // The year is computed in 64 bits and truncated, so check it round trips.
+    let (y, m, d) = civil_from_days(z);
+    (days_from_civil(y, m, d) == z).then_some((y, m, d))
+}
+
+/// Return the day of the week for the given number of days since 1970-01-01, with 0 being Monday
+/// and 6 being Sunday.
+pub fn weekday_from_days(z: i64) -> u8 {
+    // 1970-01-01 was a Thursday.
+    (z + 3).rem_euclid(7) as u8
+}
+
+/// Return the ISO 8601 week-numbering year and week number (1 through 53) for the given number of
+/// days since 1970-01-01.
+pub(crate) fn iso_week_from_days(z: i64) -> (i32, u8) {
+    // The ISO week belongs to whichever year its Thursday is in.
+    let thursday = z - i64::from(weekday_from_days(z)) + 3;
+    let (year, _, _) = civil_from_days(thursday);
+    let week = (thursday - days_from_civil(year, 1, 1)) / 7 + 1;
+    (year, week as u8)
+}
+
+/// Return the day number of the Monday starting the given ISO 8601 week.
+pub(crate) fn iso_week_start(year: i32, week: u8) -> i64 {
+    // January 4th is always in week 1.
+    let jan4 = days_from_civil(year, 1, 4);
+    jan4 - i64::from(weekday_from_days(jan4)) + 7 * (i64::from(week) - 1)
+}
+
+/// Return the number of ISO 8601 weeks (52 or 53) in the given week-numbering year.
+pub(crate) fn iso_weeks_in_year(year: i32) -> u8 {
+    iso_week_from_days(days_from_civil(year, 12, 28)).1
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+        assert!(is_leap_year(0));
+        assert!(is_leap_year(-4));
+        assert!(!is_leap_year(-100));
+        assert!(is_leap_year(-400));
+    }
+
+    #[test]
+    fn month_lengths() {
+        assert_eq!(31, month_length(2023, 1));
+        assert_eq!(28, month_length(2023, 2));
+        assert_eq!(29, month_length(2024, 2));
+        assert_eq!(28, month_length(1900, 2));
+        assert_eq!(30, month_length(2023, 4));
+        assert_eq!(31, month_length(2023, 12));
+        assert_eq!(31, month_length(2023, 13));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn checked_month() {
+        assert_eq!(29, days_in_month(2024, 2));
+        assert_eq!(31, days_in_month(2023, 12));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic]
+    fn month_out_of_range() {
+        days_in_month(2023, 13);
+    }
+
+    #[test]
+    fn fixed_points() {
+        let points = [
+            ((1970, 1, 1), 0),
+            ((1970, 1, 2), 1),
+            ((1969, 12, 31), -1),
+            ((2000, 3, 1), 11017),
+            ((2000, 2, 29), 11016),
+            ((1900, 1, 1), -25567),
+            ((0, 3, 1), -719468),
+            ((-1, 12, 31), -719529),
+        ];
+        for ((y, m, d), n) in points {
+            assert_eq!(n, days_from_civil(y, m, d), "{y}-{m}-{d}");
+            assert_eq!((y, m, d), civil_from_days(n), "{n}");
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let mut expected = (1899, 12, 31);
+        for n in days_from_civil(1899, 12, 31)..days_from_civil(2101, 1, 1) {
+            assert_eq!(expected, civil_from_days(n));
+            assert_eq!(n, days_from_civil(expected.0, expected.1, expected.2));
+            let (y, m, d) = expected;
+            expected = if d < month_length(y, m) {
+                (y, m, d + 1)
+            } else if m < 12 {
+                (y, m + 1, 1)
+            } else {
+                (y + 1, 1, 1)
+            };
+        }
+    }
+
+    #[test]
+    fn weekdays() {
+        // 1970-01-01 was a Thursday, and 2000-01-01 was a Saturday.
+        assert_eq!(3, weekday_from_days(0));
+        assert_eq!(5, weekday_from_days(days_from_civil(2000, 1, 1)));
+        assert_eq!(2, weekday_from_days(-1));
+    }
+}
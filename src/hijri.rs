@@ -0,0 +1,228 @@
+use crate::gregorian::{days_from_civil, weekday_from_days};
+use crate::CalendarDurationExt;
+use core::fmt::{self, Display, Formatter};
+
+/// A date in the tabular Islamic (Hijri) calendar.
+///
+/// This is the arithmetic version of the calendar, with months alternating between 30 and 29
+/// days, and 11 leap years in every 30-year cycle in which the last month has 30 days instead of
+/// 29 (years 2, 5, 7, 10, 13, 16, 18, 21, 24, 26, and 29 of the cycle). It uses the civil epoch:
+/// 1 Muharram 1 AH is July 19th, 622 in the proleptic Gregorian calendar. Dates in the calendar
+/// as observed can differ from it by a day or two.
+///
+/// Months in the calendar have 29 or 30 days, so when an anniversary falls on the 30th of a month
+/// with only 29, it moves to the 1st of the next month.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HijriDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// The number of days from 1970-01-01 to the day before 1 Muharram 1 AH.
+fn epoch() -> i64 {
+    days_from_civil(622, 7, 19) - 1
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (14 + 11 * i64::from(year)).rem_euclid(30) < 11
+}
+
+fn days_in_month(year: i32, month: u8) -> u8 {
+    if month % 2 == 1 || (month == 12 && is_leap_year(year)) {
+        30
+    } else {
+        29
+    }
+}
+
+/// The number of days in the years before the given one, since the epoch.
+fn days_before_year(year: i32) -> i64 {
+    let y = i64::from(year);
+    (y - 1) * 354 + (3 + 11 * y).div_euclid(30)
+}
+
+impl HijriDate {
+    /// Make a date from the year, month, and day, if it is valid.
+    pub fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if !(1 ..= 12).contains(&month) || day < 1 || day > days_in_month(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// The year (AH).
+    pub fn year(self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 (Muharram) to 12 (Dhu al-Hijjah).
+    pub fn month(self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month.
+    pub fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Return the number of days from 1970-01-01 to the date.
+    pub fn to_epoch_day(self) -> i64 {
+        let month = i64::from(self.month);
+        epoch() + days_before_year(self.year) + 29 * (month - 1) + month / 2
+            + i64::from(self.day)
+    }
+
+    /// Return the date the given number of days after 1970-01-01, or `None` if its year can't be
+    /// represented.
+    pub fn from_epoch_day(days: i64) -> Option<Self> {
+        let n = days - epoch();
+        // Estimate the year from the mean year length, then correct it.
+        let mut year = i32::try_from((30 * n + 10646).div_euclid(10631)).ok()?;
+        while days_before_year(year) >= n {
+            year = year.checked_sub(1)?;
+        }
+        while days_before_year(year.checked_add(1)?) < n {
+            year += 1;
+        }
+        let mut day = n - days_before_year(year);
+        let mut month = 1;
+        while day > i64::from(days_in_month(year, month)) {
+            day -= i64::from(days_in_month(year, month));
+            month += 1;
+        }
+        Some(Self { year, month, day: day as u8 })
+    }
+}
+
+impl Display for HijriDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}-{:02} AH", self.year, self.month, self.day)
+    }
+}
+
+impl CalendarDurationExt for HijriDate {
+    fn ymd(self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+        Self::new(y, m, d)
+    }
+
+    fn checked_from_ymd_or_next(y: i32, m: u8, d: u8) -> Option<Self> {
+        match Self::new(y, m, d) {
+            Some(date) => Some(date),
+            None if (1 ..= 12).contains(&m) && d > days_in_month(y, m) => {
+                if m == 12 {
+                    Self::new(y.checked_add(1)?, 1, 1)
+                } else {
+                    Self::new(y, m + 1, 1)
+                }
+            }
+            None => None,
+        }
+    }
+
+    fn last_day_of_month(y: i32, m: u8) -> u8 {
+        days_in_month(y, m)
+    }
+
+    #[cfg(not(feature = "strict"))]
+    fn succ(self) -> Self {
+        self.checked_succ().expect("date out of range")
+    }
+
+    fn checked_succ(self) -> Option<Self> {
+        Self::checked_from_ymd_or_next(self.year, self.month, self.day + 1)
+    }
+
+    fn min_value() -> Self {
+        Self { year: i32::MIN, month: 1, day: 1 }
+    }
+
+    fn max_value() -> Self {
+        Self { year: i32::MAX, month: 12, day: days_in_month(i32::MAX, 12) }
+    }
+
+    fn to_day_number(self) -> i64 {
+        self.to_epoch_day()
+    }
+
+    fn from_day_number(days: i64) -> Option<Self> {
+        Self::from_epoch_day(days)
+    }
+
+    fn days_since(self, other: Self) -> i64 {
+        self.to_epoch_day() - other.to_epoch_day()
+    }
+
+    fn weekday_from_monday(self) -> u8 {
+        weekday_from_days(self.to_epoch_day())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CalendarDuration;
+
+    #[test]
+    fn conversions() {
+        assert_eq!(days_from_civil(622, 7, 19), HijriDate::new(1, 1, 1).unwrap().to_epoch_day());
+        assert_eq!(days_from_civil(2023, 7, 19),
+            HijriDate::new(1445, 1, 1).unwrap().to_epoch_day());
+        assert_eq!(days_from_civil(2023, 3, 23),
+            HijriDate::new(1444, 9, 1).unwrap().to_epoch_day());
+        assert_eq!(HijriDate::new(1389, 10, 22), HijriDate::from_epoch_day(0));
+
+        for n in -10000 .. 20000 {
+            let date = HijriDate::from_epoch_day(n).unwrap();
+            assert_eq!(n, date.to_epoch_day(), "{date}");
+            assert_eq!(HijriDate::from_epoch_day(n + 1), date.checked_succ(), "{date}");
+        }
+    }
+
+    #[test]
+    fn months() {
+        assert_eq!(None, HijriDate::new(1444, 2, 30));
+        assert!(HijriDate::new(1444, 1, 30).is_some());
+        // 1445 is the 5th year of its cycle, so it is a leap year.
+        assert!(HijriDate::new(1445, 12, 30).is_some());
+        assert_eq!(None, HijriDate::new(1444, 12, 30));
+        assert_eq!(HijriDate::new(1444, 3, 1), HijriDate::checked_from_ymd_or_next(1444, 2, 30));
+        assert_eq!(HijriDate::new(1445, 1, 1), HijriDate::checked_from_ymd_or_next(1444, 12, 30));
+    }
+
+    #[test]
+    fn weekdays() {
+        let date = |y, m, d| HijriDate::new(y, m, d).unwrap();
+        // 1 Muharram 1445 was Wednesday, July 19th, 2023, 1 Ramadan 1444 was Thursday, March 23rd,
+        // 2023, and the epoch was a Friday.
+        assert_eq!(2, date(1445, 1, 1).weekday_from_monday());
+        assert_eq!(3, date(1444, 9, 1).weekday_from_monday());
+        assert_eq!(4, date(1, 1, 1).weekday_from_monday());
+        for n in -1000 .. 1000 {
+            let date = HijriDate::from_epoch_day(n).unwrap();
+            assert_eq!(weekday_from_days(n), date.weekday_from_monday(), "{date}");
+        }
+
+        // A week from Wednesday to Wednesday has 5 business days.
+        assert_eq!(5, date(1445, 1, 1).business_days_from(date(1445, 1, 8)));
+        assert_eq!(20, date(1444, 9, 1).business_days_from(date(1444, 9, 29)));
+    }
+
+    #[test]
+    fn add_days() {
+        let date = |y, m, d| HijriDate::new(y, m, d).unwrap();
+        let days = |days| CalendarDuration { years: 0, months: 0, days };
+        // Muharram has 30 days, and Safar 29.
+        assert_eq!(Some(date(1445, 2, 1)), date(1445, 1, 30).add_calendar_duration(&days(1)));
+        assert_eq!(Some(date(1445, 3, 2)), date(1445, 2, 29).add_calendar_duration(&days(2)));
+        assert_eq!(Some(date(1445, 2, 29)), date(1445, 3, 2).sub_calendar_duration(&days(2)));
+        assert_eq!(Some(date(1446, 1, 1)), date(1445, 12, 30).add_calendar_duration(&days(1)));
+        assert_eq!(Some(date(1445, 1, 1)), date(1444, 1, 1).add_calendar_duration(&days(354)));
+        let month = CalendarDuration { years: 0, months: 1, days: 0 };
+        assert_eq!(Some(date(1446, 1, 30)), date(1445, 12, 30).add_calendar_duration(&month));
+    }
+}
@@ -0,0 +1,398 @@
+//! Built-in [`HolidayCalendar`] implementations.
+//!
+//! All of these are computed from the rules that define them (fixed dates, "nth weekday of the
+//! month" rules, and the date of Easter), so they work for any year rather than only for a baked-in
+//! range. One-off holidays which were declared individually are listed explicitly.
+
+use crate::gregorian::{civil_from_days, days_from_civil, month_length, weekday_from_days};
+use crate::HolidayCalendar;
+use alloc::vec::Vec;
+
+const MONDAY: u8 = 0;
+const THURSDAY: u8 = 3;
+const FRIDAY: u8 = 4;
+const SATURDAY: u8 = 5;
+const SUNDAY: u8 = 6;
+
+/// United States federal holidays, as observed by federal employees.
+///
+/// A holiday falling on a Saturday is observed on the preceding Friday, and one falling on a
+/// Sunday is observed on the following Monday; only the observed day counts as a holiday. This
+/// means New Year's Day can be observed on December 31 of the previous year.
+///
+/// Martin Luther King Jr. Day is included from 1986 and Juneteenth from 2021. Veterans Day is on
+/// the fourth Monday of October from 1971 through 1977. Years before 1971 are computed using the
+/// rules of the Uniform Monday Holiday Act regardless. Inauguration Day is not included.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct UsFederal;
+
+impl UsFederal {
+    /// Return the observed holidays which fall in the given year, in order.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(i32, u8, u8)> {
+        let mut observed = Self::observed(year).collect::<Vec<_>>();
+        observed.sort_unstable();
+        observed
+    }
+
+    /// Return the observed holidays which fall in the given year, in no particular order.
+    fn observed(year: i32) -> impl Iterator<Item = (i32, u8, u8)> {
+        let veterans_day = if (1971..=1977).contains(&year) {
+            (year, 10, nth_weekday(year, 10, MONDAY, 4))
+        } else {
+            (year, 11, 11)
+        };
+        let nominal = [
+            Some((year, 1, 1)),
+            (year >= 1986).then(|| (year, 1, nth_weekday(year, 1, MONDAY, 3))),
+            Some((year, 2, nth_weekday(year, 2, MONDAY, 3))),
+            Some((year, 5, last_weekday(year, 5, MONDAY))),
+            (year >= 2021).then_some((year, 6, 19)),
+            Some((year, 7, 4)),
+            Some((year, 9, nth_weekday(year, 9, MONDAY, 1))),
+            Some((year, 10, nth_weekday(year, 10, MONDAY, 2))),
+            Some(veterans_day),
+            Some((year, 11, nth_weekday(year, 11, THURSDAY, 4))),
+            Some((year, 12, 25)),
+            // Observed on December 31 when it falls on a Saturday.
+            year.checked_add(1).map(|next| (next, 1, 1)),
+        ];
+
+        nominal
+            .into_iter()
+            .flatten()
+            .map(|(y, m, d)| {
+                let days = days_from_civil(y, m, d);
+                match weekday_from_days(days) {
+                    SATURDAY => civil_from_days(days - 1),
+                    SUNDAY => civil_from_days(days + 1),
+                    _ => (y, m, d),
+                }
+            })
+            .filter(move |&(y, _, _)| y == year)
+    }
+}
+
+impl HolidayCalendar for UsFederal {
+    fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+        Self::observed(ymd.0).any(|holiday| holiday == ymd)
+    }
+}
+
+/// Bank holidays in England and Wales.
+///
+/// When Christmas Day, Boxing Day, or New Year's Day falls on a weekend, the substitute day on the
+/// following weekday is the bank holiday. The early May bank holiday is included from 1978, and
+/// the one-off bank holidays and moved holidays proclaimed since 1977 (jubilees, royal weddings,
+/// the millennium, and so on) are included.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct EnglandAndWales;
+
+impl EnglandAndWales {
+    /// Return the bank holidays which fall in the given year, in order.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(i32, u8, u8)> {
+        let mut days = Self::holidays(year).collect::<Vec<_>>();
+        days.sort_unstable();
+        days
+    }
+
+    /// Return the bank holidays which fall in the given year, in no particular order.
+    fn holidays(year: i32) -> impl Iterator<Item = (i32, u8, u8)> {
+        let (m, d) = easter_sunday(year);
+        let easter = days_from_civil(year, m, d);
+
+        let new_year = match weekday_from_days(days_from_civil(year, 1, 1)) {
+            SATURDAY => (year, 1, 3),
+            SUNDAY => (year, 1, 2),
+            _ => (year, 1, 1),
+        };
+
+        let early_may = match year {
+            ..=1977 => None,
+            1995 | 2020 => Some((year, 5, 8)),
+            _ => Some((year, 5, nth_weekday(year, 5, MONDAY, 1))),
+        };
+
+        let spring = match year {
+            2002 | 2012 => (year, 6, 4),
+            2022 => (year, 6, 2),
+            _ => (year, 5, last_weekday(year, 5, MONDAY)),
+        };
+
+        let (christmas, boxing_day) = match weekday_from_days(days_from_civil(year, 12, 25)) {
+            FRIDAY => (25, 28),
+            SATURDAY => (27, 28),
+            SUNDAY => (27, 26),
+            _ => (25, 26),
+        };
+
+        let one_off = [
+            (1977, 6, 7),
+            (1981, 7, 29),
+            (1999, 12, 31),
+            (2002, 6, 3),
+            (2011, 4, 29),
+            (2012, 6, 5),
+            (2022, 6, 3),
+            (2022, 9, 19),
+            (2023, 5, 8),
+        ]
+        .into_iter()
+        .filter(move |&(y, _, _)| y == year);
+
+        [
+            Some(new_year),
+            Some(civil_from_days(easter - 2)),
+            Some(civil_from_days(easter + 1)),
+            early_may,
+            Some(spring),
+            Some((year, 8, last_weekday(year, 8, MONDAY))),
+            Some((year, 12, christmas)),
+            Some((year, 12, boxing_day)),
+        ]
+        .into_iter()
+        .flatten()
+        .chain(one_off)
+    }
+}
+
+impl HolidayCalendar for EnglandAndWales {
+    fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+        Self::holidays(ymd.0).any(|holiday| holiday == ymd)
+    }
+}
+
+/// Closing days of the TARGET payment system used for euro settlement (and hence for most euro
+/// money-market and bond conventions).
+///
+/// The closing days are New Year's Day, Good Friday, Easter Monday, 1 May, Christmas Day, and 26
+/// December, with no substitution when they fall on a weekend. In 2000 and 2001, 31 December was
+/// also a closing day. The set of closing days in force since 2002 is used for all other years.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Target;
+
+impl Target {
+    /// Return the closing days which fall in the given year, in order.
+    pub fn holidays_in_year(&self, year: i32) -> Vec<(i32, u8, u8)> {
+        let mut days = Self::closing_days(year).collect::<Vec<_>>();
+        days.sort_unstable();
+        days
+    }
+
+    /// Return the closing days which fall in the given year, in no particular order.
+    fn closing_days(year: i32) -> impl Iterator<Item = (i32, u8, u8)> {
+        let (m, d) = easter_sunday(year);
+        let easter = days_from_civil(year, m, d);
+        [
+            Some((year, 1, 1)),
+            Some(civil_from_days(easter - 2)),
+            Some(civil_from_days(easter + 1)),
+            Some((year, 5, 1)),
+            Some((year, 12, 25)),
+            Some((year, 12, 26)),
+            (year == 2000 || year == 2001).then_some((year, 12, 31)),
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl HolidayCalendar for Target {
+    fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+        Self::closing_days(ymd.0).any(|day| day == ymd)
+    }
+}
+
+/// Return the (month, day) of Western (Gregorian) Easter Sunday in the given year.
+///
+/// This is the anonymous Gregorian algorithm (Meeus/Jones/Butcher).
+fn easter_sunday(year: i32) -> (u8, u8) {
+    let a = year.rem_euclid(19);
+    let b = year.div_euclid(100);
+    let c = year.rem_euclid(100);
+    let d = b / 4;
+    let e = b % 4;
+    let f = (b + 8) / 25;
+    let g = (b - f + 1) / 3;
+    let h = (19 * a + b - d - g + 15).rem_euclid(30);
+    let i = c / 4;
+    let k = c % 4;
+    let l = (32 + 2 * e + 2 * i - h - k).rem_euclid(7);
+    let m = (a + 11 * h + 22 * l) / 451;
+    let month = (h + l - 7 * m + 114) / 31;
+    let day = (h + l - 7 * m + 114) % 31 + 1;
+    (month as u8, day as u8)
+}
+
+/// Return the day of the month of the `n`th (one-based) given weekday in a month.
+fn nth_weekday(y: i32, m: u8, weekday: u8, n: u8) -> u8 {
+    let first = weekday_from_days(days_from_civil(y, m, 1));
+    1 + (weekday + 7 - first) % 7 + 7 * (n - 1)
+}
+
+/// Return the day of the month of the last given weekday in a month.
+fn last_weekday(y: i32, m: u8, weekday: u8) -> u8 {
+    let last = month_length(y, m);
+    let last_weekday = weekday_from_days(days_from_civil(y, m, last));
+    last - (last_weekday + 7 - weekday) % 7
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn easter() {
+        assert_eq!((4, 23), easter_sunday(2000));
+        assert_eq!((3, 31), easter_sunday(2024));
+        assert_eq!((4, 20), easter_sunday(2025));
+        assert_eq!((3, 23), easter_sunday(2008));
+        assert_eq!((4, 25), easter_sunday(1943));
+    }
+
+    #[test]
+    fn us_federal_2024() {
+        assert_eq!(
+            vec![
+                (2024, 1, 1),
+                (2024, 1, 15),
+                (2024, 2, 19),
+                (2024, 5, 27),
+                (2024, 6, 19),
+                (2024, 7, 4),
+                (2024, 9, 2),
+                (2024, 10, 14),
+                (2024, 11, 11),
+                (2024, 11, 28),
+                (2024, 12, 25),
+            ],
+            UsFederal.holidays_in_year(2024));
+    }
+
+    #[test]
+    fn us_federal_2000() {
+        // New Year's Day 2000 was a Saturday, so it was observed on 1999-12-31, and Veterans Day
+        // was also a Saturday.
+        assert_eq!(
+            vec![
+                (2000, 1, 17),
+                (2000, 2, 21),
+                (2000, 5, 29),
+                (2000, 7, 4),
+                (2000, 9, 4),
+                (2000, 10, 9),
+                (2000, 11, 10),
+                (2000, 11, 23),
+                (2000, 12, 25),
+            ],
+            UsFederal.holidays_in_year(2000));
+        assert!(UsFederal.is_holiday((1999, 12, 31)));
+        assert!(!UsFederal.is_holiday((2000, 1, 1)));
+    }
+
+    #[test]
+    fn england_and_wales_2024() {
+        assert_eq!(
+            vec![
+                (2024, 1, 1),
+                (2024, 3, 29),
+                (2024, 4, 1),
+                (2024, 5, 6),
+                (2024, 5, 27),
+                (2024, 8, 26),
+                (2024, 12, 25),
+                (2024, 12, 26),
+            ],
+            EnglandAndWales.holidays_in_year(2024));
+    }
+
+    #[test]
+    fn england_and_wales_2012() {
+        assert_eq!(
+            vec![
+                (2012, 1, 2),
+                (2012, 4, 6),
+                (2012, 4, 9),
+                (2012, 5, 7),
+                (2012, 6, 4),
+                (2012, 6, 5),
+                (2012, 8, 27),
+                (2012, 12, 25),
+                (2012, 12, 26),
+            ],
+            EnglandAndWales.holidays_in_year(2012));
+    }
+
+    #[test]
+    fn england_and_wales_christmas_substitutes() {
+        // Christmas on Saturday (2021): Monday and Tuesday.
+        assert!(EnglandAndWales.is_holiday((2021, 12, 27)));
+        assert!(EnglandAndWales.is_holiday((2021, 12, 28)));
+        assert!(!EnglandAndWales.is_holiday((2021, 12, 25)));
+        // Christmas on Sunday (2022): Boxing Day Monday, substitute Tuesday.
+        assert!(EnglandAndWales.is_holiday((2022, 12, 26)));
+        assert!(EnglandAndWales.is_holiday((2022, 12, 27)));
+        // Christmas on Friday (2020): Boxing Day substitute on Monday.
+        assert!(EnglandAndWales.is_holiday((2020, 12, 25)));
+        assert!(EnglandAndWales.is_holiday((2020, 12, 28)));
+    }
+
+    #[test]
+    fn target_2024() {
+        assert_eq!(
+            vec![
+                (2024, 1, 1),
+                (2024, 3, 29),
+                (2024, 4, 1),
+                (2024, 5, 1),
+                (2024, 12, 25),
+                (2024, 12, 26),
+            ],
+            Target.holidays_in_year(2024));
+    }
+
+    #[test]
+    fn target_2005() {
+        // No substitute days, even though several of these fell on weekends.
+        assert_eq!(
+            vec![
+                (2005, 1, 1),
+                (2005, 3, 25),
+                (2005, 3, 28),
+                (2005, 5, 1),
+                (2005, 12, 25),
+                (2005, 12, 26),
+            ],
+            Target.holidays_in_year(2005));
+        assert!(Target.is_holiday((2001, 12, 31)));
+        assert!(!Target.is_holiday((2005, 12, 31)));
+    }
+
+    #[test]
+    fn extreme_years() {
+        for year in [i32::MIN, i32::MAX] {
+            for ymd in [(year, 1, 1), (year, 12, 31)] {
+                UsFederal.is_holiday(ymd);
+                EnglandAndWales.is_holiday(ymd);
+                Target.is_holiday(ymd);
+            }
+        }
+    }
+
+    #[test]
+    fn is_holiday_matches_list() {
+        for year in 1965..2035 {
+            let lists = [
+                UsFederal.holidays_in_year(year),
+                EnglandAndWales.holidays_in_year(year),
+                Target.holidays_in_year(year),
+            ];
+            for days in days_from_civil(year, 1, 1)..days_from_civil(year + 1, 1, 1) {
+                let ymd = civil_from_days(days);
+                assert_eq!(lists[0].contains(&ymd), UsFederal.is_holiday(ymd), "{ymd:?}");
+                assert_eq!(lists[1].contains(&ymd), EnglandAndWales.is_holiday(ymd), "{ymd:?}");
+                assert_eq!(lists[2].contains(&ymd), Target.is_holiday(ymd), "{ymd:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,235 @@
+//! Implementations of [`CalendarDurationExt`] for `icu_calendar` dates in the Gregorian and Hebrew
+//! calendars.
+//!
+//! Months are numbered by their position in the year, so in a Hebrew leap year Adar I is month 6,
+//! Adar II is month 7, and Elul is month 13.
+
+use crate::CalendarDurationExt;
+use icu_calendar::cal::Hebrew;
+use icu_calendar::types::{DateFields, RataDie};
+use icu_calendar::{Date, Gregorian};
+
+/// The rata die (days from 0001-01-01, which is day 1) of 1970-01-01.
+const UNIX_EPOCH_RATA_DIE: i64 = 719_163;
+
+macro_rules! icu_impl {
+    ($calendar:ident) => {
+        impl CalendarDurationExt for Date<$calendar> {
+            fn ymd(self) -> (i32, u8, u8) {
+                (self.year().extended_year(), self.month().ordinal, self.day_of_month().0)
+            }
+
+            fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+                let mut fields = DateFields::default();
+                fields.extended_year = Some(y);
+                fields.ordinal_month = Some(m);
+                fields.day = Some(d);
+                Date::try_from_fields(fields, Default::default(), $calendar).ok()
+            }
+
+            fn months_in_year(y: i32) -> u8 {
+                Self::from_ymd(y, 1, 1).map_or(12, |date| date.months_in_year())
+            }
+
+            fn last_day_of_month(y: i32, m: u8) -> u8 {
+                Self::from_ymd(y, m, 1).map_or(0, |date| date.days_in_month())
+            }
+
+            #[cfg(not(feature = "strict"))]
+            fn succ(self) -> Self {
+                self.checked_succ().expect("date out of range")
+            }
+
+            fn checked_succ(self) -> Option<Self> {
+                let next = self.to_rata_die() + 1;
+                // Dates past the end of the supported range are clamped to it.
+                Some(Date::from_rata_die(next, $calendar)).filter(|date| date.to_rata_die() == next)
+            }
+
+            // Day numbers out of the supported range are clamped to it, but ones too large for
+            // arithmetic are debug assertion failures.
+            fn min_value() -> Self {
+                Date::from_rata_die(RataDie::new(i64::MIN >> 16), $calendar)
+            }
+
+            fn max_value() -> Self {
+                Date::from_rata_die(RataDie::new(i64::MAX >> 16), $calendar)
+            }
+
+            fn to_day_number(self) -> i64 {
+                self.to_rata_die().to_i64_date() - UNIX_EPOCH_RATA_DIE
+            }
+
+            fn from_day_number(days: i64) -> Option<Self> {
+                let range = Self::min_value().to_day_number() ..= Self::max_value().to_day_number();
+                let rata_die = RataDie::new(days.checked_add(UNIX_EPOCH_RATA_DIE)?);
+                range.contains(&days).then(|| Date::from_rata_die(rata_die, $calendar))
+            }
+
+            fn days_since(self, other: Self) -> i64 {
+                self.to_rata_die() - other.to_rata_die()
+            }
+
+            fn weekday_from_monday(self) -> u8 {
+                self.weekday() as u8 - 1
+            }
+        }
+    };
+}
+
+icu_impl!(Gregorian);
+icu_impl!(Hebrew);
+
+#[cfg(all(test, not(feature = "strict")))]
+mod test {
+    use super::*;
+    use crate::{AddOptions, CalendarDuration, HolidayCalendar, PeriodEnd, Weekend};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    mod gregorian {
+        use crate::*;
+        use icu_calendar::Date;
+
+        tests!(|y, m, d| Date::try_new_gregorian(y, m, d).expect("failed to construct Date"));
+    }
+
+    fn hebrew(y: i32, m: u8, d: u8) -> Date<Hebrew> {
+        Date::<Hebrew>::from_ymd(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn hebrew_leap_year() {
+        // 5784 is a leap year, with Adar I and Adar II, and 5785 isn't.
+        assert_eq!(13, <Date<Hebrew> as CalendarDurationExt>::months_in_year(5784));
+        assert_eq!(12, <Date<Hebrew> as CalendarDurationExt>::months_in_year(5785));
+        assert_eq!(Some((5785, 1)), Date::<Hebrew>::next_month(5784, 13));
+        assert_eq!(Some((5785, 12)), Date::<Hebrew>::next_month(5785, 11));
+        assert_eq!(Some((5786, 1)), Date::<Hebrew>::next_month(5785, 12));
+
+        // From Shevat to Nisan is two months in a common year, but three in a leap year.
+        assert_eq!(dur(0, 2, 0), hebrew(5785, 7, 10).calendar_duration_from(hebrew(5785, 5, 10)));
+        assert_eq!(dur(0, 3, 0), hebrew(5784, 8, 10).calendar_duration_from(hebrew(5784, 5, 10)));
+        assert_eq!(dur(0, 1, 0), hebrew(5785, 1, 10).calendar_duration_from(hebrew(5784, 13, 10)));
+        assert_eq!(dur(1, 0, 0), hebrew(5785, 1, 1).calendar_duration_from(hebrew(5784, 1, 1)));
+        assert_eq!(dur(1, 1, 5), hebrew(5785, 2, 6).calendar_duration_from(hebrew(5784, 1, 1)));
+    }
+
+    #[test]
+    fn hebrew_month_lengths() {
+        // Tishrei has 30 days, and Tevet has 29, so the 30th of Tevet is the 1st of Shevat.
+        assert_eq!(30, Date::<Hebrew>::last_day_of_month(5785, 1));
+        assert_eq!(29, Date::<Hebrew>::last_day_of_month(5785, 4));
+        assert_eq!(None, Date::<Hebrew>::from_ymd(5785, 4, 30));
+        assert_eq!(Some(hebrew(5785, 5, 1)),
+            Date::<Hebrew>::checked_from_ymd_or_next(5785, 4, 30));
+        assert_eq!(dur(0, 1, 0), hebrew(5785, 5, 1).calendar_duration_from(hebrew(5785, 3, 30)));
+    }
+
+    #[test]
+    fn same_day_as_gregorian() {
+        let gregorian = Date::try_new_gregorian(2024, 10, 3).unwrap();
+        let hebrew = gregorian.to_calendar(Hebrew);
+        assert_eq!((5785, 1, 1), hebrew.ymd());
+        assert_eq!(gregorian.weekday_from_monday(), hebrew.weekday_from_monday());
+        assert_eq!(3, hebrew.weekday_from_monday());
+        assert_eq!(hebrew.to_calendar(Gregorian).checked_succ(),
+            hebrew.checked_succ().map(|d| d.to_calendar(Gregorian)));
+    }
+
+    #[test]
+    fn hebrew_add_and_sub() {
+        // Months are added across Adar I and Adar II in a leap year, and Elul is its 13th month.
+        assert_eq!(Some(hebrew(5784, 6, 10)),
+            hebrew(5784, 5, 10).add_calendar_duration(&dur(0, 1, 0)));
+        assert_eq!(Some(hebrew(5784, 8, 10)),
+            hebrew(5784, 5, 10).add_calendar_duration(&dur(0, 3, 0)));
+        assert_eq!(Some(hebrew(5785, 7, 10)),
+            hebrew(5785, 5, 10).add_calendar_duration(&dur(0, 2, 0)));
+        assert_eq!(Some(hebrew(5785, 1, 10)),
+            hebrew(5784, 13, 10).add_calendar_duration(&dur(0, 1, 0)));
+        assert_eq!(Some(hebrew(5786, 2, 10)),
+            hebrew(5784, 13, 10).add_calendar_duration(&dur(1, 2, 0)));
+        assert_eq!(Some(hebrew(5784, 13, 10)),
+            hebrew(5785, 1, 10).sub_calendar_duration(&dur(0, 1, 0)));
+        assert_eq!(Some(hebrew(5784, 5, 10)),
+            hebrew(5784, 8, 10).sub_calendar_duration(&dur(0, 3, 0)));
+        assert_eq!(Some(hebrew(5783, 12, 10)),
+            hebrew(5784, 13, 10).sub_calendar_duration(&dur(0, 13, 0)));
+        // 5783 has no 13th month, so a year before Elul 5784 moves on to the next valid date.
+        assert_eq!(Some(hebrew(5784, 1, 1)),
+            hebrew(5784, 13, 10).sub_calendar_duration(&dur(1, 0, 0)));
+
+        // Days are counted across the end of Tevet, which has 29 days.
+        assert_eq!(Some(hebrew(5785, 5, 1)),
+            hebrew(5785, 4, 29).add_calendar_duration(&dur(0, 0, 1)));
+        assert_eq!(Some(hebrew(5785, 4, 29)),
+            hebrew(5785, 5, 1).sub_calendar_duration(&dur(0, 0, 1)));
+        let options = AddOptions::new().then_round_to(PeriodEnd::EndOfMonth);
+        assert_eq!(Some(hebrew(5785, 4, 29)),
+            hebrew(5785, 3, 10).add_calendar_duration_with(&dur(0, 1, 0), &options));
+
+        // Adding the duration between two dates to the earlier one gives the later one.
+        let start = hebrew(5784, 1, 1).to_day_number();
+        for i in 0 .. 40 {
+            let a = Date::<Hebrew>::from_day_number(start + i * 17).unwrap();
+            for j in 0 .. 40 {
+                let b = Date::<Hebrew>::from_day_number(start + i * 17 + j * 23).unwrap();
+                let dur = b.calendar_duration_from(a);
+                assert_eq!(Some(b), a.add_calendar_duration(&dur), "{a:?} + {dur:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn hebrew_weekdays() {
+        let start = Date::try_new_gregorian(2023, 9, 1).unwrap();
+        for i in 0 .. 800 {
+            let gregorian = Date::<Gregorian>::from_day_number(start.to_day_number() + i).unwrap();
+            let hebrew = gregorian.to_calendar(Hebrew);
+            assert_eq!(gregorian.to_day_number(), hebrew.to_day_number());
+            assert_eq!(Some(hebrew), Date::<Hebrew>::from_day_number(hebrew.to_day_number()));
+            // The trait's default weekday comes from the day number, and agrees with icu's.
+            assert_eq!(crate::gregorian::weekday_from_days(hebrew.to_day_number()),
+                hebrew.weekday_from_monday());
+        }
+
+        // Rosh Hashanah 5785 (2024-10-03) was a Thursday, and Yom Kippur 5785 a Saturday.
+        let rosh_hashanah = hebrew(5785, 1, 1);
+        assert_eq!(3, rosh_hashanah.weekday_from_monday());
+        assert_eq!(5, hebrew(5785, 1, 10).weekday_from_monday());
+
+        // From Thursday to the Thursday two weeks later is 10 business days, as in any calendar.
+        let later = hebrew(5785, 1, 15);
+        assert_eq!(10, rosh_hashanah.business_days_from(later));
+        let gregorian = |date: Date<Hebrew>| date.to_calendar(Gregorian);
+        assert_eq!(gregorian(rosh_hashanah).business_days_from(gregorian(later)),
+            rosh_hashanah.business_days_from(later));
+        struct Holiday((i32, u8, u8));
+        impl HolidayCalendar for Holiday {
+            fn is_holiday(&self, ymd: (i32, u8, u8)) -> bool {
+                ymd == self.0
+            }
+        }
+        // Holiday calendars are given Gregorian dates.
+        assert_eq!(9, rosh_hashanah.business_days_from_with(
+            later, Weekend::SaturdaySunday, &Holiday((2024, 10, 4))));
+    }
+
+    #[cfg(feature = "testkit")]
+    #[test]
+    fn conforms() {
+        crate::testkit::check_implementation::<Date<Gregorian>>(1999 ..= 2001);
+    }
+
+    #[test]
+    fn bounds() {
+        let max = Date::<Gregorian>::max_value();
+        assert_eq!(None, max.checked_succ());
+        assert!(Date::<Gregorian>::min_value() < max);
+        assert_eq!(None, Date::<Hebrew>::max_value().checked_succ());
+        assert!(Date::<Hebrew>::min_value().checked_succ().is_some());
+    }
+}
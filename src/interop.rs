@@ -0,0 +1,94 @@
+//! Conversions between the `chrono` and `time` date types, when both features are enabled.
+
+use crate::{duration_between, CalendarDuration, CalendarDurationExt, PlainDate};
+use chrono::NaiveDate;
+
+/// Convert a [`chrono::NaiveDate`] to the [`time::Date`] with the same year, month, and day.
+///
+/// Returns `None` if the date is outside the range of `time::Date`, which is smaller than
+/// chrono's: years -9999 through 9999, unless time's `large-dates` feature is enabled.
+///
+/// (These can't be `From` implementations, because neither type belongs to this crate.)
+pub fn time_date_from_chrono(date: NaiveDate) -> Option<time::Date> {
+    let (y, m, d) = date.ymd();
+    <time::Date as CalendarDurationExt>::from_ymd(y, m, d)
+}
+
+/// Convert a [`time::Date`] to the [`chrono::NaiveDate`] with the same year, month, and day.
+///
+/// Returns `None` if the date is outside the range of `NaiveDate`, which can only happen if time's
+/// `large-dates` feature is enabled.
+pub fn chrono_date_from_time(date: time::Date) -> Option<NaiveDate> {
+    let (y, m, d) = date.ymd();
+    <NaiveDate as CalendarDurationExt>::from_ymd(y, m, d)
+}
+
+/// Compute the calendar duration between a [`chrono::NaiveDate`] and a [`time::Date`]. The order
+/// of the dates doesn't matter.
+///
+/// This gives the same result as converting one date to the other's type and using
+/// [`calendar_duration_from`](CalendarDurationExt::calendar_duration_from), but works for any pair
+/// of dates, even when one can't be converted.
+pub fn calendar_duration_between_mixed(a: NaiveDate, b: time::Date) -> CalendarDuration {
+    let (a, b) = (a.to_day_number(), b.to_day_number());
+    match (PlainDate::from_epoch_day(a), PlainDate::from_epoch_day(b)) {
+        (Some(a), Some(b)) => duration_between(a, b),
+        // Both types' years fit in an i32, so this can't happen.
+        _ => CalendarDuration { years: 0, months: 0, days: 0 },
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use time::Month;
+
+    fn chrono_date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn time_date(y: i32, m: u8, d: u8) -> time::Date {
+        time::Date::from_calendar_date(y, Month::try_from(m).unwrap(), d).unwrap()
+    }
+
+    #[test]
+    fn conversions() {
+        for (y, m, d) in [(2024, 2, 29), (1970, 1, 1), (-9999, 1, 1), (9999, 12, 31)] {
+            let c = chrono_date(y, u32::from(m), u32::from(d));
+            let t = time_date(y, m, d);
+            assert_eq!(Some(t), time_date_from_chrono(c));
+            assert_eq!(Some(c), chrono_date_from_time(t));
+        }
+        assert_eq!(None, time_date_from_chrono(chrono_date(10000, 1, 1)));
+        assert_eq!(None, time_date_from_chrono(NaiveDate::MIN));
+    }
+
+    #[test]
+    fn mixed_matches_single_backend() {
+        let pairs = [
+            ((1988, 6, 16), (2020, 4, 8)),
+            ((2024, 1, 31), (2024, 3, 1)),
+            ((2020, 2, 29), (2021, 2, 28)),
+            ((2023, 12, 31), (2021, 1, 1)),
+            ((-100, 3, 1), (9999, 12, 31)),
+        ];
+        for ((y1, m1, d1), (y2, m2, d2)) in pairs {
+            let a = chrono_date(y1, m1, d1);
+            let b = time_date(y2, m2, d2);
+            let mixed = calendar_duration_between_mixed(a, b);
+            let b_chrono = chrono_date_from_time(b).unwrap();
+            let a_time = time_date_from_chrono(a).unwrap();
+            assert_eq!(Ok(mixed.clone()), b_chrono.try_calendar_duration_from(a));
+            assert_eq!(Ok(mixed), a_time.try_calendar_duration_from(b));
+        }
+    }
+
+    #[test]
+    fn unconvertible() {
+        // Too late for time::Date, but the duration can still be computed.
+        let a = NaiveDate::from_ymd_opt(10001, 1, 15).unwrap();
+        let b = time_date(9999, 12, 1);
+        assert_eq!(CalendarDuration { years: 1, months: 1, days: 14 },
+            calendar_duration_between_mixed(a, b));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{duration_between, CalendarDuration, CalendarDurationExt};
+use alloc::{vec, vec::Vec};
+
+/// How many days either side of the estimate to look for matching dates.
+///
+/// Different start dates only give the same duration when they fall in the days at the end of a
+/// month which don't exist in a shorter month, so all the matches are within a few days of each
+/// other.
+const WINDOW: i64 = 7;
+
+pub(crate) fn dates_before<D: CalendarDurationExt>(later: D, dur: &CalendarDuration) -> Vec<D> {
+    let Some(estimate) = later.sub_calendar_duration(dur) else {
+        return vec![];
+    };
+    matching(estimate.to_day_number(), |date| {
+        date <= later && duration_between(later, date) == *dur
+    })
+}
+
+pub(crate) fn dates_after<D: CalendarDurationExt>(earlier: D, dur: &CalendarDuration) -> Vec<D> {
+    let Some(estimate) = earlier.add_calendar_duration(dur) else {
+        return vec![];
+    };
+    matching(estimate.to_day_number(), |date| {
+        date >= earlier && duration_between(date, earlier) == *dur
+    })
+}
+
+/// Return the dates within the window around the given day number which match the predicate, in
+/// order.
+fn matching<D: CalendarDurationExt>(estimate: i64, pred: impl Fn(D) -> bool) -> Vec<D> {
+    (estimate - WINDOW ..= estimate + WINDOW)
+        .filter_map(D::from_day_number)
+        .filter(|&date| pred(date))
+        .collect()
+}
+
+#[cfg(all(test, not(feature = "strict"), feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn feb30() {
+        let dur = CalendarDuration { years: 0, months: 2, days: 14 };
+        assert_eq!(
+            vec![date(2024, 12, 29), date(2024, 12, 30), date(2024, 12, 31), date(2025, 1, 1)],
+            NaiveDate::dates_at_duration_before(date(2025, 3, 15), &dur));
+        for start in NaiveDate::dates_at_duration_before(date(2025, 3, 15), &dur) {
+            assert_eq!(vec![date(2025, 3, 15)], NaiveDate::dates_at_duration_after(start, &dur));
+        }
+    }
+
+    #[test]
+    fn none() {
+        // Nothing is 1 month and 30 days before March 1st, 2023: January 29th through February 1st
+        // are all exactly 1 month before it, via "February 29th" and so on.
+        let dur = CalendarDuration { years: 0, months: 1, days: 30 };
+        assert_eq!(Vec::<NaiveDate>::new(),
+            NaiveDate::dates_at_duration_before(date(2023, 3, 1), &dur));
+        assert_eq!(
+            vec![date(2023, 1, 29), date(2023, 1, 30), date(2023, 1, 31), date(2023, 2, 1)],
+            NaiveDate::dates_at_duration_before(date(2023, 3, 1), &CalendarDuration {
+                years: 0, months: 1, days: 0 }));
+
+        // Denormalized durations are never the result of a difference.
+        let dur = CalendarDuration { years: 0, months: 0, days: 45 };
+        assert_eq!(Vec::<NaiveDate>::new(),
+            NaiveDate::dates_at_duration_before(date(2023, 3, 1), &dur));
+        assert_eq!(Vec::<NaiveDate>::new(),
+            NaiveDate::dates_at_duration_after(date(2023, 3, 1), &dur));
+    }
+
+    #[test]
+    fn exhaustive() {
+        // Compare against checking every date in a wider range.
+        let base = date(2023, 10, 1);
+        for later_offset in 0..60 {
+            let later = base + chrono::Days::new(later_offset);
+            let candidates = (0..=120)
+                .map(|n| later - chrono::Days::new(n))
+                .collect::<Vec<_>>();
+            for &start in &candidates[..100] {
+                let dur = later.calendar_duration_from(start);
+                let mut expected = candidates.iter()
+                    .copied()
+                    .filter(|&d| later.calendar_duration_from(d) == dur)
+                    .collect::<Vec<_>>();
+                expected.sort();
+                assert_eq!(expected, NaiveDate::dates_at_duration_before(later, &dur),
+                    "{later} {dur}");
+                assert!(NaiveDate::dates_at_duration_after(start, &dur).contains(&later),
+                    "{start} {dur}");
+            }
+        }
+    }
+}
@@ -0,0 +1,304 @@
+#[cfg(feature = "alloc")]
+use crate::parse::{Builder, Unit};
+#[cfg(feature = "alloc")]
+use crate::ParseError;
+use crate::MixedDuration;
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, format, string::{String, ToString}};
+use core::fmt::{self, Write};
+#[cfg(feature = "alloc")]
+use core::time::Duration;
+
+/// Split a leading number off the input, returning its digits (and any fraction, with either `.`
+/// or `,` as the decimal sign), its designator letter, and the rest of the input.
+#[cfg(feature = "alloc")]
+fn component(s: &str) -> Result<(&str, char, &str), ParseError> {
+    let end = s.find(|c: char| !(c.is_ascii_digit() || c == '.' || c == ','))
+        .unwrap_or(s.len());
+    let (number, rest) = s.split_at(end);
+    if number.is_empty() {
+        return Err(ParseError::InvalidIso8601(s.to_owned()));
+    }
+    let mut chars = rest.chars();
+    let designator = chars.next().ok_or(ParseError::MissingUnit)?;
+    Ok((number, designator.to_ascii_uppercase(), chars.as_str()))
+}
+
+#[cfg(feature = "alloc")]
+fn integer(number: &str) -> Result<u64, ParseError> {
+    if !number.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(ParseError::InvalidNumber(number.to_owned()));
+    }
+    number.parse().map_err(|_| ParseError::InvalidNumber(number.to_owned()))
+}
+
+/// Parse a number of seconds, which may have a fraction of up to nine digits.
+#[cfg(feature = "alloc")]
+fn seconds(number: &str) -> Result<Duration, ParseError> {
+    let invalid = || ParseError::InvalidNumber(number.to_owned());
+    let (whole, fraction) = match number.split_once(['.', ',']) {
+        Some((_, "")) => return Err(invalid()),
+        Some((whole, fraction)) => (whole, fraction),
+        None => (number, ""),
+    };
+    if whole.is_empty() || fraction.len() > 9 || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let nanos = format!("{fraction:0<9}").parse().map_err(|_| invalid())?;
+    Ok(Duration::new(integer(whole)?, nanos))
+}
+
+/// Parse the time section, after the `T`, into an exact duration.
+#[cfg(feature = "alloc")]
+fn time_section(mut s: &str) -> Result<Duration, ParseError> {
+    let out_of_range = || ParseError::OutOfRange("seconds".to_owned());
+    let mut exact = Duration::ZERO;
+    // Designators must be given in this order, each at most once.
+    let mut allowed = "HMS";
+    if s.is_empty() {
+        return Err(ParseError::InvalidIso8601("T".to_owned()));
+    }
+    while !s.is_empty() {
+        let (number, designator, rest) = component(s)?;
+        let Some(pos) = allowed.find(designator) else {
+            return Err(if "HMS".contains(designator) {
+                ParseError::InvalidIso8601(s.to_owned())
+            } else {
+                ParseError::UnknownUnit(designator.to_string())
+            });
+        };
+        allowed = &allowed[pos + 1 ..];
+        let part = match designator {
+            'H' => integer(number)?.checked_mul(3600)
+                .map(Duration::from_secs)
+                .ok_or_else(out_of_range)?,
+            'M' => Duration::from_secs(integer(number)?.checked_mul(60).ok_or_else(out_of_range)?),
+            _ => seconds(number)?,
+        };
+        exact = exact.checked_add(part).ok_or_else(out_of_range)?;
+        s = rest;
+    }
+    Ok(exact)
+}
+
+/// Parse an ISO 8601 duration which has no time section, or only a zero one.
+#[cfg(feature = "alloc")]
+pub(crate) fn parse_calendar_iso8601(s: &str) -> Result<crate::CalendarDuration, ParseError> {
+    let mixed = MixedDuration::parse_iso8601(s)?;
+    if mixed.exact != Duration::ZERO {
+        return Err(ParseError::TimeOfDay(s.to_owned()));
+    }
+    Ok(mixed.calendar)
+}
+
+impl crate::CalendarDuration {
+    /// Format the duration in ISO 8601 form, like `P31Y9M23D`. This is also what the alternate
+    /// form of [`Display`](core::fmt::Display) (`{:#}`) writes.
+    ///
+    /// Zero components are left out, and the zero duration is `P0D`.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso8601(&self) -> String {
+        let mut out = String::new();
+        self.write_iso8601(&mut out).unwrap();
+        out
+    }
+
+    /// Write the duration in ISO 8601 form, without needing to allocate.
+    pub(crate) fn write_iso8601(&self, out: &mut (impl Write + ?Sized)) -> fmt::Result {
+        out.write_char('P')?;
+        if self.is_zero() {
+            return out.write_str("0D");
+        }
+        for (n, designator) in [(self.years, 'Y'), (self.months, 'M'), (self.days, 'D')] {
+            if n != 0 {
+                write!(out, "{n}{designator}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MixedDuration {
+    /// Parse an ISO 8601 duration like `P1Y2M3DT4H5M6.5S`, as used by `java.time`, JavaScript's
+    /// Temporal, and others.
+    ///
+    /// The date section may have years, months, weeks, and days, and the time section (after
+    /// `T`) may have hours, minutes, and seconds, each at most once and in that order. Weeks are
+    /// counted as seven days. Only seconds may have a fraction, with `.` or `,` as the decimal
+    /// sign and up to nine digits. At least one component must be given, and negative durations
+    /// aren't supported. Designators may be upper or lower case.
+    #[cfg(feature = "alloc")]
+    pub fn parse_iso8601(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let rest = s.strip_prefix(['P', 'p'])
+            .ok_or_else(|| ParseError::InvalidIso8601(s.to_owned()))?;
+        let (mut date, time) = match rest.find(['T', 't']) {
+            Some(pos) => (&rest[.. pos], Some(&rest[pos + 1 ..])),
+            None => (rest, None),
+        };
+        if date.is_empty() && time.is_none() {
+            return Err(ParseError::InvalidIso8601(s.to_owned()));
+        }
+
+        let mut builder = Builder::default();
+        let mut allowed = "YMWD";
+        while !date.is_empty() {
+            let (number, designator, rest) = component(date)?;
+            let Some(pos) = allowed.find(designator) else {
+                return Err(if "YMWD".contains(designator) {
+                    ParseError::InvalidIso8601(date.to_owned())
+                } else {
+                    ParseError::UnknownUnit(designator.to_string())
+                });
+            };
+            allowed = &allowed[pos + 1 ..];
+            // Fractional years, months, and days have no exact meaning on a calendar.
+            let value = integer(number)?;
+            match designator {
+                'Y' => builder.set(Unit::Years, value)?,
+                'M' => builder.set(Unit::Months, value)?,
+                'W' => builder.set_weeks(value)?,
+                _ => builder.set(Unit::Days, value)?,
+            }
+            date = rest;
+        }
+
+        let exact = time.map(time_section).transpose()?.unwrap_or(Duration::ZERO);
+        Ok(MixedDuration { calendar: builder.build()?, exact })
+    }
+
+    /// Format the duration in ISO 8601 form, like `P1Y2M3DT4H5M6.5S`.
+    ///
+    /// Zero components are left out, and the zero duration is `PT0S`. The exact part is written
+    /// as hours, minutes, and seconds, with as many fractional digits as needed. Parsing the
+    /// result with [`parse_iso8601`](Self::parse_iso8601) gives back the same duration.
+    #[cfg(feature = "alloc")]
+    pub fn to_iso8601(&self) -> String {
+        let mut out = String::from("P");
+        let c = &self.calendar;
+        for (n, designator) in [(c.years, 'Y'), (c.months, 'M'), (c.days, 'D')] {
+            if n != 0 {
+                write!(out, "{n}{designator}").unwrap();
+            }
+        }
+
+        let secs = self.exact.as_secs();
+        let nanos = self.exact.subsec_nanos();
+        if secs != 0 || nanos != 0 || out.len() == 1 {
+            out.push('T');
+            for (n, designator) in [(secs / 3600, 'H'), (secs / 60 % 60, 'M')] {
+                if n != 0 {
+                    write!(out, "{n}{designator}").unwrap();
+                }
+            }
+            let s = secs % 60;
+            if nanos != 0 {
+                write!(out, "{s}.{}S", Fraction(nanos)).unwrap();
+            } else if s != 0 || out.len() == 2 {
+                write!(out, "{s}S").unwrap();
+            }
+        }
+        out
+    }
+}
+
+/// A nonzero number of nanoseconds, written as the digits after a decimal point, without any
+/// trailing zeros.
+pub(crate) struct Fraction(pub(crate) u32);
+
+impl fmt::Display for Fraction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = self.0;
+        let mut width = 9;
+        while digits.is_multiple_of(10) && width > 1 {
+            digits /= 10;
+            width -= 1;
+        }
+        write!(f, "{digits:0width$}")
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use crate::CalendarDuration;
+
+    fn mixed(years: u32, months: u32, days: u32, exact: Duration) -> MixedDuration {
+        MixedDuration::new(CalendarDuration { years, months, days }, exact)
+    }
+
+    #[test]
+    fn calendar_only() {
+        let dur = |years, months, days| CalendarDuration { years, months, days };
+        assert_eq!("P31Y9M23D", dur(31, 9, 23).to_iso8601());
+        assert_eq!("P2Y", dur(2, 0, 0).to_iso8601());
+        assert_eq!("P18M", dur(0, 18, 0).to_iso8601());
+        assert_eq!("P45D", dur(0, 0, 45).to_iso8601());
+        assert_eq!("P1Y3D", dur(1, 0, 3).to_iso8601());
+        assert_eq!("P0D", dur(0, 0, 0).to_iso8601());
+        assert_eq!("P31Y9M23D", format!("{:#}", dur(31, 9, 23)));
+        assert_eq!("P0D", format!("{:#}", dur(0, 0, 0)));
+        assert_eq!("31 years, 9 months, 23 days", format!("{}", dur(31, 9, 23)));
+    }
+
+    #[test]
+    fn time_only() {
+        let d = mixed(0, 0, 0, Duration::from_secs(15 * 60));
+        assert_eq!(Ok(d.clone()), MixedDuration::parse_iso8601("PT15M"));
+        assert_eq!("PT15M", d.to_iso8601());
+        assert_eq!(Ok(mixed(0, 0, 0, Duration::from_secs(36 * 3600))),
+            MixedDuration::parse_iso8601("PT36H"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let cases = [
+            ("P1YT1S", mixed(1, 0, 0, Duration::from_secs(1))),
+            ("P1Y2M3DT4H5M6S", mixed(1, 2, 3, Duration::from_secs(4 * 3600 + 5 * 60 + 6))),
+            ("P1M2DT3H", mixed(0, 1, 2, Duration::from_secs(3 * 3600))),
+            ("P3D", mixed(0, 0, 3, Duration::ZERO)),
+            ("PT1H0.5S", mixed(0, 0, 0, Duration::from_millis(3_600_500))),
+            ("PT0.000000001S", mixed(0, 0, 0, Duration::from_nanos(1))),
+            ("PT0S", mixed(0, 0, 0, Duration::ZERO)),
+        ];
+        for (s, d) in cases {
+            assert_eq!(Ok(d.clone()), MixedDuration::parse_iso8601(s), "{s}");
+            assert_eq!(s, d.to_iso8601());
+        }
+    }
+
+    #[test]
+    fn other_forms() {
+        let parse = MixedDuration::parse_iso8601;
+        assert_eq!(Ok(mixed(0, 0, 0, Duration::from_millis(1500))), parse("PT1,5S"));
+        assert_eq!(Ok(mixed(0, 0, 0, Duration::from_millis(1500))), parse("pt1.50s"));
+        assert_eq!(Ok(mixed(0, 0, 17, Duration::ZERO)), parse("P2W3D"));
+        assert_eq!(Ok(mixed(0, 0, 0, Duration::ZERO)), parse("P0D"));
+        assert_eq!(Ok(mixed(0, 0, 0, Duration::from_secs(90))), parse("PT90S"));
+        assert_eq!("PT1M30S", parse("PT90S").unwrap().to_iso8601());
+    }
+
+    #[test]
+    fn errors() {
+        let parse = MixedDuration::parse_iso8601;
+        let invalid = |s: &str| Err(ParseError::InvalidIso8601(s.to_owned()));
+        assert_eq!(Err(ParseError::Empty), parse(""));
+        assert_eq!(Err(ParseError::UnknownUnit("H".to_owned())), parse("P1H"));
+        assert_eq!(invalid("1Y"), parse("1Y"));
+        assert_eq!(invalid("P"), parse("P"));
+        assert_eq!(invalid("T"), parse("PT"));
+        assert_eq!(invalid("1Y"), parse("P1M1Y"));
+        assert_eq!(invalid("1H"), parse("PT1M1H"));
+        assert_eq!(invalid("-1D"), parse("P-1D"));
+        assert_eq!(Err(ParseError::MissingUnit), parse("P1"));
+        assert_eq!(Err(ParseError::InvalidNumber("1.5".to_owned())), parse("P1.5Y"));
+        assert_eq!(Err(ParseError::InvalidNumber("0.5".to_owned())), parse("PT0.5H"));
+        assert_eq!(Err(ParseError::InvalidNumber("1.".to_owned())), parse("PT1.S"));
+        assert_eq!(Err(ParseError::InvalidNumber("0.1234567891".to_owned())),
+            parse("PT0.1234567891S"));
+        assert_eq!(Err(ParseError::OutOfRange("years".to_owned())), parse("P5000000000Y"));
+    }
+}
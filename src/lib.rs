@@ -1,4 +1,8 @@
+#![cfg_attr(not(feature = "std"), no_std)]
 #![deny(missing_docs, rust_2018_idioms)]
+// The docs link the checked methods to the panicking ones they stand in for, which `strict`
+// removes. Those links are checked in the other configurations.
+#![cfg_attr(feature = "strict", allow(rustdoc::broken_intra_doc_links))]
 
 //! An extension trait for date-like types to allow computing "calendar durations" between dates.
 //!
@@ -12,17 +16,245 @@
 //! Note that this crate is only concerned with whole days. It does not account for leap-seconds or
 //! timezone changes, and so the trait is only implemented for date-like types, not date-time ones.
 //!
-//! This crate comes with implementations for two types:
-//!   - [`chrono::NaiveDate`] which can be enabled by compiling with the `chrono` feature.
-//!   - [`time::Date`] which can be enabled by compiling with the `time` feature.
+//! This crate comes with implementations for these types:
+//!   - [`PlainDate`], the crate's own minimal date type, which is always available.
+#![cfg_attr(feature = "chrono", doc = "  - [`chrono::NaiveDate`]")]
+#![cfg_attr(not(feature = "chrono"), doc = "  - `chrono::NaiveDate`")]
+//!     which can be enabled by compiling with the `chrono` feature.
+#![cfg_attr(feature = "time", doc = "  - [`time::Date`]")]
+#![cfg_attr(not(feature = "time"), doc = "  - `time::Date`")]
+//!     which can be enabled by compiling with the `time` feature.
+#![cfg_attr(feature = "jiff", doc = "  - [`jiff::civil::Date`]")]
+#![cfg_attr(not(feature = "jiff"), doc = "  - `jiff::civil::Date`")]
+//!     which can be enabled by compiling with the `jiff` feature.
+//!   - `icu_calendar::Date<Gregorian>` and `icu_calendar::Date<Hebrew>`, which can be enabled by
+//!     compiling with the `icu` feature. Hebrew dates number their months by position in the
+//!     year, so a leap year has 13.
 //!
-//! By default, neither is enabled, and because of Rust's prohibition on implementations of foreign
-//! traits for foreign types, the trait by itself is not useful without one of these
-//! implementations. So you'll need to use it in your Crates.toml as
+//! By default, none of the date library implementations are enabled. Small tools which don't
+//! otherwise need a date library can use [`PlainDate`] with no features at all. Otherwise, because
+//! of Rust's prohibition on implementations of foreign traits for foreign types, you'll need to
+//! enable the one for your date library in your Crates.toml as
 //! ```cargo
 //! calendar_duration = { version = "$current_version_here", features = ["chrono"] }
 //! ```
-//! (or `features = ["time"]` if you're using that crate.)
+//! (or `features = ["time"]` or `features = ["jiff"]` if you're using one of those crates.)
+//!
+//! With the `chrono` feature,
+#![cfg_attr(feature = "chrono", doc = "[`calendar_duration_between_zoned`]")]
+#![cfg_attr(not(feature = "chrono"), doc = "`calendar_duration_between_zoned`")]
+//! compares the local dates of time zone aware `DateTime`s.
+//!
+//! With both the `chrono` and `time` features enabled, dates of those two types can be converted
+//! to each other and compared with
+#![cfg_attr(
+    all(feature = "chrono", feature = "time"),
+    doc = "[`calendar_duration_between_mixed`]."
+)]
+#![cfg_attr(
+    not(all(feature = "chrono", feature = "time")),
+    doc = "`calendar_duration_between_mixed`."
+)]
+//!
+//! The [`gregorian`] module has date math for implementing the trait for other types, and
+//! [`PlainDate`] can be parsed from and written as ISO 8601 dates, and converted to and from the
+//! `chrono` and `time` date types, as a neutral type to exchange dates in. The [`date!`] and
+//! [`calendar_duration!`] macros make constants of dates and durations which are checked at
+//! compile time.
+//!
+//! The crate is `no_std` when the default `std` feature is disabled. Date math, comparisons, and
+//! the [`Display`](core::fmt::Display) implementations all work without it. Parsing, and anything
+//! else which returns a `String` or `Vec`, needs the `alloc` feature, which `std` enables. The
+//! `time`, `chrono`, and `jiff` implementations work with those crates' own default features
+//! disabled, and turn them back on along with `std` and `alloc`:
+//! ```cargo
+//! [dependencies.calendar_duration]
+//! version = "$current_version_here"
+//! default-features = false
+//! features = ["time"]
+//! ```
+//!
+//! Other optional features:
+//!   - `holidays`: built-in [`HolidayCalendar`]s in the
+#![cfg_attr(feature = "holidays", doc = "    [`holidays`]")]
+#![cfg_attr(not(feature = "holidays"), doc = "    `holidays`")]
+//!     module. This also enables the `alloc` feature.
+//!   - `uniffi`: [UniFFI](https://mozilla.github.io/uniffi-rs/) exports for Kotlin and Swift, in
+//!     the `ffi` module. This also enables the `time` feature.
+//!   - `testkit`: the
+#![cfg_attr(feature = "testkit", doc = "    [`testkit`]")]
+#![cfg_attr(not(feature = "testkit"), doc = "    `testkit`")]
+//!     module, for checking implementations of [`CalendarDurationExt`] for other date types.
+//!   - `rusqlite`: storing [`CalendarDuration`]s in SQLite as ISO 8601 text, with `rusqlite`.
+//!   - `clap`:
+#![cfg_attr(feature = "clap", doc = "    [`CalendarDurationValueParser`],")]
+#![cfg_attr(not(feature = "clap"), doc = "    `CalendarDurationValueParser`,")]
+//!     so that [`CalendarDuration`] command line arguments can be given in ISO 8601, shorthand
+//!     (`18m`), or English (`2 years`).
+//!   - `serde`: `Serialize` and `Deserialize` for [`CalendarDuration`] as a struct of its fields,
+//!     and the
+#![cfg_attr(feature = "serde", doc = "    [`serde_string`]")]
+#![cfg_attr(not(feature = "serde"), doc = "    `serde_string`")]
+//!     module for writing it as an ISO 8601 string.
+//!   - `clock`:
+#![cfg_attr(feature = "clock", doc = "    [`CalendarDurationToday`],")]
+#![cfg_attr(not(feature = "clock"), doc = "    `CalendarDurationToday`,")]
+//!     for measuring durations to the current date, and
+#![cfg_attr(feature = "clock", doc = "    [`age_from_birthdate`].")]
+#![cfg_attr(not(feature = "clock"), doc = "    `age_from_birthdate`.")]
+//!     It is implemented for `chrono::NaiveDate` and `time::Date` when those features are enabled
+//!     too.
+//!   - `arbitrary`: `Arbitrary` for [`CalendarDuration`], for fuzzing. It generates normalized
+//!     durations, with fewer than 12 months and 31 days.
+//!   - `proptest`: the
+#![cfg_attr(feature = "proptest", doc = "    [`strategies`]")]
+#![cfg_attr(not(feature = "proptest"), doc = "    `strategies`")]
+//!     module, with `proptest` strategies for durations, (year, month, day) triples, and dates of
+//!     any type implementing the trait.
+//!   - `temporal-js`: conversions to and from the JavaScript Temporal API's `PlainDate` and
+//!     `Duration` on `wasm32`, in the `temporal_js` module.
+//!   - `strict`: removes every method which can panic from [`CalendarDurationExt`], leaving only
+//!     the checked versions: [`checked_succ`], [`checked_from_ymd_or_next`], and
+//!     [`try_calendar_duration_from`]. `checked_succ` becomes the required method instead of
+//!     `succ`. Because it changes the trait, implementations outside of this crate need to be
+//!     written for one configuration or the other. The `+`, `-`, `*`, and `sum` operations on
+//!     [`CalendarDuration`], which panic on overflow, are removed too, in favor of
+//!     [`checked_add`](CalendarDuration::checked_add),
+//!     [`checked_sub`](CalendarDuration::checked_sub), and
+//!     [`checked_mul`](CalendarDuration::checked_mul). So are the operators on [`Years`],
+//!     [`Months`], [`Weeks`], and [`Days`], and the conversions between them, leaving
+//!     [`Years::checked_to_months`] and [`Weeks::checked_to_days`], and `+` on
+//!     [`MixedDuration`], leaving [`MixedDuration::checked_add`].
+//!
+//! [`checked_succ`]: CalendarDurationExt::checked_succ
+//! [`checked_from_ymd_or_next`]: CalendarDurationExt::checked_from_ymd_or_next
+//! [`try_calendar_duration_from`]: CalendarDurationExt::try_calendar_duration_from
+
+// Tests format durations to strings even when the library itself doesn't allocate.
+#[cfg(any(feature = "alloc", test))]
+extern crate alloc;
+
+mod absolute;
+mod accrual;
+mod across;
+mod age;
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary_impl;
+mod arithmetic;
+mod bucket;
+mod business;
+#[cfg(feature = "clap")]
+mod clap_impl;
+#[cfg(feature = "alloc")]
+mod classify;
+mod delta;
+mod explain;
+#[cfg(feature = "uniffi")]
+pub mod ffi;
+mod format;
+mod granular;
+pub mod gregorian;
+#[cfg(feature = "holidays")]
+pub mod holidays;
+mod hijri;
+#[cfg(all(feature = "chrono", feature = "time"))]
+mod interop;
+#[cfg(feature = "alloc")]
+mod inverse;
+mod iso8601;
+mod literal;
+mod mixed;
+mod ops;
+mod overflow;
+#[cfg(feature = "alloc")]
+mod parse;
+mod period;
+#[cfg(feature = "alloc")]
+mod pg_interval;
+mod plain;
+mod range;
+mod reaching;
+mod recurrence;
+mod relative;
+mod retail;
+#[cfg(feature = "rusqlite")]
+mod rusqlite_impl;
+#[cfg(feature = "alloc")]
+mod semimonthly;
+#[cfg(feature = "serde")]
+pub mod serde_string;
+mod signed;
+mod significant;
+#[cfg(feature = "alloc")]
+mod sortable;
+#[cfg(feature = "alloc")]
+mod sql_interval;
+#[cfg(feature = "proptest")]
+pub mod strategies;
+#[cfg(all(feature = "temporal-js", target_arch = "wasm32"))]
+pub mod temporal_js;
+#[cfg(feature = "testkit")]
+pub mod testkit;
+#[cfg(feature = "clock")]
+mod today;
+mod units;
+mod weeks;
+#[cfg(feature = "chrono")]
+mod zoned;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!();
+
+pub use accrual::AccrualPeriod;
+pub use across::{calendar_duration_across, CalendarChoice, EpochDay};
+pub use age::{AgeReport, LeapDayPolicy};
+pub use approx::ApproxDuration;
+pub use arithmetic::{AddOptions, AdditionOrder, PeriodEnd};
+pub use bucket::{BucketKey, Granularity};
+pub use business::{BusinessDayRoll, HolidayCalendar, Weekend};
+#[cfg(feature = "clap")]
+pub use clap_impl::CalendarDurationValueParser;
+#[cfg(feature = "alloc")]
+pub use classify::DurationBuckets;
+pub use delta::RelativeDelta;
+pub use explain::{Step, StepTrace, StepUnit};
+pub use format::{CalendarDurationFormatter, CompactDuration, Digits, FormattedDuration};
+pub use granular::DurationUnit;
+pub use hijri::HijriDate;
+#[cfg(all(feature = "chrono", feature = "time"))]
+pub use interop::{
+    calendar_duration_between_mixed, chrono_date_from_time, time_date_from_chrono,
+};
+#[doc(hidden)]
+pub use literal::__parse_duration_literal;
+pub use mixed::{CalendarDateTime, CalendarDateTimeDurationExt, MixedDuration};
+pub use overflow::OverflowPolicy;
+#[cfg(feature = "alloc")]
+pub use parse::ParseError;
+pub use period::{CalendarPeriod, EndConvention};
+pub use plain::{DateParseError, PlainDate};
+#[cfg(feature = "alloc")]
+pub use range::total_service;
+pub use range::{CalendarRange, DateRange, IsoWeek};
+pub use recurrence::{Cycle, Recurrence, Recurrences};
+pub use relative::RelativeBucket;
+pub use retail::{FiscalYearEnd, RetailCalendar, RetailDuration, WeekPattern};
+#[cfg(feature = "alloc")]
+pub use semimonthly::{PayPeriod, SemiMonthly};
+pub use signed::SignedCalendarDuration;
+pub use significant::SignificantDisplay;
+#[cfg(feature = "clock")]
+pub use today::{age_from_birthdate, CalendarDurationToday};
+#[cfg(feature = "alloc")]
+pub use sql_interval::SqlIntervalError;
+pub use units::{Days, Months, Weeks, Years};
+pub use weeks::CalendarDurationWithWeeks;
+#[cfg(feature = "chrono")]
+pub use zoned::calendar_duration_between_zoned;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 /// Extension trait to allow computing a "calendar duration" from two dates.
 /// 
@@ -35,76 +267,728 @@ pub trait CalendarDurationExt: Sized + Ord + Copy {
     fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self>;
 
     /// Construct a date from the given year, month, and date; or the next day if such date is not
-    /// valid (either leap year or 30/31 day month difference).
-    fn from_ymd_or_next(mut y: i32, mut m: u8, d: u8) -> Self {
+    /// valid (either leap year or 30/31 day month difference). The next day after any day past the
+    /// end of a month, like April 31st or February 30th, is the 1st of the following month.
+    ///
+    /// Panics if neither date can be constructed. See
+    /// [`checked_from_ymd_or_next`](Self::checked_from_ymd_or_next) for a version which doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn from_ymd_or_next(y: i32, m: u8, d: u8) -> Self {
+        Self::checked_from_ymd_or_next(y, m, d)
+            .unwrap_or_else(|| panic!("constructing a date for ({y},{m},{d}) failed"))
+    }
+
+    /// Construct a date from the given year, month, and date; or the next day if such date is not
+    /// valid (either leap year or 30/31 day month difference). The next day after any day past the
+    /// end of a month, like April 31st or February 30th, is the 1st of the following month.
+    ///
+    /// Returns `None` if neither date can be constructed, such as when it is out of the range of
+    /// the date type.
+    fn checked_from_ymd_or_next(y: i32, m: u8, d: u8) -> Option<Self> {
         Self::from_ymd(y, m, d)
-            .unwrap_or_else(|| {
-                match (m, d) {
-                    (2, 29) | (2, 30) | (2, 31) => Self::from_ymd(y, 3, 1).unwrap(),
-                    (_, 31) => {
-                        if m == 12 {
-                            m = 1;
-                            y += 1;
-                        } else {
-                            m += 1;
-                        }
-                        Self::from_ymd(y, m, 30).unwrap()
-                    }
-                    _ => panic!("constructing a date for ({y},{m},{d}) failed for unknown reason"),
+            .or_else(|| {
+                let last = Self::last_day_of_month(y, m);
+                if d <= last || d > 31 {
+                    return None;
                 }
+                let (y, m) = Self::next_month(y, m)?;
+                Self::from_ymd(y, m, 1)
             })
     }
 
+    /// Construct a date from the given year, month, and date; or the last day of the month if the
+    /// day is past the end of it, so "February 30th" is February 28th or 29th.
+    ///
+    /// Panics if the date can't be constructed. See
+    /// [`checked_from_ymd_or_clamped`](Self::checked_from_ymd_or_clamped) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn from_ymd_or_clamped(y: i32, m: u8, d: u8) -> Self {
+        Self::checked_from_ymd_or_clamped(y, m, d)
+            .unwrap_or_else(|| panic!("constructing a date for ({y},{m},{d}) failed"))
+    }
+
+    /// Construct a date from the given year, month, and date; or the last day of the month if the
+    /// day is past the end of it.
+    ///
+    /// Returns `None` if no day of the month up to the given one can be constructed, such as when
+    /// it is out of the range of the date type.
+    fn checked_from_ymd_or_clamped(y: i32, m: u8, d: u8) -> Option<Self> {
+        (1 ..= d).rev().find_map(|d| Self::from_ymd(y, m, d))
+    }
+
+    /// Return the number of months in the given year.
+    ///
+    /// The default is the Gregorian calendar's 12. Calendars with a different number of months in
+    /// some years, like the Hebrew calendar with its leap month, should override this so that
+    /// durations count months correctly.
+    fn months_in_year(_y: i32) -> u8 {
+        12
+    }
+
+    /// Return the number of days in the given month, which is also its last day.
+    ///
+    /// The default is the Gregorian calendar's, and gives 31 for a month which doesn't exist.
+    /// Calendars with other month lengths should override this so that
+    /// [`checked_from_ymd_or_next`](Self::checked_from_ymd_or_next) knows which days are past the
+    /// end of a month.
+    fn last_day_of_month(y: i32, m: u8) -> u8 {
+        gregorian::month_length(y, m)
+    }
+
+    /// Return the year and month after the given one, or `None` if the year would overflow.
+    ///
+    /// The default follows the last month of the year, according to
+    /// [`months_in_year`](Self::months_in_year), with the first month of the next year.
+    fn next_month(y: i32, m: u8) -> Option<(i32, u8)> {
+        if m >= Self::months_in_year(y) {
+            Some((y.checked_add(1)?, 1))
+        } else {
+            Some((y, m + 1))
+        }
+    }
+
     /// Return the date for the next day from the given one.
+    ///
+    /// Implementations panic if the date is the latest one the type can represent. See
+    /// [`checked_succ`](Self::checked_succ) for a version which doesn't.
+    #[cfg(not(feature = "strict"))]
     fn succ(self) -> Self;
 
+    /// Return the date for the next day from the given one, or `None` if it can't be represented.
+    #[cfg(not(feature = "strict"))]
+    fn checked_succ(self) -> Option<Self> {
+        Some(self.succ())
+    }
+
+    /// Return the date for the next day from the given one, or `None` if it can't be represented.
+    #[cfg(feature = "strict")]
+    fn checked_succ(self) -> Option<Self>;
+
+    /// Return the earliest date the type can represent.
+    fn min_value() -> Self;
+
+    /// Return the latest date the type can represent.
+    fn max_value() -> Self;
+
     /// Compute the calendar duration difference from the other date.
+    ///
+    /// This doesn't panic for any pair of dates the type can represent, including its
+    /// [`min_value`](Self::min_value) and [`max_value`](Self::max_value). It only panics if the
+    /// date type's implementation is inconsistent, as described for
+    /// [`try_calendar_duration_from`](Self::try_calendar_duration_from), which returns an error
+    /// instead.
+    #[cfg(not(feature = "strict"))]
     fn calendar_duration_from(self, other: Self) -> CalendarDuration {
-        let (later, mut earlier) = if self > other {
-            (self, other)
-        } else {
-            (other, self)
-        };
+        self.try_calendar_duration_from(other)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
 
-        let (mut y, mut m, d) = earlier.ymd();
-        let mut years = 0u32;
-        loop {
-            let next = Self::from_ymd_or_next(y + 1, m, d);
-            if later < next {
-                break;
-            }
-            years += 1;
-            y += 1;
-            earlier = next;
+    /// Compute the calendar duration difference from the other date.
+    ///
+    /// Anniversaries which are out of the range of the date type are necessarily after both dates,
+    /// so they don't cause an error. This only fails if [`days_since`](Self::days_since) gives a
+    /// negative number of days from an earlier date to a later one, or more than fit in a `u32`,
+    /// which a correct implementation never does.
+    ///
+    /// This takes constant time for date types which override `days_since`.
+    fn try_calendar_duration_from(self, other: Self)
+        -> Result<CalendarDuration, CalendarDurationError>
+    {
+        self.try_calendar_duration_from_with(other, OverflowPolicy::NextValid)
+    }
+
+    /// Compute the calendar duration difference from the other date, expressed in only the units
+    /// from `largest` down to `smallest`.
+    ///
+    /// Units larger than `largest` are folded into it: with [`DurationUnit::Months`], 31 years
+    /// and 9 months is 381 months, and with [`DurationUnit::Days`], the duration is the number of
+    /// days between the dates. Units smaller than `smallest` are dropped without rounding: with
+    /// [`DurationUnit::Months`], 1 month and 29 days is 1 month. If `smallest` is larger than
+    /// `largest`, `largest` is used for both.
+    ///
+    /// With [`DurationUnit::Years`] and [`DurationUnit::Days`], this is the same as
+    /// [`calendar_duration_from`](Self::calendar_duration_from).
+    ///
+    /// Panics if the folded months or days don't fit in a `u32`, or under the same conditions as
+    /// [`calendar_duration_from`](Self::calendar_duration_from). See
+    /// [`try_calendar_duration_from_in`](Self::try_calendar_duration_from_in) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_duration_from_in(self, other: Self, largest: DurationUnit, smallest: DurationUnit)
+        -> CalendarDuration
+    {
+        self.try_calendar_duration_from_in(other, largest, smallest)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
+
+    /// Compute the calendar duration difference from the other date in only the given units, as
+    /// with [`calendar_duration_from_in`](Self::calendar_duration_from_in).
+    fn try_calendar_duration_from_in(
+        self,
+        other: Self,
+        largest: DurationUnit,
+        smallest: DurationUnit,
+    ) -> Result<CalendarDuration, CalendarDurationError> {
+        granular::duration_in(self, other, largest, smallest)
+    }
+
+    /// Compute the calendar duration from the other date to this one, keeping its direction: the
+    /// result is negative if this date is before the other one. The magnitude is the same as
+    /// [`calendar_duration_from`](Self::calendar_duration_from) gives for either order.
+    ///
+    /// For example, a deadline's duration since today displays as "in 2 months, 3 days" if the
+    /// deadline is still to come, and "2 months, 3 days ago" if it has passed.
+    ///
+    /// Panics if the date type fails to produce a date in between the two. See
+    /// [`try_calendar_duration_since`](Self::try_calendar_duration_since) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_duration_since(self, other: Self) -> SignedCalendarDuration {
+        self.try_calendar_duration_since(other)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
+
+    /// Compute the calendar duration from the other date to this one, keeping its direction, as
+    /// with [`calendar_duration_since`](Self::calendar_duration_since).
+    fn try_calendar_duration_since(self, other: Self)
+        -> Result<SignedCalendarDuration, CalendarDurationError>
+    {
+        let duration = self.try_calendar_duration_from(other)?;
+        Ok(SignedCalendarDuration::new(duration, self < other))
+    }
+
+    /// Compute the calendar duration difference from the other date, resolving anniversaries which
+    /// fall on a day that doesn't exist in their month with the given policy.
+    ///
+    /// [`calendar_duration_from`](Self::calendar_duration_from) uses
+    /// [`OverflowPolicy::NextValid`], so from January 31st to February 28th is 28 days, since the
+    /// month's anniversary is "February 31st", or March 1st. With
+    /// [`OverflowPolicy::ClampToEndOfMonth`] the anniversary is February 28th, so it is 1 month,
+    /// as usual for billing and subscriptions.
+    ///
+    /// Panics under the same conditions as `calendar_duration_from`. See
+    /// [`try_calendar_duration_from_with`](Self::try_calendar_duration_from_with) for a version
+    /// which doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_duration_from_with(self, other: Self, policy: OverflowPolicy) -> CalendarDuration {
+        self.try_calendar_duration_from_with(other, policy)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
+
+    /// Compute the calendar duration difference from the other date, as with
+    /// [`calendar_duration_from_with`](Self::calendar_duration_from_with).
+    fn try_calendar_duration_from_with(self, other: Self, policy: OverflowPolicy)
+        -> Result<CalendarDuration, CalendarDurationError>
+    {
+        overflow::duration_from(self, other, policy)
+    }
+
+    /// Compute the calendar duration between this date and the other one, counting both of them,
+    /// as is usual for legal, employment, and rental periods. This is the duration to the day
+    /// after the later date, so January 1st through January 31st is 1 month, January 1st through
+    /// December 31st is 1 year, and a date through itself is 1 day.
+    ///
+    /// This works when the later date is [`max_value`](Self::max_value), and so has no next day.
+    /// It panics if the duration can't be computed. See
+    /// [`try_calendar_duration_from_inclusive`](Self::try_calendar_duration_from_inclusive) for a
+    /// version which doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_duration_from_inclusive(self, other: Self) -> CalendarDuration {
+        self.try_calendar_duration_from_inclusive(other)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
+
+    /// Compute the calendar duration between this date and the other one, counting both of them,
+    /// as with [`calendar_duration_from_inclusive`](Self::calendar_duration_from_inclusive).
+    ///
+    /// As well as the errors of [`try_calendar_duration_from`](Self::try_calendar_duration_from),
+    /// this fails if the later date is the last day of the last month whose year can be given to
+    /// [`from_ymd`](Self::from_ymd), since the day after it can't even be named.
+    fn try_calendar_duration_from_inclusive(self, other: Self)
+        -> Result<CalendarDuration, CalendarDurationError>
+    {
+        overflow::duration_from_inclusive(self, other)
+    }
+
+    /// Compute the calendar duration between two dates, as with
+    /// [`try_calendar_duration_from`](Self::try_calendar_duration_from), along with the steps
+    /// taken from the earlier date to the later one: the anniversary reached after adding the
+    /// years, then the months, and then the days. Units which are zero are left out.
+    ///
+    /// This is useful for showing how a duration was arrived at. See [`Step::trace`] to display
+    /// the steps.
+    #[cfg(feature = "alloc")]
+    fn calendar_duration_explained(self, other: Self) -> (CalendarDuration, Vec<Step<Self>>) {
+        explain::explained(self, other)
+    }
+
+    /// Add a calendar duration to the date.
+    ///
+    /// The years and months are added first, keeping the day of the month. If that day doesn't
+    /// exist in the resulting month, it is resolved the same way [`from_ymd_or_next`] does. Then
+    /// the days are added. To add the days first, use
+    /// [`add_calendar_duration_with`](Self::add_calendar_duration_with) and
+    /// [`AdditionOrder::DaysFirst`].
+    ///
+    /// This is the same rule [`calendar_duration_from`](Self::calendar_duration_from) uses, so
+    /// adding the duration from an earlier date to a later one to the earlier date gives back the
+    /// later one. Subtracting it from the later date doesn't always give back the earlier one:
+    /// January 30th to March 1st is 1 month (via "February 30th"), but a month before March 1st is
+    /// February 1st.
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    ///
+    /// [`from_ymd_or_next`]: Self::checked_from_ymd_or_next
+    fn add_calendar_duration(self, dur: &CalendarDuration) -> Option<Self> {
+        self.add_calendar_duration_with(dur, &AddOptions::default())
+    }
+
+    /// Add a calendar duration to the date, as with
+    /// [`add_calendar_duration`](Self::add_calendar_duration), with the given options.
+    fn add_calendar_duration_with(self, dur: &CalendarDuration, options: &AddOptions)
+        -> Option<Self>
+    {
+        arithmetic::add(self, dur, options)
+    }
+
+    /// Subtract a calendar duration from the date.
+    ///
+    /// This is the reverse of [`add_calendar_duration`](Self::add_calendar_duration): the days are
+    /// subtracted first, and then the years and months, keeping the day of the month. If that day
+    /// doesn't exist in the resulting month, it is resolved the same way [`from_ymd_or_next`] does,
+    /// so one month before March 31 is March 1 (for "February 31").
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    ///
+    /// [`from_ymd_or_next`]: Self::checked_from_ymd_or_next
+    fn sub_calendar_duration(self, dur: &CalendarDuration) -> Option<Self> {
+        arithmetic::sub(self, dur)
+    }
+
+    /// Add a number of years to the date, resolving a day which doesn't exist in the resulting
+    /// month (February 29th) with the given policy. `date + Years(n)` uses the default policy.
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    fn add_years_with(self, years: Years, policy: OverflowPolicy) -> Option<Self> {
+        self.add_calendar_duration_with(&years.into(), &AddOptions::new().overflow_policy(policy))
+    }
+
+    /// Add a number of months to the date, resolving a day which doesn't exist in the resulting
+    /// month with the given policy. `date + Months(n)` uses the default policy.
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    fn add_months_with(self, months: Months, policy: OverflowPolicy) -> Option<Self> {
+        self.add_calendar_duration_with(&months.into(), &AddOptions::new().overflow_policy(policy))
+    }
+
+    /// Subtract a number of years from the date, resolving a day which doesn't exist in the
+    /// resulting month with the given policy. `date - Years(n)` uses the default policy.
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    fn sub_years_with(self, years: Years, policy: OverflowPolicy) -> Option<Self> {
+        arithmetic::sub_with(self, &years.into(), policy)
+    }
+
+    /// Subtract a number of months from the date, resolving a day which doesn't exist in the
+    /// resulting month with the given policy. `date - Months(n)` uses the default policy.
+    ///
+    /// Returns `None` if the result can't be represented by the date type.
+    fn sub_months_with(self, months: Months, policy: OverflowPolicy) -> Option<Self> {
+        arithmetic::sub_with(self, &months.into(), policy)
+    }
+
+    /// Return every date whose calendar duration from the later date is exactly the given one, in
+    /// order.
+    ///
+    /// Because months have different lengths, several start dates can give the same duration:
+    /// December 29th, 2024 through January 1st, 2025 are all 2 months and 14 days before March
+    /// 15th, 2025, via "February 29th" and so on. Some durations aren't the duration from any
+    /// date, so this can also be empty.
+    #[cfg(feature = "alloc")]
+    fn dates_at_duration_before(later: Self, dur: &CalendarDuration) -> Vec<Self> {
+        inverse::dates_before(later, dur)
+    }
+
+    /// Return every date whose calendar duration from the earlier date is exactly the given one,
+    /// in order. This is the counterpart of
+    /// [`dates_at_duration_before`](Self::dates_at_duration_before).
+    #[cfg(feature = "alloc")]
+    fn dates_at_duration_after(earlier: Self, dur: &CalendarDuration) -> Vec<Self> {
+        inverse::dates_after(earlier, dur)
+    }
+
+    /// Return the first date whose calendar duration from the start date is at least the given
+    /// threshold, such as the date on which ten years of service is reached.
+    ///
+    /// Durations are compared field by field: years first, then months, then days. This is not
+    /// always the same as adding the threshold to the start date. Returns `None` if the date can't
+    /// be represented by the date type.
+    fn first_date_reaching(start: Self, threshold: &CalendarDuration) -> Option<Self> {
+        Self::first_date_reaching_with(start, threshold, OverflowPolicy::NextValid)
+    }
+
+    /// Return the first date whose calendar duration from the start date is at least the given
+    /// threshold, as with [`first_date_reaching`](Self::first_date_reaching), with anniversaries
+    /// on days which don't exist in their month resolved according to the given policy.
+    fn first_date_reaching_with(
+        start: Self,
+        threshold: &CalendarDuration,
+        policy: OverflowPolicy,
+    ) -> Option<Self> {
+        reaching::first_date_reaching(start, threshold, policy)
+    }
+
+    /// Iterate over this date and the dates recurring every given duration after it, like "the
+    /// 31st of every month" or "every year on February 29th".
+    ///
+    /// Each occurrence is measured from this date rather than from the one before it, so the day
+    /// of the month doesn't drift: monthly from January 31st comes back to March 31st after
+    /// February. A day which doesn't exist in an occurrence's month is moved forward to the next
+    /// valid date, as with [`calendar_duration_from`](Self::calendar_duration_from). For a
+    /// different [`OverflowPolicy`], use [`Recurrence::with_policy`].
+    ///
+    /// The iteration stops at the first occurrence which can't be represented by the date type.
+    fn recurrences(self, every: CalendarDuration) -> Recurrences<Self> {
+        Recurrence::new(self, every).iter()
+    }
+
+    /// Iterate over the dates from the start date up to but not including the end date at each
+    /// step, like the month boundaries for billing periods. This is the same as
+    /// [`DateRange::step_by`]. The iterator is empty if the start date isn't before the end.
+    ///
+    /// Panics if the step is zero. See [`DateRange::checked_step_by`] for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_range(start: Self, end: Self, step: CalendarDuration) -> CalendarRange<Self> {
+        DateRange::new(start, end).step_by(step)
+    }
+
+    /// Return the age on the given date of a person born on this date, in whole years, as usually
+    /// counted in most of the world: it goes up by one on each anniversary of the birth date.
+    ///
+    /// Someone born on February 29th turns a year older on March 1st in common years. Returns 0
+    /// if the date is before the birth date.
+    fn age_on(self, as_of: Self) -> u32 {
+        age::age_on(self, as_of)
+    }
+
+    /// Return the number of whole years between this date and the other one, in either order.
+    ///
+    /// This is the same as the [`years`](CalendarDuration::years) of
+    /// [`calendar_duration_from`](Self::calendar_duration_from), found from the years of the two
+    /// dates and whether the anniversary has been reached, without computing the rest of the
+    /// duration. Someone born on February 29th has a whole year more on March 1st in common
+    /// years, not February 28th.
+    fn whole_years_since(self, other: Self) -> u32 {
+        overflow::whole_years_between(self, other)
+    }
+
+    /// Return the number of whole months between this date and the other one, in either order.
+    ///
+    /// This is the same as 12 times the [`years`](CalendarDuration::years) of
+    /// [`calendar_duration_from`](Self::calendar_duration_from) plus its
+    /// [`months`](CalendarDuration::months), found from the months of the two dates without
+    /// computing the rest of the duration. Returns `u32::MAX` if the number doesn't fit.
+    fn whole_months_since(self, other: Self) -> u32 {
+        overflow::whole_months_between(self, other)
+    }
+
+    /// Return the East Asian age on the given date of a person born on this date, as
+    /// traditionally counted in Korea and elsewhere: a person is 1 on the day they are born, and a
+    /// year older every January 1st. This is the number of calendar years the person has lived
+    /// in, and is usually one or two more than [`age_on`](Self::age_on).
+    ///
+    /// Returns 0 if the date is before the birth date.
+    fn east_asian_age(self, as_of: Self) -> u32 {
+        age::east_asian_age(self, as_of)
+    }
+
+    /// Return the earliest and latest birth dates, inclusive, of a person whose
+    /// [`age_on`](Self::age_on) the given date is the given number of whole years.
+    ///
+    /// The range is usually a year long, but takes the leap-day rule of `age_on` into account:
+    /// someone born on February 29th is the same age as someone born on February 28th until
+    /// March 1st. Returns `None` if either end of the range can't be represented by the date type.
+    fn birthdate_range_for_age(years: u32, as_of: Self) -> Option<(Self, Self)> {
+        age::birthdate_range_for_age(years, as_of)
+    }
+
+    /// Return the first anniversary of this date after the given one, with anniversaries of
+    /// February 29th in common years observed according to the policy. If the given date is
+    /// before this one, this date itself is next.
+    ///
+    /// Returns `None` if the anniversary can't be represented by the date type.
+    fn next_anniversary(self, as_of: Self, policy: LeapDayPolicy) -> Option<Self> {
+        age::next_anniversary(self, as_of, policy)
+    }
+
+    /// Return the time since the most recent anniversary of this date, and the time until the
+    /// next one, on the given date: "turned 36 two months ago, 10 months until 37". The next
+    /// anniversary is the one given by [`next_anniversary`](Self::next_anniversary), so on an
+    /// anniversary the first duration is zero and the second is usually a year. If the given date
+    /// is before this one, the first duration is zero and the second is the time until this date.
+    ///
+    /// Panics if the next anniversary can't be represented by the date type.
+    #[cfg(not(feature = "strict"))]
+    fn anniversary_span(self, as_of: Self, policy: LeapDayPolicy)
+        -> (CalendarDuration, CalendarDuration)
+    {
+        age::anniversary_span(self, as_of, policy).expect("date out of range")
+    }
+
+    /// Return whether the required duration has been completed from this date to the given one,
+    /// such as whether someone born on this date is old enough for an age gate.
+    ///
+    /// The requirement is added to this date, with a day of the month which doesn't exist resolved
+    /// according to the policy, and the result is compared to the given date. So someone born on
+    /// February 29th reaches 18 years on February 28th or March 1st in a common year, depending on
+    /// the policy. This isn't the same as comparing the fields of
+    /// [`calendar_duration_from`](Self::calendar_duration_from) with the requirement, because the
+    /// duration between two dates isn't always the duration which gets from one to the other.
+    ///
+    /// Returns `false` if adding the requirement goes past the range of the date type.
+    fn has_elapsed(self, required: &CalendarDuration, as_of: Self, policy: LeapDayPolicy) -> bool {
+        age::has_elapsed(self, required, as_of, policy)
+    }
+
+    /// Return both the [`age_on`](Self::age_on) and the
+    /// [`east_asian_age`](Self::east_asian_age) on the given date of a person born on this date.
+    fn age_report(self, as_of: Self) -> AgeReport {
+        AgeReport {
+            international: self.age_on(as_of),
+            east_asian: self.east_asian_age(as_of),
         }
+    }
 
-        let mut months = 0;
-        loop {
-            let mut next_m = m + 1;
-            let mut next_y = y;
-            if next_m == 13 {
-                next_m = 1;
-                next_y += 1;
-            }
+    /// Return the amount accrued from this start date to the given date, at the given rate for
+    /// each completed period, such as 1.25 days of leave per completed month of service. The
+    /// amount is limited to the cap, if one is given.
+    ///
+    /// Periods are completed on the anniversaries of the start date, the same as the months and
+    /// years of [`calendar_duration_from`](Self::calendar_duration_from). Nothing accrues if the
+    /// date is not after the start date.
+    fn accrued(self, as_of: Self, per: AccrualPeriod, rate: f64, cap: Option<f64>) -> f64 {
+        accrual::accrued(self, as_of, per, rate, cap)
+    }
 
-            let next = Self::from_ymd_or_next(next_y, next_m, d);
-            if later < next {
-                break;
-            }
+    /// Return the amount accrued from this start date to the given date, as with
+    /// [`accrued`](Self::accrued), plus a prorated amount for the current partial period.
+    ///
+    /// The partial period is prorated by the number of days elapsed out of its actual length, so
+    /// half of February is fewer days than half of March.
+    fn accrued_prorated(self, as_of: Self, per: AccrualPeriod, rate: f64, cap: Option<f64>)
+        -> f64
+    {
+        accrual::accrued_prorated(self, as_of, per, rate, cap)
+    }
 
-            months += 1;
-            y = next_y;
-            m = next_m;
-            earlier = next;
+    /// Return whether this date is within the given window of time before the reference date,
+    /// such as "within the last 18 months".
+    ///
+    /// This is true when the date is on or after
+    /// [`reference.sub_calendar_duration(window)`](Self::sub_calendar_duration), so the first day
+    /// of the window is included. Note that this means month-end references have windows starting
+    /// on the first of a month when the month before has fewer days: one month back from March 31
+    /// starts on March 1. Dates after the reference are considered within the window. If the start
+    /// of the window can't be represented by the date type, every date is within it.
+    fn is_within(self, reference: Self, window: &CalendarDuration) -> bool {
+        match reference.sub_calendar_duration(window) {
+            Some(start) => self >= start,
+            None => true,
         }
+    }
+
+    /// Classify this date relative to a reference date (usually today) into a coarse bucket like
+    /// "yesterday", "last week", or "last month", using calendar week, month, and year boundaries.
+    ///
+    /// See [`RelativeBucket`] for details.
+    fn classify_relative(self, reference: Self) -> RelativeBucket {
+        relative::classify(self, reference)
+    }
 
+    /// Return the key of the calendar bucket (ISO week, month, quarter, or year) containing the
+    /// date, for grouping dates into cohorts.
+    fn bucket(self, granularity: Granularity) -> BucketKey {
+        bucket::bucket(self, granularity)
+    }
+
+    /// Return the first date in the given calendar bucket, or `None` if the key doesn't identify a
+    /// valid bucket or the date can't be represented.
+    fn bucket_start_date(key: BucketKey) -> Option<Self> {
+        bucket::bucket_start_date(key)
+    }
+
+    /// Return the number of days from 1970-01-01 in the proleptic Gregorian calendar to the date,
+    /// which is negative for earlier dates.
+    ///
+    /// Adding days, weekdays, and business days all go through this and
+    /// [`from_day_number`](Self::from_day_number). The default treats the date's
+    /// [`ymd`](Self::ymd) as a Gregorian date, so calendars other than the Gregorian one must
+    /// override both.
+    fn to_day_number(self) -> i64 {
+        let (y, m, d) = self.ymd();
+        gregorian::days_from_civil(y, m, d)
+    }
+
+    /// Return the date for the given number of days from 1970-01-01 in the proleptic Gregorian
+    /// calendar, or `None` if the date type can't represent it. The inverse of
+    /// [`to_day_number`](Self::to_day_number).
+    fn from_day_number(days: i64) -> Option<Self> {
+        let (y, m, d) = gregorian::checked_civil_from_days(days)?;
+        Self::from_ymd(y, m, d)
+    }
+
+    /// Return the number of days from the other date to this one, which is negative if this date
+    /// is before the other one.
+    ///
+    /// The default implementation counts the days one at a time with
+    /// [`checked_succ`](Self::checked_succ), which works for any calendar but takes time
+    /// proportional to the distance. Date types which can count days directly should override it.
+    fn days_since(self, other: Self) -> i64 {
+        let (mut earlier, later, sign) = if self < other {
+            (self, other, -1)
+        } else {
+            (other, self, 1)
+        };
         let mut days = 0;
-        while later > earlier {
+        while earlier < later {
+            let Some(next) = earlier.checked_succ() else { break };
+            earlier = next;
             days += 1;
-            earlier = earlier.succ();
         }
+        sign * days
+    }
 
-        CalendarDuration { years, months, days }
+    /// Count the whole weeks and days from the other date, in either order, with no years or
+    /// months, like "38 weeks, 2 days".
+    ///
+    /// The weeks are elapsed 7-day spans from the earlier date, whichever day of the week it is,
+    /// so they're the same across the start of an ISO year as anywhere else. To count the ISO
+    /// weeks a report keyed by week number would show between the dates, see
+    /// [`iso_week_boundaries_crossed`](Self::iso_week_boundaries_crossed).
+    ///
+    /// To split only the days of a calendar duration into weeks, like "2 months, 1 week, 4 days",
+    /// see [`CalendarDuration::with_weeks`].
+    ///
+    /// Panics if the number of days doesn't fit in a `u32`. See
+    /// [`try_weeks_and_days_from`](Self::try_weeks_and_days_from) for a version which doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn weeks_and_days_from(self, other: Self) -> CalendarDurationWithWeeks {
+        self.try_weeks_and_days_from(other)
+            .unwrap_or_else(|e| panic!("computing weeks failed: {e}"))
+    }
+
+    /// Count the whole weeks and days from the other date, as with
+    /// [`weeks_and_days_from`](Self::weeks_and_days_from).
+    fn try_weeks_and_days_from(self, other: Self)
+        -> Result<CalendarDurationWithWeeks, CalendarDurationError>
+    {
+        weeks::weeks_and_days_between(self, other)
+    }
+
+    /// Return the day of the week for the date, with 0 being Monday and 6 being Sunday.
+    fn weekday_from_monday(self) -> u8 {
+        gregorian::weekday_from_days(self.to_day_number())
+    }
+
+    /// Count the whole ISO 8601 weeks from this date to the other one, and the days left over.
+    ///
+    /// Weeks are aligned to their Mondays rather than counted 7 days at a time: only the weeks
+    /// from Monday to Sunday which fall entirely between the two dates are whole, so from a
+    /// Thursday to the next Thursday is 0 weeks and 7 days, and from a Wednesday to the Wednesday
+    /// two weeks later is 1 week and 7 days. The leftover days are the parts of weeks at either
+    /// end, so there can be up to 12 of them. This works the same across the start of an ISO year
+    /// which isn't the start of a calendar year.
+    ///
+    /// The number of weeks is negative if the other date is before this one. For the number of
+    /// weeks between two [`IsoWeek`]s, see [`IsoWeek::weeks_until`].
+    fn iso_weeks_between(self, other: Self) -> (i64, u8) {
+        range::iso_weeks_between(self, other)
+    }
+
+    /// Count the ISO 8601 week boundaries (the starts of weeks, on Mondays) crossed from this date
+    /// to the other one, which is the difference between their week numbers counted across years.
+    ///
+    /// This is how far apart the dates are in a report keyed by ISO week: from Thursday 2020-12-31
+    /// in week 53 of 2020 to Monday 2021-01-04 in week 1 of 2021 is 1, though only 4 days have
+    /// elapsed. To count elapsed 7-day spans from the earlier date instead, see
+    /// [`weeks_and_days_from`](Self::weeks_and_days_from).
+    ///
+    /// The count is negative if the other date is before this one. The same as
+    /// [`IsoWeek::of(self).weeks_until(IsoWeek::of(other))`](IsoWeek::weeks_until).
+    fn iso_week_boundaries_crossed(self, other: Self) -> i64 {
+        range::iso_week_boundaries_crossed(self, other)
+    }
+
+    /// Count the weekdays (Monday through Friday) between this date and the other one.
+    ///
+    /// The earlier of the two dates is excluded from the count and the later one is included, so
+    /// the count from a Friday to the following Monday is 1, and from a Saturday to the following
+    /// Sunday is 5. The order of the arguments does not matter. This takes constant time.
+    fn business_days_from(self, other: Self) -> u32 {
+        let (a, b) = (self.to_day_number(), other.to_day_number());
+        business::weekdays_between(a.min(b), a.max(b), Weekend::SaturdaySunday) as u32
+    }
+
+    /// Count the weekdays between this date and the other one which aren't in the list of
+    /// holidays, counting the same way as [`business_days_from`](Self::business_days_from).
+    ///
+    /// Holidays which fall on weekends or outside the range aren't counted, and a holiday listed
+    /// more than once is only counted once. This takes time proportional to the number of
+    /// holidays, rather than the number of days between the dates.
+    #[cfg(feature = "alloc")]
+    fn business_days_from_with_holidays(self, other: Self, holidays: &[Self]) -> u32 {
+        let (later, earlier) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let holidays = holidays.iter()
+            .filter(|&&day| earlier < day && day <= later)
+            .map(|&day| day.to_day_number())
+            .collect::<Vec<_>>();
+        business::business_days_between_listed(
+            earlier.to_day_number(),
+            later.to_day_number(),
+            Weekend::SaturdaySunday,
+            holidays)
+    }
+
+    /// Count the business days between this date and the other one: days which are neither part
+    /// of the given weekend nor a holiday in the given calendar.
+    ///
+    /// The earlier of the two dates is excluded from the count and the later one is included, so
+    /// the count from a Friday to the following Monday is 1 with a Saturday/Sunday weekend. The
+    /// order of the arguments does not matter.
+    fn business_days_from_with(
+        self,
+        other: Self,
+        weekend: Weekend,
+        holidays: &impl HolidayCalendar,
+    ) -> u32 {
+        let (later, earlier) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        business::business_days_between(
+            earlier.to_day_number(),
+            later.to_day_number(),
+            weekend,
+            holidays)
+    }
+
+    /// Compute the year fraction between this date and the other one using the Business/252
+    /// day-count convention used in Brazilian fixed income: the number of business days (as
+    /// counted by [`business_days_from_with`](Self::business_days_from_with)) divided by 252.
+    fn year_fraction_bus_252(
+        self,
+        other: Self,
+        weekend: Weekend,
+        holidays: &impl HolidayCalendar,
+    ) -> f64 {
+        f64::from(self.business_days_from_with(other, weekend, holidays)) / 252.
     }
 }
 
@@ -113,74 +997,197 @@ pub trait CalendarDurationExt: Sized + Ord + Copy {
 ///
 /// Calendar duration includes the number of years, months, and days.
 ///
-/// It includes a [`Display`](std::fmt::Display) implementation which formats the duration nicely
-/// in English.
-#[derive(Debug, Clone)]
+/// Durations computed from two dates are always normalized: they have fewer than 12 months, and
+/// fewer days than there are in a month. Durations made directly may have more, like 45 days or
+/// 18 months. These are added to dates as given, so 18 months is the same as 1 year and 6 months,
+/// but 45 days is not necessarily the same as any number of months and days.
+///
+/// It includes a [`Display`](core::fmt::Display) implementation which formats the duration nicely
+/// in English. For other languages, see [`CalendarDurationFormatter`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "uniffi", derive(uniffi::Record))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CalendarDuration {
     /// Number of whole years of duration.
     pub years: u32,
 
     /// Number of whole months in addition to the [`years`](Self::years).
-    pub months: u8,
+    pub months: u32,
 
     /// Number of whole days in addition to the [`months`](Self::months) and
     /// [`years`](Self::years).
-    pub days: u8,
+    pub days: u32,
 }
 
-impl std::fmt::Display for CalendarDuration {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let mut any = false;
-        if self.years > 0 {
-            if self.years > 1 {
-                write!(f, "{} years", self.years)?;
-            } else {
-                f.write_str("1 year")?;
-            }
-            any = true;
-        }
+impl CalendarDuration {
+    /// The zero duration, which is also the [`Default`].
+    pub const ZERO: CalendarDuration = CalendarDuration { years: 0, months: 0, days: 0 };
 
-        if self.months > 0 {
-            if any {
-                f.write_str(", ")?;
-            }
-            if self.months > 1 {
-                write!(f, "{} months", self.months)?;
-            } else {
-                f.write_str("1 month")?;
-            }
-            any = true;
+    /// Make a duration from its components.
+    ///
+    /// These aren't checked or normalized: durations with 12 or more months, or more days than
+    /// are in a month, are allowed, and are added to dates as given. See [`CalendarDuration`].
+    pub const fn new(years: u32, months: u32, days: u32) -> Self {
+        CalendarDuration { years, months, days }
+    }
+
+    /// Return whether all the components are zero.
+    pub const fn is_zero(&self) -> bool {
+        self.years == 0 && self.months == 0 && self.days == 0
+    }
+}
+
+/// Formats the duration in English, like "31 years, 9 months, 23 days", or "same day" for the zero
+/// duration. The alternate form (`{:#}`) formats it in ISO 8601 form instead, like `P31Y9M23D`;
+/// see
+#[cfg_attr(feature = "alloc", doc = "[`to_iso8601`](CalendarDuration::to_iso8601).")]
+#[cfg_attr(not(feature = "alloc"), doc = "`to_iso8601`.")]
+///
+/// Width, fill, and alignment are honored in both forms, and default to the left as with strings.
+/// The precision limits the English form to that many of its largest nonzero units, truncating
+/// the rest, so `{:.1}` writes "31 years" for the duration above; a precision of zero is taken as
+/// one. To round instead, see [`significant`](CalendarDuration::significant).
+///
+/// ```
+/// # use calendar_duration::CalendarDuration;
+/// let dur = CalendarDuration { years: 31, months: 9, days: 23 };
+/// assert_eq!("31 years, 9 months", format!("{dur:.2}"));
+/// assert_eq!("|    31 years|", format!("|{dur:>12.1}|"));
+/// ```
+impl core::fmt::Display for CalendarDuration {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if f.alternate() {
+            return format::pad(f, |w| self.write_iso8601(w));
         }
+        let units = f.precision().map_or(3, |n| n.max(1));
+        format::pad(f, |w| self.write_english(w, units))
+    }
+}
+
+impl CalendarDuration {
+    /// Write the duration in English, with at most the given number of nonzero units.
+    fn write_english(&self, w: &mut dyn core::fmt::Write, units: usize) -> core::fmt::Result {
+        let components = [
+            (self.years, "year", "years"),
+            (self.months, "month", "months"),
+            (self.days, "day", "days"),
+        ];
 
-        if self.days > 0 {
+        let mut any = false;
+        for (n, singular, plural) in components.into_iter().filter(|c| c.0 > 0).take(units) {
             if any {
-                f.write_str(", ")?;
+                w.write_str(", ")?;
             }
-            if self.days > 1 {
-                write!(f, "{} days", self.days)?;
+            if n > 1 {
+                write!(w, "{n} {plural}")?;
             } else {
-                f.write_str("1 day")?;
+                write!(w, "1 {singular}")?;
             }
             any = true;
         }
 
         if !any {
-            f.write_str("same day")?
+            w.write_str("same day")?;
         }
+        Ok(())
+    }
+}
 
+/// An error from computing a calendar duration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CalendarDurationError {
+    /// A date needed to compute the duration could not be represented by the date type.
+    OutOfRange,
+}
+
+impl core::fmt::Display for CalendarDurationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            CalendarDurationError::OutOfRange => f.write_str("date out of range"),
+        }
+    }
+}
+
+impl core::error::Error for CalendarDurationError {}
+
+/// Compute the difference between two dates, for use within the crate, where the dates are always
+/// valid and so [`CalendarDurationExt::try_calendar_duration_from`] can't fail for any correct
+/// implementation of the trait. This gives the zero duration for an incorrect one.
+pub(crate) fn duration_between<D: CalendarDurationExt>(a: D, b: D) -> CalendarDuration {
+    a.try_calendar_duration_from(b)
+        .unwrap_or(CalendarDuration { years: 0, months: 0, days: 0 })
+}
+
+/// `Display` output written into a fixed buffer, so that the common tests can check formatting
+/// without an allocator.
+#[cfg(all(test, not(feature = "strict")))]
+struct TestString {
+    buf: [u8; 64],
+    len: usize,
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+impl TestString {
+    fn as_str(&self) -> &str {
+        core::str::from_utf8(&self.buf[..self.len]).expect("only whole strs are written")
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+impl core::fmt::Write for TestString {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.buf.get_mut(self.len..end).ok_or(core::fmt::Error)?.copy_from_slice(s.as_bytes());
+        self.len = end;
         Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, not(feature = "strict")))]
+impl core::fmt::Debug for TestString {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        core::fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+impl PartialEq<&str> for TestString {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+impl PartialEq<TestString> for &str {
+    fn eq(&self, other: &TestString) -> bool {
+        *self == other.as_str()
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+trait ToTestString: core::fmt::Display {
+    fn to_test_string(&self) -> TestString {
+        let mut s = TestString { buf: [0; 64], len: 0 };
+        core::fmt::Write::write_fmt(&mut s, format_args!("{self}"))
+            .expect("test output fits in the buffer");
+        s
+    }
+}
+
+#[cfg(all(test, not(feature = "strict")))]
+impl<T: core::fmt::Display + ?Sized> ToTestString for T {}
+
+#[cfg(all(test, not(feature = "strict")))]
 macro_rules! tests {
     ($ctor:expr) => {
+        use crate::ToTestString;
+
         #[test]
         fn it_works() {
             let a = $ctor(2020, 4, 8);
             let b = $ctor(1988, 6, 16);
             let c = a.calendar_duration_from(b);
-            assert_eq!(c.to_string(), "31 years, 9 months, 23 days");
+            assert_eq!(c.to_test_string(), "31 years, 9 months, 23 days");
         }
 
         #[test]
@@ -189,7 +1196,115 @@ macro_rules! tests {
                 $ctor(1999, 12, 31)
                     .calendar_duration_from(
                         $ctor(1999, 12, 31))
-                    .to_string());
+                    .to_test_string());
+        }
+
+        #[test]
+        fn weeks_and_days() {
+            let due = $ctor(2024, 10, 7);
+            let today = $ctor(2024, 2, 28);
+            assert_eq!("7 months, 1 week, 2 days",
+                due.calendar_duration_from(today).with_weeks().to_test_string());
+            assert_eq!("31 weeks, 5 days", today.weeks_and_days_from(due).to_test_string());
+            assert_eq!("same day", today.weeks_and_days_from(today).to_test_string());
+        }
+
+        #[test]
+        fn whole_years_and_months() {
+            let born = $ctor(2004, 2, 29);
+            for (y, m, d, years, months) in [(2023, 2, 28, 18, 227), (2023, 3, 1, 19, 228),
+                (2024, 2, 28, 19, 239), (2024, 2, 29, 20, 240), (2004, 3, 28, 0, 0),
+                (2004, 3, 29, 0, 1), (2004, 2, 29, 0, 0)]
+            {
+                let as_of = $ctor(y, m, d);
+                assert_eq!(years, as_of.whole_years_since(born), "{y}-{m}-{d}");
+                assert_eq!(years, born.whole_years_since(as_of), "{y}-{m}-{d}");
+                assert_eq!(months, as_of.whole_months_since(born), "{y}-{m}-{d}");
+                let dur = as_of.calendar_duration_from(born);
+                assert_eq!((dur.years, dur.years * 12 + dur.months), (years, months));
+            }
+            // One month after January 31st is March 1st.
+            assert_eq!(0, $ctor(2023, 2, 28).whole_months_since($ctor(2023, 1, 31)));
+            assert_eq!(1, $ctor(2023, 3, 1).whole_months_since($ctor(2023, 1, 31)));
+            assert_eq!(12, $ctor(2024, 1, 31).whole_months_since($ctor(2023, 1, 31)));
+            assert_eq!(13, $ctor(2024, 3, 1).whole_months_since($ctor(2023, 1, 31)));
+        }
+
+        #[test]
+        fn selected_units() {
+            use DurationUnit::*;
+            let a = $ctor(2020, 4, 8);
+            let b = $ctor(1988, 6, 16);
+            let within = |largest, smallest| a.calendar_duration_from_in(b, largest, smallest);
+            assert_eq!(a.calendar_duration_from(b), within(Years, Days));
+            assert_eq!("381 months, 23 days", within(Months, Days).to_test_string());
+            assert_eq!("11619 days", within(Days, Days).to_test_string());
+            assert_eq!("31 years, 9 months", within(Years, Months).to_test_string());
+            assert_eq!("31 years", within(Years, Years).to_test_string());
+            assert_eq!("381 months", within(Months, Years).to_test_string());
+
+            // 29 days, which is 1 month and 1 day in a common year.
+            let (a, b) = ($ctor(2021, 3, 2), $ctor(2021, 2, 1));
+            assert_eq!("1 month", b.calendar_duration_from_in(a, Months, Months).to_test_string());
+            assert_eq!("29 days", b.calendar_duration_from_in(a, Days, Days).to_test_string());
+            let (a, b) = ($ctor(2021, 1, 30), $ctor(2021, 1, 1));
+            assert_eq!("same day", a.calendar_duration_from_in(b, Years, Months).to_test_string());
+        }
+
+        #[test]
+        fn since_keeps_direction() {
+            let deadline = $ctor(2020, 6, 11);
+            let today = $ctor(2020, 4, 8);
+            let ahead = deadline.calendar_duration_since(today);
+            let behind = today.calendar_duration_since(deadline);
+            assert_eq!(ahead.magnitude(), behind.magnitude());
+            assert_eq!(&deadline.calendar_duration_from(today), ahead.magnitude());
+            assert!(!ahead.is_negative());
+            assert!(behind.is_negative());
+            assert_eq!("in 2 months, 3 days", ahead.to_test_string());
+            assert_eq!("2 months, 3 days ago", behind.to_test_string());
+            assert_eq!("same day", today.calendar_duration_since(today).to_test_string());
+        }
+
+        #[test]
+        fn overflow_policies() {
+            use OverflowPolicy::*;
+            let jan31 = $ctor(2023, 1, 31);
+            let feb28 = $ctor(2023, 2, 28);
+            assert_eq!("28 days", feb28.calendar_duration_from(jan31).to_test_string());
+            assert_eq!("28 days",
+                feb28.calendar_duration_from_with(jan31, NextValid).to_test_string());
+            assert_eq!("1 month",
+                feb28.calendar_duration_from_with(jan31, ClampToEndOfMonth).to_test_string());
+            assert_eq!("1 month",
+                jan31.calendar_duration_from_with(feb28, ClampToEndOfMonth).to_test_string());
+
+            // February 29th's anniversary in a non-leap year is March 1st, or clamped to
+            // February 28th.
+            let leap_day = $ctor(2024, 2, 29);
+            let (feb28, mar1) = ($ctor(2025, 2, 28), $ctor(2025, 3, 1));
+            assert_eq!("11 months, 30 days",
+                feb28.calendar_duration_from_with(leap_day, NextValid).to_test_string());
+            assert_eq!("1 year",
+                mar1.calendar_duration_from_with(leap_day, NextValid).to_test_string());
+            assert_eq!("1 year",
+                feb28.calendar_duration_from_with(leap_day, ClampToEndOfMonth).to_test_string());
+            assert_eq!("1 year, 1 day",
+                mar1.calendar_duration_from_with(leap_day, ClampToEndOfMonth).to_test_string());
+
+            fn clamped<D: CalendarDurationExt>(_like: D, y: i32, m: u8, d: u8) -> Option<D> {
+                D::checked_from_ymd_or_clamped(y, m, d)
+            }
+            fn or_clamped<D: CalendarDurationExt>(_like: D, y: i32, m: u8, d: u8) -> D {
+                D::from_ymd_or_clamped(y, m, d)
+            }
+            fn or_next<D: CalendarDurationExt>(_like: D, y: i32, m: u8, d: u8) -> D {
+                D::from_ymd_or_next(y, m, d)
+            }
+            assert_eq!(feb28, or_clamped(feb28, 2025, 2, 29));
+            assert_eq!(mar1, or_next(feb28, 2025, 2, 29));
+            assert_eq!(Some($ctor(2024, 4, 30)), clamped(feb28, 2024, 4, 31));
+            assert_eq!(None, clamped(feb28, 2024, 13, 1));
         }
 
         #[test]
@@ -198,7 +1313,7 @@ macro_rules! tests {
                 $ctor(2005, 3, 1)
                     .calendar_duration_from(
                         $ctor(2004, 2, 29))
-                    .to_string());
+                    .to_test_string());
         }
 
         #[test]
@@ -207,7 +1322,7 @@ macro_rules! tests {
                 $ctor(2005, 3, 1)
                     .calendar_duration_from(
                         $ctor(2004, 3, 1))
-                    .to_string());
+                    .to_test_string());
         }
 
         #[test]
@@ -216,7 +1331,7 @@ macro_rules! tests {
                 $ctor(2000, 7, 31)
                     .calendar_duration_from(
                         $ctor(2000, 5, 31))
-                    .to_string());
+                    .to_test_string());
         }
 
         #[test]
@@ -224,13 +1339,13 @@ macro_rules! tests {
             let start = $ctor(2000, 8, 31);
             let mut earlier = $ctor(2000, 6, 30);
 
-            assert_eq!("2 months, 1 day", start.calendar_duration_from(earlier).to_string());
+            assert_eq!("2 months, 1 day", start.calendar_duration_from(earlier).to_test_string());
 
             // Next day goes to 2000-07-01 because June has 30 days.
             earlier = earlier.succ();
 
             // So we never get exactly "2 months".
-            assert_eq!("1 month, 30 days", start.calendar_duration_from(earlier).to_string());
+            assert_eq!("1 month, 30 days", start.calendar_duration_from(earlier).to_test_string());
         }
 
         #[test]
@@ -238,19 +1353,252 @@ macro_rules! tests {
             let mut start = $ctor(2024, 12, 29);
             let later = $ctor(2025, 3, 15);
 
-            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_string());
+            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_test_string());
 
             start = start.succ(); // 2024-12-30
-            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_string());
+            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_test_string());
 
             start = start.succ(); // 2024-12-31
-            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_string());
+            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_test_string());
 
             start = start.succ(); // 2025-01-01
-            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_string());
+            assert_eq!("2 months, 14 days", start.calendar_duration_from(later).to_test_string());
 
             start = start.succ(); // 2025-01-02
-            assert_eq!("2 months, 13 days", start.calendar_duration_from(later).to_string());
+            assert_eq!("2 months, 13 days", start.calendar_duration_from(later).to_test_string());
+        }
+
+        #[test]
+        fn add() {
+            let dur = CalendarDuration { years: 31, months: 9, days: 23 };
+            assert_eq!(Some($ctor(2020, 4, 8)), $ctor(1988, 6, 16).add_calendar_duration(&dur));
+
+            let dur = CalendarDuration { years: 0, months: 1, days: 0 };
+            assert_eq!(Some($ctor(2001, 1, 15)), $ctor(2000, 12, 15).add_calendar_duration(&dur));
+            assert_eq!(Some($ctor(2004, 3, 1)), $ctor(2004, 1, 31).add_calendar_duration(&dur));
+        }
+
+        #[test]
+        fn sub() {
+            let dur = CalendarDuration { years: 31, months: 9, days: 23 };
+            assert_eq!(Some($ctor(1988, 6, 16)), $ctor(2020, 4, 8).sub_calendar_duration(&dur));
+
+            let dur = CalendarDuration { years: 0, months: 1, days: 0 };
+            assert_eq!(Some($ctor(2000, 12, 15)), $ctor(2001, 1, 15).sub_calendar_duration(&dur));
+            assert_eq!(Some($ctor(2001, 3, 1)), $ctor(2001, 3, 31).sub_calendar_duration(&dur));
+            assert_eq!(Some($ctor(2001, 4, 30)), $ctor(2001, 5, 30).sub_calendar_duration(&dur));
+        }
+
+        #[test]
+        fn add_round_trip() {
+            fn round_trip<D: CalendarDurationExt + core::fmt::Debug>(earlier: D, later: D) {
+                let dur = later.calendar_duration_from(earlier);
+                assert_eq!(Some(later), earlier.add_calendar_duration(&dur), "{earlier:?} {dur:?}");
+            }
+            // Leap days.
+            round_trip($ctor(2020, 2, 29), $ctor(2021, 2, 28));
+            round_trip($ctor(2020, 2, 29), $ctor(2021, 3, 1));
+            round_trip($ctor(2020, 2, 29), $ctor(2024, 2, 29));
+            round_trip($ctor(2019, 3, 1), $ctor(2020, 2, 29));
+            // The 31st of a month.
+            round_trip($ctor(2023, 1, 31), $ctor(2023, 2, 28));
+            round_trip($ctor(2023, 1, 31), $ctor(2023, 3, 1));
+            round_trip($ctor(2023, 1, 31), $ctor(2023, 3, 31));
+            round_trip($ctor(2023, 3, 31), $ctor(2023, 6, 29));
+            // December to January.
+            round_trip($ctor(2023, 12, 15), $ctor(2024, 1, 14));
+            round_trip($ctor(2023, 12, 31), $ctor(2024, 1, 31));
+            round_trip($ctor(2023, 12, 1), $ctor(2025, 1, 1));
+
+            let start = $ctor(2023, 11, 1);
+            let mut a = start;
+            for _ in 0..200 {
+                let mut b = a;
+                for _ in 0..200 {
+                    round_trip(a, b);
+                    b = b.succ();
+                }
+                a = a.succ();
+            }
+        }
+
+        #[test]
+        fn sub_round_trip() {
+            // Subtracting gets back to the earlier date when its day of the month exists in every
+            // month on the way.
+            fn round_trip<D: CalendarDurationExt + core::fmt::Debug>(earlier: D, later: D) {
+                let dur = later.calendar_duration_from(earlier);
+                assert_eq!(Some(earlier), later.sub_calendar_duration(&dur), "{later:?} {dur:?}");
+            }
+            round_trip($ctor(2020, 2, 29), $ctor(2024, 2, 29));
+            round_trip($ctor(2020, 2, 28), $ctor(2021, 3, 1));
+            round_trip($ctor(2023, 12, 15), $ctor(2024, 1, 14));
+            round_trip($ctor(2023, 12, 31), $ctor(2024, 1, 31));
+            round_trip($ctor(2023, 1, 28), $ctor(2023, 3, 1));
+        }
+
+        #[test]
+        fn or_next_at_end_of_month() {
+            fn or_next<D: CalendarDurationExt>(_: D, y: i32, m: u8, d: u8) -> (i32, u8, u8) {
+                D::from_ymd_or_next(y, m, d).ymd()
+            }
+            let sample = $ctor(2000, 1, 1);
+            for y in [2023, 2024] {
+                for m in 1 ..= 12 {
+                    for d in 29 ..= 31 {
+                        let expected = if d <= gregorian::days_in_month(y, m) {
+                            (y, m, d)
+                        } else {
+                            (y, m + 1, 1)
+                        };
+                        assert_eq!(expected, or_next(sample, y, m, d), "({y}, {m}, {d})");
+                    }
+                }
+            }
+        }
+
+        #[test]
+        fn from_the_31st_across_30_day_months() {
+            // Each month, with the whole months to it from January 31st.
+            for (m, months) in [(4, 2), (6, 4), (9, 7), (11, 9)] {
+                // The month before and the month after each have a 31st.
+                let start = $ctor(2023, m - 1, 31);
+                let from_start = |y, m, d| $ctor(y, m, d).calendar_duration_from(start);
+                assert_eq!(CalendarDuration::new(0, 0, 30), from_start(2023, m, 30));
+                assert_eq!(CalendarDuration::new(0, 1, 0), from_start(2023, m + 1, 1));
+                assert_eq!(CalendarDuration::new(0, 1, 1), from_start(2023, m + 1, 2));
+                assert_eq!(CalendarDuration::new(0, 2, 0), from_start(2023, m + 1, 31));
+                assert_eq!(CalendarDuration::new(1, 0, 0), from_start(2024, m - 1, 31));
+
+                let january = $ctor(2023, 1, 31);
+                assert_eq!(CalendarDuration::new(0, months, 30),
+                    $ctor(2023, m, 30).calendar_duration_from(january));
+                assert_eq!(CalendarDuration::new(0, months + 1, 0),
+                    $ctor(2023, m + 1, 1).calendar_duration_from(january));
+            }
+        }
+
+        #[test]
+        fn inclusive() {
+            for (y, m, last) in [(2023, 2, 28), (2024, 2, 29), (2023, 4, 30), (2023, 1, 31)] {
+                assert_eq!(CalendarDuration::new(0, 1, 0),
+                    $ctor(y, m, 1).calendar_duration_from_inclusive($ctor(y, m, last)));
+                assert_eq!(CalendarDuration::new(0, 1, 0),
+                    $ctor(y, m, last).calendar_duration_from_inclusive($ctor(y, m, 1)));
+            }
+            let (new_year, new_years_eve) = ($ctor(2023, 1, 1), $ctor(2023, 12, 31));
+            assert_eq!("1 year",
+                new_year.calendar_duration_from_inclusive(new_years_eve).to_test_string());
+            let leap_day = $ctor(2024, 2, 29);
+            assert_eq!("1 day",
+                leap_day.calendar_duration_from_inclusive(leap_day).to_test_string());
+            assert_eq!(CalendarDuration::new(0, 0, 15),
+                $ctor(2024, 1, 1).calendar_duration_from_inclusive($ctor(2024, 1, 15)));
+        }
+
+        #[test]
+        fn add_out_of_range() {
+            fn bounds<D: CalendarDurationExt>(_: D) -> (D, D) {
+                (D::min_value(), D::max_value())
+            }
+            let (min, max) = bounds($ctor(2000, 1, 1));
+            let day = CalendarDuration { years: 0, months: 0, days: 1 };
+            let month = CalendarDuration { years: 0, months: 1, days: 0 };
+            let forever = CalendarDuration { years: u32::MAX, months: u32::MAX, days: u32::MAX };
+            assert_eq!(None, max.add_calendar_duration(&day));
+            assert_eq!(None, max.add_calendar_duration(&month));
+            assert_eq!(None, $ctor(2000, 1, 1).add_calendar_duration(&forever));
+            assert_eq!(None, min.sub_calendar_duration(&day));
+            assert_eq!(None, min.sub_calendar_duration(&month));
+            assert_eq!(None, $ctor(2000, 1, 1).sub_calendar_duration(&forever));
+        }
+
+        #[test]
+        fn denormalized() {
+            // 45 days and 18 months are added as given, without converting them first.
+            let days = CalendarDuration { years: 0, months: 0, days: 45 };
+            assert_eq!(Some($ctor(2023, 3, 17)), $ctor(2023, 1, 31).add_calendar_duration(&days));
+            assert_eq!(Some($ctor(2023, 1, 31)), $ctor(2023, 3, 17).sub_calendar_duration(&days));
+
+            let months = CalendarDuration { years: 0, months: 18, days: 0 };
+            let normalized = CalendarDuration { years: 1, months: 6, days: 0 };
+            assert_eq!($ctor(2023, 8, 31).add_calendar_duration(&normalized),
+                $ctor(2023, 8, 31).add_calendar_duration(&months));
+            assert_eq!(Some($ctor(2025, 3, 1)), $ctor(2023, 8, 31).add_calendar_duration(&months));
+
+            let mixed = CalendarDuration { years: 1, months: 14, days: 40 };
+            assert_eq!(Some($ctor(2022, 4, 10)), $ctor(2020, 1, 1).add_calendar_duration(&mixed));
+        }
+
+        #[test]
+        fn always_normalized() {
+            // The difference between any two dates has fewer than 12 months, and fewer days than
+            // there are in a month.
+            let start = $ctor(2019, 12, 1);
+            let mut a = start;
+            for _ in 0..500 {
+                let mut b = start;
+                for _ in 0..500 {
+                    let c = a.calendar_duration_from(b);
+                    assert!(c.months < 12, "{a:?} {b:?} {c:?}");
+                    assert!(c.days < 31, "{a:?} {b:?} {c:?}");
+                    b = b.succ();
+                }
+                a = a.succ();
+            }
+        }
+
+        #[test]
+        fn is_within() {
+            let eighteen_months = CalendarDuration { years: 1, months: 6, days: 0 };
+            let reference = $ctor(2024, 6, 15);
+            assert!($ctor(2022, 12, 15).is_within(reference, &eighteen_months));
+            assert!(!$ctor(2022, 12, 14).is_within(reference, &eighteen_months));
+            assert!(reference.is_within(reference, &eighteen_months));
+
+            // The month before March 31 has no 31st, so the window starts on March 1.
+            let one_month = CalendarDuration { years: 0, months: 1, days: 0 };
+            let reference = $ctor(2023, 3, 31);
+            assert!($ctor(2023, 3, 1).is_within(reference, &one_month));
+            assert!(!$ctor(2023, 2, 28).is_within(reference, &one_month));
+        }
+
+        #[test]
+        fn add_in_either_order() {
+            let dur = CalendarDuration { years: 0, months: 1, days: 30 };
+            let days_first = AddOptions::new().order(AdditionOrder::DaysFirst);
+            assert_eq!(Some($ctor(2023, 3, 31)), $ctor(2023, 1, 30).add_calendar_duration(&dur));
+            assert_eq!(Some($ctor(2023, 4, 1)),
+                $ctor(2023, 1, 30).add_calendar_duration_with(&dur, &days_first));
+            assert_eq!(Some($ctor(2024, 3, 31)), $ctor(2024, 1, 30).add_calendar_duration(&dur));
+            assert_eq!(Some($ctor(2024, 3, 29)),
+                $ctor(2024, 1, 30).add_calendar_duration_with(&dur, &days_first));
+
+            // Without any month-end overflow, the order doesn't matter.
+            let dur = CalendarDuration { years: 0, months: 1, days: 10 };
+            assert_eq!(Some($ctor(2024, 2, 11)),
+                $ctor(2024, 1, 1).add_calendar_duration_with(&dur, &days_first));
+            assert_eq!(Some($ctor(2024, 2, 11)), $ctor(2024, 1, 1).add_calendar_duration(&dur));
+        }
+
+        #[test]
+        fn add_then_round_to_end_of_period() {
+            let three_months = CalendarDuration { years: 0, months: 3, days: 0 };
+            let end_of_month = AddOptions::new().then_round_to(PeriodEnd::EndOfMonth);
+            let end_of_quarter = AddOptions::new().then_round_to(PeriodEnd::EndOfQuarter);
+
+            assert_eq!(Some($ctor(2024, 4, 30)),
+                $ctor(2024, 1, 14).add_calendar_duration_with(&three_months, &end_of_month));
+            assert_eq!(Some($ctor(2024, 4, 30)),
+                $ctor(2024, 1, 1).add_calendar_duration_with(&three_months, &end_of_month));
+            assert_eq!(Some($ctor(2024, 2, 29)),
+                $ctor(2023, 11, 29).add_calendar_duration_with(&three_months, &end_of_month));
+            assert_eq!(Some($ctor(2024, 6, 30)),
+                $ctor(2024, 1, 14).add_calendar_duration_with(&three_months, &end_of_quarter));
+            assert_eq!(Some($ctor(2025, 3, 31)),
+                $ctor(2024, 12, 31).add_calendar_duration_with(&three_months, &end_of_quarter));
+            assert_eq!(Some($ctor(2024, 4, 14)),
+                $ctor(2024, 1, 14).add_calendar_duration_with(&three_months, &AddOptions::new()));
         }
     }
 }
@@ -263,24 +1611,77 @@ mod chrono_impl {
     impl CalendarDurationExt for chrono::NaiveDate {
         fn ymd(self) -> (i32, u8, u8) {
             (self.year(),
-                u8::try_from(self.month()).expect("month out of bounds"),
-                u8::try_from(self.day()).expect("day out of bounds"))
+                self.month() as u8,
+                self.day() as u8)
         }
 
         fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
             NaiveDate::from_ymd_opt(y, u32::from(m), u32::from(d))
         }
 
+        #[cfg(not(feature = "strict"))]
         fn succ(self) -> Self {
             NaiveDate::succ_opt(&self).expect("date out of range")
         }
+
+        fn checked_succ(self) -> Option<Self> {
+            NaiveDate::succ_opt(&self)
+        }
+
+        fn min_value() -> Self {
+            NaiveDate::MIN
+        }
+
+        fn max_value() -> Self {
+            NaiveDate::MAX
+        }
+
+        fn to_day_number(self) -> i64 {
+            // Day 1 of the common era is 0001-01-01, 719,163 days before 1970-01-01.
+            i64::from(self.num_days_from_ce()) - 719_163
+        }
+
+        fn from_day_number(days: i64) -> Option<Self> {
+            let days = i32::try_from(days.checked_add(719_163)?).ok()?;
+            NaiveDate::from_num_days_from_ce_opt(days)
+        }
+
+        fn days_since(self, other: Self) -> i64 {
+            i64::from(self.num_days_from_ce()) - i64::from(other.num_days_from_ce())
+        }
+
+        fn weekday_from_monday(self) -> u8 {
+            self.weekday().num_days_from_monday() as u8
+        }
     }
 
-    #[cfg(test)]
+    #[cfg(all(test, not(feature = "strict")))]
     mod test {
         use super::*;
 
-        tests!(NaiveDate::from_ymd);
+        tests!(|y, m, d| NaiveDate::from_ymd_opt(y, m, d).expect("failed to construct NaiveDate"));
+
+        #[test]
+        fn bounds() {
+            assert_eq!((-262143, 1, 1), NaiveDate::min_value().ymd());
+            assert_eq!((262142, 12, 31), NaiveDate::max_value().ymd());
+            assert_eq!(None, NaiveDate::max_value().checked_succ());
+            assert_eq!(CalendarDuration { years: 524285, months: 11, days: 30 },
+                NaiveDate::max_value().calendar_duration_from(NaiveDate::min_value()));
+        }
+
+        #[test]
+        fn limits() {
+            let (max, min) = (NaiveDate::MAX, NaiveDate::MIN);
+            let before_max = max.pred_opt().unwrap();
+            assert_eq!(Ok(CalendarDuration { years: 0, months: 0, days: 1 }),
+                max.try_calendar_duration_from(before_max));
+            assert_eq!(Ok(CalendarDuration { years: 0, months: 11, days: 30 }),
+                NaiveDate::from_ymd_opt(max.year(), 1, 1).unwrap().try_calendar_duration_from(max));
+            assert_eq!(Ok(CalendarDuration { years: 0, months: 1, days: 0 }),
+                min.try_calendar_duration_from(NaiveDate::from_ymd_opt(min.year(), 2, 1).unwrap()));
+            assert!(min.try_calendar_duration_from(max).is_ok());
+        }
     }
 }
 
@@ -300,12 +1701,43 @@ mod time_impl {
             Date::from_calendar_date(y, time::Month::try_from(m).ok()?, d).ok()
         }
 
+        #[cfg(not(feature = "strict"))]
         fn succ(self) -> Self {
             self.next_day().expect("cannot increment max date")
         }
+
+        fn checked_succ(self) -> Option<Self> {
+            self.next_day()
+        }
+
+        fn min_value() -> Self {
+            Date::MIN
+        }
+
+        fn max_value() -> Self {
+            Date::MAX
+        }
+
+        fn to_day_number(self) -> i64 {
+            // 1970-01-01 is Julian day 2,440,588.
+            i64::from(self.to_julian_day()) - 2_440_588
+        }
+
+        fn from_day_number(days: i64) -> Option<Self> {
+            let days = i32::try_from(days.checked_add(2_440_588)?).ok()?;
+            Date::from_julian_day(days).ok()
+        }
+
+        fn days_since(self, other: Self) -> i64 {
+            i64::from(self.to_julian_day()) - i64::from(other.to_julian_day())
+        }
+
+        fn weekday_from_monday(self) -> u8 {
+            self.weekday().number_days_from_monday()
+        }
     }
 
-    #[cfg(test)]
+    #[cfg(all(test, not(feature = "strict")))]
     mod test {
         use super::*;
 
@@ -313,5 +1745,220 @@ mod time_impl {
             let month = time::Month::try_from(m).expect("invalid month");
             Date::from_calendar_date(y, month, d).expect("failed to construct Date")
         });
+
+        #[test]
+        fn bounds() {
+            assert_eq!((-9999, 1, 1), Date::min_value().ymd());
+            assert_eq!((9999, 12, 31), Date::max_value().ymd());
+            assert_eq!(None, Date::max_value().checked_succ());
+            assert_eq!(CalendarDuration { years: 19998, months: 11, days: 30 },
+                Date::max_value().calendar_duration_from(Date::min_value()));
+        }
+
+        #[test]
+        fn limits() {
+            let before_max = Date::MAX.previous_day().unwrap();
+            assert_eq!(Ok(CalendarDuration { years: 0, months: 0, days: 1 }),
+                Date::MAX.try_calendar_duration_from(before_max));
+            // The anniversaries on the 31st of the last month are out of range.
+            assert_eq!(Ok(CalendarDuration { years: 0, months: 11, days: 0 }),
+                Date::from_ymd(9999, 1, 31).unwrap().try_calendar_duration_from(Date::MAX));
+            assert_eq!(Ok(CalendarDuration { years: 1, months: 0, days: 0 }),
+                Date::from_ymd(-9998, 1, 1).unwrap().try_calendar_duration_from(Date::MIN));
+            assert!(Date::MIN.try_calendar_duration_from(Date::MAX).is_ok());
+        }
+
+        #[test]
+        fn inclusive_through_max() {
+            let from_max = |y, m, d| Date::from_ymd(y, m, d).unwrap()
+                .try_calendar_duration_from_inclusive(Date::MAX);
+            assert_eq!(Ok(CalendarDuration::new(0, 0, 1)),
+                Date::MAX.try_calendar_duration_from_inclusive(Date::MAX));
+            assert_eq!(Ok(CalendarDuration::new(0, 1, 0)), from_max(9999, 12, 1));
+            assert_eq!(Ok(CalendarDuration::new(1, 0, 0)), from_max(9999, 1, 1));
+            assert_eq!(Ok(CalendarDuration::new(0, 2, 0)), from_max(9999, 11, 1));
+            assert_eq!(Ok(CalendarDuration::new(0, 1, 2)), from_max(9999, 11, 30));
+            // The anniversaries on the 31st fall on the 1st of the next month when it's short.
+            assert_eq!(Ok(CalendarDuration::new(0, 2, 1)), from_max(9999, 10, 31));
+            assert_eq!(Ok(CalendarDuration::new(0, 11, 1)), from_max(9999, 1, 31));
+            assert_eq!(Ok(CalendarDuration::new(19999, 0, 0)), from_max(-9999, 1, 1));
+        }
+    }
+}
+
+#[cfg(feature = "jiff")]
+mod jiff_impl {
+    use super::*;
+    use jiff::civil::Date;
+
+    impl CalendarDurationExt for jiff::civil::Date {
+        fn ymd(self) -> (i32, u8, u8) {
+            (i32::from(self.year()), self.month() as u8, self.day() as u8)
+        }
+
+        fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+            Date::new(i16::try_from(y).ok()?, i8::try_from(m).ok()?, i8::try_from(d).ok()?).ok()
+        }
+
+        #[cfg(not(feature = "strict"))]
+        fn succ(self) -> Self {
+            self.tomorrow().expect("cannot increment max date")
+        }
+
+        fn checked_succ(self) -> Option<Self> {
+            self.tomorrow().ok()
+        }
+
+        fn min_value() -> Self {
+            Date::MIN
+        }
+
+        fn max_value() -> Self {
+            Date::MAX
+        }
+
+        fn days_since(self, other: Self) -> i64 {
+            self.to_day_number() - other.to_day_number()
+        }
+
+        fn weekday_from_monday(self) -> u8 {
+            self.weekday().to_monday_zero_offset() as u8
+        }
+    }
+
+    #[cfg(all(test, not(feature = "strict")))]
+    mod test {
+        use super::*;
+
+        tests!(|y, m, d| Date::new(y, m, d).expect("failed to construct Date"));
+
+        #[test]
+        fn bounds() {
+            assert_eq!((-9999, 1, 1), Date::min_value().ymd());
+            assert_eq!((9999, 12, 31), Date::max_value().ymd());
+            assert_eq!(None, Date::max_value().checked_succ());
+            assert_eq!(None, Date::from_ymd(10000, 1, 1));
+            assert_eq!(None, Date::from_ymd(2024, 200, 1));
+            assert_eq!(CalendarDuration { years: 19998, months: 11, days: 30 },
+                Date::max_value().calendar_duration_from(Date::min_value()));
+        }
+
+        #[cfg(feature = "chrono")]
+        #[test]
+        fn same_as_chrono() {
+            let dates = [(1988, 6, 16), (2000, 2, 29), (2000, 5, 31), (2001, 2, 28),
+                (2004, 2, 29), (2019, 12, 31), (2020, 1, 31), (2020, 4, 8), (2023, 3, 1)];
+            for (y1, m1, d1) in dates {
+                for (y2, m2, d2) in dates {
+                    let jiff = Date::new(y1, m1, d1).unwrap()
+                        .calendar_duration_from(Date::new(y2, m2, d2).unwrap());
+                    let chrono = chrono::NaiveDate::from_ymd_opt(y1.into(), m1 as u32, d1 as u32)
+                        .unwrap()
+                        .calendar_duration_from(
+                            chrono::NaiveDate::from_ymd_opt(y2.into(), m2 as u32, d2 as u32)
+                                .unwrap());
+                    assert_eq!(chrono, jiff, "{y1}-{m1}-{d1} to {y2}-{m2}-{d2}");
+                }
+            }
+        }
+    }
+}
+
+// After the `tests!` macro, so that it can use it.
+#[cfg(feature = "icu")]
+mod icu_impl;
+
+/// The common tests run against [`PlainDate`], so the algorithm is tested with no features.
+#[cfg(all(test, not(feature = "strict")))]
+mod plain_test {
+    use super::*;
+
+    tests!(|y, m, d| PlainDate::new(y, m, d).expect("failed to construct PlainDate"));
+}
+
+/// Tests of the checked methods which are all that's available with the `strict` feature.
+///
+/// In optimized builds, the `time` wrappers here are annotated with `#[no_panic]`, which fails to
+/// link if the compiler can't prove that they never panic. Run them with:
+/// ```text
+/// CARGO_PROFILE_RELEASE_CODEGEN_UNITS=1 cargo test --release --features strict,chrono,time
+/// ```
+/// The `chrono` ones aren't annotated because chrono's own date construction and field accessors
+/// contain panicking branches which the compiler can't rule out.
+#[cfg(all(test, feature = "strict", feature = "chrono", feature = "time"))]
+mod strict_test {
+    use super::*;
+    use chrono::NaiveDate;
+    use time::Date;
+
+    fn chrono_duration(a: NaiveDate, b: NaiveDate)
+        -> Result<CalendarDuration, CalendarDurationError>
+    {
+        a.try_calendar_duration_from(b)
+    }
+
+    #[cfg_attr(not(debug_assertions), no_panic::no_panic)]
+    fn time_duration(a: Date, b: Date) -> Result<CalendarDuration, CalendarDurationError> {
+        a.try_calendar_duration_from(b)
+    }
+
+    fn chrono_succ(d: NaiveDate) -> Option<NaiveDate> {
+        d.checked_succ()
+    }
+
+    #[cfg_attr(not(debug_assertions), no_panic::no_panic)]
+    fn time_succ(d: Date) -> Option<Date> {
+        d.checked_succ()
+    }
+
+    #[cfg_attr(not(debug_assertions), no_panic::no_panic)]
+    fn time_from_ymd_or_next(y: i32, m: u8, d: u8) -> Option<Date> {
+        Date::checked_from_ymd_or_next(y, m, d)
+    }
+
+    #[cfg_attr(not(debug_assertions), no_panic::no_panic)]
+    fn time_add(d: Date, dur: &CalendarDuration) -> Option<Date> {
+        d.add_calendar_duration(dur)
+    }
+
+    fn chrono_date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn time_date(y: i32, m: u8, d: u8) -> Date {
+        Date::from_ymd(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn duration() {
+        let expected = CalendarDuration { years: 31, months: 9, days: 23 };
+        assert_eq!(Ok(expected.clone()),
+            chrono_duration(chrono_date(2020, 4, 8), chrono_date(1988, 6, 16)));
+        assert_eq!(Ok(expected), time_duration(time_date(1988, 6, 16), time_date(2020, 4, 8)));
+    }
+
+    #[test]
+    fn limits() {
+        assert_eq!(None, chrono_succ(NaiveDate::MAX));
+        assert_eq!(None, time_succ(Date::MAX));
+        assert_eq!(Some(time_date(2020, 1, 2)), time_succ(time_date(2020, 1, 1)));
+
+        // Anniversaries past the end of the range don't cause errors.
+        let (y, _, _) = Date::MAX.ymd();
+        assert_eq!(Ok(CalendarDuration { years: 0, months: 11, days: 30 }),
+            time_duration(time_date(y, 1, 1), Date::MAX));
+        assert!(chrono_duration(NaiveDate::MIN, NaiveDate::MAX).is_ok());
+
+        assert_eq!(None, time_add(Date::MAX, &CalendarDuration { years: 0, months: 0, days: 1 }));
+        assert_eq!(None, time_add(Date::MAX, &CalendarDuration { years: 0, months: 1, days: 0 }));
+    }
+
+    #[test]
+    fn from_ymd_or_next() {
+        assert_eq!(Some(time_date(2023, 3, 1)), time_from_ymd_or_next(2023, 2, 29));
+        assert_eq!(Some(time_date(2024, 2, 29)), time_from_ymd_or_next(2024, 2, 29));
+        assert_eq!(None, time_from_ymd_or_next(2023, 13, 1));
+        let (y, _, _) = Date::MAX.ymd();
+        assert_eq!(None, time_from_ymd_or_next(y + 1, 1, 1));
     }
 }
@@ -0,0 +1,159 @@
+use crate::CalendarDuration;
+
+/// Make a [`PlainDate`](crate::PlainDate) constant from a date literal, checked at compile time.
+///
+/// ```
+/// use calendar_duration::{date, PlainDate};
+/// const LEAP_DAY: PlainDate = date!(2024-02-29);
+/// assert_eq!(PlainDate::new(2024, 2, 29), Some(LEAP_DAY));
+/// assert_eq!(PlainDate::new(-44, 3, 15), Some(date!(-44-03-15)));
+/// ```
+///
+/// A date which doesn't exist fails to compile:
+///
+/// ```compile_fail
+/// let date = calendar_duration::date!(2023-02-29);
+/// ```
+///
+/// ```compile_fail
+/// let date = calendar_duration::date!(2024-13-01);
+/// ```
+#[macro_export]
+macro_rules! date {
+    ($y:literal - $m:literal - $d:literal) => {{
+        // Months and days are written with leading zeros, as in ISO 8601.
+        #[allow(clippy::zero_prefixed_literal)]
+        const DATE: $crate::PlainDate = match $crate::PlainDate::new($y, $m, $d) {
+            Some(date) => date,
+            None => panic!(concat!("invalid date: ", stringify!($y-$m-$d))),
+        };
+        DATE
+    }};
+}
+
+/// Make a [`CalendarDuration`] constant from an ISO 8601 duration literal like `"P1Y6M"`, checked
+/// at compile time.
+///
+/// The duration may have years, months, weeks, and days, in that order. Weeks are counted as 7
+/// days each.
+///
+/// ```
+/// use calendar_duration::{calendar_duration, CalendarDuration};
+/// const CLIFF: CalendarDuration = calendar_duration!("P1Y6M");
+/// assert_eq!(CalendarDuration { years: 1, months: 6, days: 0 }, CLIFF);
+/// assert_eq!(CalendarDuration { years: 0, months: 0, days: 17 }, calendar_duration!("P2W3D"));
+/// ```
+///
+/// A malformed duration, or one with time components, fails to compile:
+///
+/// ```compile_fail
+/// let dur = calendar_duration::calendar_duration!("P1Y6");
+/// ```
+///
+/// ```compile_fail
+/// let dur = calendar_duration::calendar_duration!("P1DT12H");
+/// ```
+#[macro_export]
+macro_rules! calendar_duration {
+    ($s:literal) => {
+        const {
+            match $crate::__parse_duration_literal($s) {
+                Some(dur) => dur,
+                None => panic!(concat!("invalid calendar duration: ", $s)),
+            }
+        }
+    };
+}
+
+/// Parse an ISO 8601 duration with only years, months, weeks, and days, for
+/// [`calendar_duration!`]. This is a `const fn` so that the macro can check it at compile time.
+#[doc(hidden)]
+pub const fn __parse_duration_literal(s: &str) -> Option<CalendarDuration> {
+    let s = s.as_bytes();
+    if s.len() < 3 || s[0] != b'P' {
+        return None;
+    }
+
+    let mut dur = CalendarDuration { years: 0, months: 0, days: 0 };
+    // The index in "YMWD" after the last unit seen, so that units are in order.
+    let mut next_unit = 0;
+    let mut i = 1;
+    while i < s.len() {
+        let start = i;
+        let mut n = 0u32;
+        while i < s.len() && s[i].is_ascii_digit() {
+            n = match n.checked_mul(10) {
+                Some(n) => match n.checked_add((s[i] - b'0') as u32) {
+                    Some(n) => n,
+                    None => return None,
+                },
+                None => return None,
+            };
+            i += 1;
+        }
+        if i == start || i == s.len() {
+            return None;
+        }
+
+        let unit = match s[i] {
+            b'Y' => 0,
+            b'M' => 1,
+            b'W' => 2,
+            b'D' => 3,
+            _ => return None,
+        };
+        if unit < next_unit {
+            return None;
+        }
+        next_unit = unit + 1;
+        i += 1;
+
+        let n = if unit == 2 {
+            match n.checked_mul(7) {
+                Some(days) => days,
+                None => return None,
+            }
+        } else {
+            n
+        };
+        match unit {
+            0 => dur.years = n,
+            1 => dur.months = n,
+            _ => match dur.days.checked_add(n) {
+                Some(days) => dur.days = days,
+                None => return None,
+            },
+        }
+    }
+    Some(dur)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::CalendarDurationExt;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(Some(dur(1, 6, 0)), __parse_duration_literal("P1Y6M"));
+        assert_eq!(Some(dur(0, 0, 0)), __parse_duration_literal("P0D"));
+        assert_eq!(Some(dur(2, 0, 10)), __parse_duration_literal("P2Y1W3D"));
+        assert_eq!(Some(dur(u32::MAX, 0, 0)), __parse_duration_literal("P4294967295Y"));
+        for bad in ["", "P", "1Y", "PY", "P1", "P1M1Y", "P1Y1Y", "P1DT1H", "P4294967296Y",
+            "P613566757W", "p1y"]
+        {
+            assert_eq!(None, __parse_duration_literal(bad), "{bad:?}");
+        }
+    }
+
+    #[test]
+    fn macros() {
+        const START: crate::PlainDate = date!(2024-01-31);
+        assert_eq!(Some(date!(2024-03-01)),
+            START.add_calendar_duration(&calendar_duration!("P1M")));
+    }
+}
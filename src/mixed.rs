@@ -0,0 +1,342 @@
+use crate::{
+    AddOptions, CalendarDuration, CalendarDurationError, CalendarDurationExt, Days, OverflowPolicy,
+};
+use crate::iso8601::Fraction;
+use core::time::Duration;
+use core::fmt::{self, Display, Formatter};
+#[cfg(not(feature = "strict"))]
+use core::ops::Add;
+
+/// A duration with a calendar part, in years, months, and days, and an exact part, in hours,
+/// minutes, and seconds, like the ISO 8601 duration `P1M2DT3H`.
+///
+/// The calendar part is added to the date first, and then the exact part is added as an amount of
+/// elapsed time, so it can carry over into the next day. See [`add_to`](Self::add_to).
+///
+/// The [`Display`] implementation formats it like "1 month, 2 days, 3 hours".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MixedDuration {
+    /// The calendar part of the duration.
+    pub calendar: CalendarDuration,
+
+    /// The exact part of the duration.
+    pub exact: Duration,
+}
+
+/// A date-time type which a [`MixedDuration`] can be added to.
+///
+/// This is implemented for `chrono::NaiveDateTime` and `time::PrimitiveDateTime` when the
+/// corresponding features are enabled.
+pub trait CalendarDateTime: Sized + Copy {
+    /// The type of the date part.
+    type Date: CalendarDurationExt;
+
+    /// Return the date part.
+    fn date_part(self) -> Self::Date;
+
+    /// Return the same time of day on the given date.
+    fn with_date_part(self, date: Self::Date) -> Self;
+
+    /// Add an exact amount of time, or return `None` if the result is out of range.
+    fn checked_add_exact(self, exact: Duration) -> Option<Self>;
+}
+
+/// Computing calendar durations between date-times, with the part less than a day in hours,
+/// minutes, and seconds.
+///
+/// This is implemented for `chrono::NaiveDateTime` and `time::PrimitiveDateTime` when the
+/// corresponding features are enabled. Leap seconds are ignored.
+pub trait CalendarDateTimeDurationExt: CalendarDateTime + Ord {
+    /// Return the time elapsed since midnight.
+    fn time_of_day(self) -> Duration;
+
+    /// Compute the duration from the other date-time, in either order: the calendar duration
+    /// between the dates, and the time left over, which is less than a day.
+    ///
+    /// If the later date-time's time of day is before the earlier one's, the last day is
+    /// borrowed, so 2024-01-31 23:00 to 2024-02-01 01:00 is 2 hours.
+    ///
+    /// Panics under the same conditions as
+    /// [`CalendarDurationExt::calendar_duration_from`]. See
+    /// [`try_calendar_duration_from`](Self::try_calendar_duration_from) for a version which
+    /// doesn't.
+    #[cfg(not(feature = "strict"))]
+    fn calendar_duration_from(self, other: Self) -> MixedDuration {
+        self.try_calendar_duration_from(other)
+            .unwrap_or_else(|e| panic!("computing calendar duration failed: {e}"))
+    }
+
+    /// Compute the duration from the other date-time, as with
+    /// [`calendar_duration_from`](Self::calendar_duration_from).
+    fn try_calendar_duration_from(self, other: Self)
+        -> Result<MixedDuration, CalendarDurationError>
+    {
+        let (later, earlier) = if self > other {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        let (start, end) = (earlier.time_of_day(), later.time_of_day());
+        let (end_date, exact) = match end.checked_sub(start) {
+            Some(exact) => (later.date_part(), exact),
+            // The later date-time is on a later date, so there is a day before it to borrow.
+            None => (
+                later.date_part().sub_calendar_duration(&Days(1).into())
+                    .ok_or(CalendarDurationError::OutOfRange)?,
+                end + Duration::from_secs(86_400) - start,
+            ),
+        };
+        let calendar = end_date.try_calendar_duration_from(earlier.date_part())?;
+        Ok(MixedDuration { calendar, exact })
+    }
+}
+
+impl MixedDuration {
+    /// Make a mixed duration from its parts.
+    pub fn new(calendar: CalendarDuration, exact: Duration) -> Self {
+        Self { calendar, exact }
+    }
+
+    /// Add the duration to a date-time: first the calendar part to the date, resolving days past
+    /// the end of the month with the given policy, and then the exact part. Returns `None` if the
+    /// result can't be represented.
+    pub fn add_to<T: CalendarDateTime>(&self, datetime: T, policy: OverflowPolicy) -> Option<T> {
+        let options = AddOptions::new().overflow_policy(policy);
+        let date = datetime.date_part().add_calendar_duration_with(&self.calendar, &options)?;
+        datetime.with_date_part(date).checked_add_exact(self.exact)
+    }
+
+    /// Add two durations part by part, or return `None` if any part overflows.
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            calendar: CalendarDuration {
+                years: self.calendar.years.checked_add(other.calendar.years)?,
+                months: self.calendar.months.checked_add(other.calendar.months)?,
+                days: self.calendar.days.checked_add(other.calendar.days)?,
+            },
+            exact: self.exact.checked_add(other.exact)?,
+        })
+    }
+
+    /// Subtract a duration part by part, or return `None` if any part would be negative.
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            calendar: CalendarDuration {
+                years: self.calendar.years.checked_sub(other.calendar.years)?,
+                months: self.calendar.months.checked_sub(other.calendar.months)?,
+                days: self.calendar.days.checked_sub(other.calendar.days)?,
+            },
+            exact: self.exact.checked_sub(other.exact)?,
+        })
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl Add for MixedDuration {
+    type Output = MixedDuration;
+
+    /// Add two durations part by part.
+    ///
+    /// Panics if any part overflows. See [`MixedDuration::checked_add`].
+    fn add(self, other: Self) -> Self {
+        self.checked_add(&other).expect("overflow when adding durations")
+    }
+}
+
+impl Display for MixedDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let secs = self.exact.as_secs();
+        let nanos = self.exact.subsec_nanos();
+        let c = &self.calendar;
+        let components = [
+            (u64::from(c.years), "year", "years"),
+            (u64::from(c.months), "month", "months"),
+            (u64::from(c.days), "day", "days"),
+            (secs / 3600, "hour", "hours"),
+            (secs / 60 % 60, "minute", "minutes"),
+        ];
+
+        let mut any = false;
+        for (n, singular, plural) in components {
+            if n == 0 {
+                continue;
+            }
+            if any {
+                f.write_str(", ")?;
+            }
+            write!(f, "{n} {}", if n == 1 { singular } else { plural })?;
+            any = true;
+        }
+
+        let s = secs % 60;
+        if s != 0 || nanos != 0 || !any {
+            if any {
+                f.write_str(", ")?;
+            }
+            if nanos == 0 {
+                write!(f, "{s} {}", if s == 1 { "second" } else { "seconds" })?;
+            } else {
+                write!(f, "{s}.{} seconds", Fraction(nanos))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDateTime for chrono::NaiveDateTime {
+    type Date = chrono::NaiveDate;
+
+    fn date_part(self) -> chrono::NaiveDate {
+        self.date()
+    }
+
+    fn with_date_part(self, date: chrono::NaiveDate) -> Self {
+        date.and_time(self.time())
+    }
+
+    fn checked_add_exact(self, exact: Duration) -> Option<Self> {
+        self.checked_add_signed(chrono::TimeDelta::from_std(exact).ok()?)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDateTimeDurationExt for chrono::NaiveDateTime {
+    fn time_of_day(self) -> Duration {
+        use chrono::Timelike;
+        Duration::new(u64::from(self.num_seconds_from_midnight()), self.nanosecond())
+    }
+}
+
+#[cfg(feature = "time")]
+impl CalendarDateTime for time::PrimitiveDateTime {
+    type Date = time::Date;
+
+    fn date_part(self) -> time::Date {
+        self.date()
+    }
+
+    fn with_date_part(self, date: time::Date) -> Self {
+        self.replace_date(date)
+    }
+
+    fn checked_add_exact(self, exact: Duration) -> Option<Self> {
+        self.checked_add(time::Duration::try_from(exact).ok()?)
+    }
+}
+
+#[cfg(feature = "time")]
+impl CalendarDateTimeDurationExt for time::PrimitiveDateTime {
+    fn time_of_day(self) -> Duration {
+        let (h, m, s, nanos) = self.as_hms_nano();
+        Duration::new(u64::from(h) * 3600 + u64::from(m) * 60 + u64::from(s), nanos)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn mixed(months: u32, days: u32, secs: u64) -> MixedDuration {
+        MixedDuration::new(dur(0, months, days), Duration::from_secs(secs))
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("1 month, 2 days, 3 hours", mixed(1, 2, 3 * 3600).to_string());
+        assert_eq!("1 hour, 1 minute, 1 second", mixed(0, 0, 3661).to_string());
+        assert_eq!("30 hours", mixed(0, 0, 30 * 3600).to_string());
+        assert_eq!("2 days", mixed(0, 2, 0).to_string());
+        assert_eq!("0 seconds", mixed(0, 0, 0).to_string());
+        let half = MixedDuration::new(dur(1, 0, 0), Duration::from_millis(1500));
+        assert_eq!("1 year, 1.5 seconds", half.to_string());
+    }
+
+    #[test]
+    fn arithmetic() {
+        #[cfg(not(feature = "strict"))]
+        assert_eq!(mixed(1, 3, 5400), mixed(1, 2, 3600) + mixed(0, 1, 1800));
+        assert_eq!(Some(mixed(1, 3, 5400)), mixed(1, 2, 3600).checked_add(&mixed(0, 1, 1800)));
+        assert_eq!(Some(mixed(1, 1, 1800)), mixed(1, 2, 3600).checked_sub(&mixed(0, 1, 1800)));
+        assert_eq!(None, mixed(1, 2, 3600).checked_sub(&mixed(2, 0, 0)));
+        assert_eq!(None, mixed(1, 2, 0).checked_sub(&mixed(0, 0, 1)));
+        let max = MixedDuration::new(dur(u32::MAX, 0, 0), Duration::ZERO);
+        assert_eq!(None, max.checked_add(&MixedDuration::new(dur(1, 0, 0), Duration::ZERO)));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_past_midnight() {
+        let at = |y, m, d, h, min| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()
+            .and_hms_opt(h, min, 0).unwrap();
+        let start = at(2024, 1, 31, 22, 30);
+        let month_and_3_hours = mixed(1, 0, 3 * 3600);
+        assert_eq!(Some(at(2024, 3, 2, 1, 30)),
+            month_and_3_hours.add_to(start, OverflowPolicy::NextValid));
+        assert_eq!(Some(at(2024, 3, 1, 1, 30)),
+            month_and_3_hours.add_to(start, OverflowPolicy::ClampToEndOfMonth));
+        assert_eq!(Some(at(2024, 2, 1, 0, 0)), mixed(0, 0, 5400).add_to(start, Default::default()));
+    }
+
+    #[cfg(all(feature = "chrono", not(feature = "strict")))]
+    #[test]
+    fn chrono_duration_between() {
+        let at = |y, m, d, h, min, s| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap()
+            .and_hms_opt(h, min, s).unwrap();
+        let late = at(2024, 1, 31, 23, 0, 0);
+        let early = at(2024, 2, 1, 1, 0, 0);
+        assert_eq!(mixed(0, 0, 2 * 3600), early.calendar_duration_from(late));
+        assert_eq!(mixed(0, 0, 2 * 3600), late.calendar_duration_from(early));
+        assert_eq!("2 hours", late.calendar_duration_from(early).to_string());
+
+        let appointment = at(2024, 6, 3, 14, 30, 0);
+        let now = at(2024, 3, 1, 9, 15, 20);
+        assert_eq!("3 months, 2 days, 5 hours, 14 minutes, 40 seconds",
+            appointment.calendar_duration_from(now).to_string());
+        // Borrowing a day from the end of a month.
+        assert_eq!("2 months, 23 hours",
+            at(2024, 4, 1, 0, 0, 0).calendar_duration_from(at(2024, 1, 31, 1, 0, 0)).to_string());
+        assert_eq!("0 seconds", now.calendar_duration_from(now).to_string());
+
+        let precise = now + chrono::TimeDelta::milliseconds(1500);
+        assert_eq!(Ok(MixedDuration::new(dur(0, 0, 0), Duration::from_millis(1500))),
+            now.try_calendar_duration_from(precise));
+    }
+
+    #[cfg(all(feature = "time", not(feature = "strict")))]
+    #[test]
+    fn time_duration_between() {
+        let at = |y, m: u8, d, h, min| {
+            let date = time::Date::from_calendar_date(y, m.try_into().unwrap(), d).unwrap();
+            date.with_hms(h, min, 0).unwrap()
+        };
+        assert_eq!("2 hours", at(2024, 1, 31, 23, 0).calendar_duration_from(at(2024, 2, 1, 1, 0))
+            .to_string());
+        assert_eq!("1 year, 30 minutes",
+            at(2025, 3, 1, 12, 30).calendar_duration_from(at(2024, 2, 29, 12, 0)).to_string());
+        assert_eq!(Ok(mixed(0, 0, 23 * 3600 + 59 * 60)),
+            at(9999, 12, 31, 23, 59).try_calendar_duration_from(at(9999, 12, 31, 0, 0)));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_past_midnight() {
+        let at = |y, m: u8, d, h, min| {
+            let date = time::Date::from_calendar_date(y, m.try_into().unwrap(), d).unwrap();
+            date.with_hms(h, min, 0).unwrap()
+        };
+        let start = at(2023, 12, 31, 23, 59);
+        assert_eq!(Some(at(2025, 1, 1, 0, 0)),
+            MixedDuration::new(dur(1, 0, 0), Duration::from_secs(60))
+                .add_to(start, OverflowPolicy::NextValid));
+        assert_eq!(Some(at(2024, 3, 1, 0, 1)),
+            mixed(2, 0, 2 * 60).add_to(start, OverflowPolicy::ClampToEndOfMonth));
+        let max = at(9999, 12, 31, 23, 0);
+        assert_eq!(None, mixed(0, 0, 3600).add_to(max, OverflowPolicy::NextValid));
+    }
+}
@@ -0,0 +1,185 @@
+use crate::CalendarDuration;
+#[cfg(not(feature = "strict"))]
+use core::iter::Sum;
+#[cfg(not(feature = "strict"))]
+use core::ops::{Add, Mul, Sub};
+
+/// Return the duration with the given total months and days, with the months split into years,
+/// or `None` if the years don't fit.
+fn from_total_months(months: u64, days: u32) -> Option<CalendarDuration> {
+    Some(CalendarDuration {
+        years: u32::try_from(months / 12).ok()?,
+        months: (months % 12) as u32,
+        days,
+    })
+}
+
+impl CalendarDuration {
+    /// The total number of months in the years and months of the duration.
+    fn total_months(&self) -> u64 {
+        u64::from(self.years) * 12 + u64::from(self.months)
+    }
+
+    /// Add two durations, returning `None` if the result overflows.
+    ///
+    /// Months carry into years, so 2 years plus 18 months is 3 years and 6 months, and the
+    /// result always has fewer than 12 months. Days don't carry into months, since the number of
+    /// days in a month depends on the date the duration is added to: 20 days plus 20 days is 40
+    /// days. Use [`normalize_at`](Self::normalize_at) to convert them from a particular date.
+    pub fn checked_add(&self, other: &CalendarDuration) -> Option<CalendarDuration> {
+        from_total_months(self.total_months() + other.total_months(),
+            self.days.checked_add(other.days)?)
+    }
+
+    /// Subtract a duration from this one, returning `None` if the result would be negative.
+    ///
+    /// As with [`checked_add`](Self::checked_add), months and years are borrowed from each other,
+    /// but days aren't: 1 year minus 1 month is 11 months, but 1 month minus 1 day is `None`.
+    pub fn checked_sub(&self, other: &CalendarDuration) -> Option<CalendarDuration> {
+        from_total_months(self.total_months().checked_sub(other.total_months())?,
+            self.days.checked_sub(other.days)?)
+    }
+
+    /// Multiply the duration by a number, returning `None` if the result overflows.
+    ///
+    /// As with [`checked_add`](Self::checked_add), months carry into years, but days don't carry
+    /// into months: 1 month and 15 days times 3 is 3 months and 45 days.
+    pub fn checked_mul(&self, n: u32) -> Option<CalendarDuration> {
+        from_total_months(self.total_months().checked_mul(u64::from(n))?,
+            self.days.checked_mul(n)?)
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl Add for CalendarDuration {
+    type Output = CalendarDuration;
+
+    /// Add two durations, as with [`checked_add`](CalendarDuration::checked_add).
+    ///
+    /// Panics if the result overflows.
+    fn add(self, other: CalendarDuration) -> CalendarDuration {
+        self.checked_add(&other).expect("overflow when adding durations")
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl Sub for CalendarDuration {
+    type Output = CalendarDuration;
+
+    /// Subtract a duration from this one, as with [`checked_sub`](CalendarDuration::checked_sub).
+    ///
+    /// Panics if the result would be negative.
+    fn sub(self, other: CalendarDuration) -> CalendarDuration {
+        self.checked_sub(&other).expect("overflow when subtracting durations")
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl Mul<u32> for CalendarDuration {
+    type Output = CalendarDuration;
+
+    /// Multiply the duration by a number, as with [`checked_mul`](CalendarDuration::checked_mul).
+    ///
+    /// Panics if the result overflows.
+    fn mul(self, n: u32) -> CalendarDuration {
+        self.checked_mul(n).expect("overflow when multiplying duration")
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl Sum for CalendarDuration {
+    /// Add up the durations, as with [`checked_add`](CalendarDuration::checked_add).
+    ///
+    /// Panics if the result overflows.
+    fn sum<I: Iterator<Item = CalendarDuration>>(iter: I) -> CalendarDuration {
+        iter.fold(CalendarDuration::ZERO, Add::add)
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl<'a> Sum<&'a CalendarDuration> for CalendarDuration {
+    /// Add up the durations, as with [`checked_add`](CalendarDuration::checked_add).
+    ///
+    /// Panics if the result overflows.
+    fn sum<I: Iterator<Item = &'a CalendarDuration>>(iter: I) -> CalendarDuration {
+        iter.cloned().sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(Some(dur(3, 6, 0)), dur(2, 0, 0).checked_add(&dur(0, 18, 0)));
+        assert_eq!(Some(dur(1, 1, 40)), dur(0, 6, 20).checked_add(&dur(0, 7, 20)));
+        assert_eq!(Some(dur(31, 9, 23)), dur(31, 9, 23).checked_add(&CalendarDuration::ZERO));
+        assert_eq!(Some(dur(u32::MAX, 11, 0)), dur(u32::MAX, 0, 0).checked_add(&dur(0, 11, 0)));
+        assert_eq!(None, dur(u32::MAX, 0, 0).checked_add(&dur(0, 12, 0)));
+        assert_eq!(None, dur(u32::MAX, 6, 0).checked_add(&dur(0, 6, 0)));
+        assert_eq!(None, dur(0, 0, u32::MAX).checked_add(&dur(0, 0, 1)));
+        // Months which don't fit in the years are still carried.
+        assert_eq!(Some(dur(u32::MAX, 11, 0)),
+            dur(0, u32::MAX, 0).checked_add(&dur(u32::MAX - 357_913_941, 8, 0)));
+    }
+
+    #[test]
+    fn sub() {
+        assert_eq!(Some(dur(0, 11, 0)), dur(1, 0, 0).checked_sub(&dur(0, 1, 0)));
+        assert_eq!(Some(dur(1, 6, 5)), dur(3, 0, 10).checked_sub(&dur(0, 18, 5)));
+        assert_eq!(Some(CalendarDuration::ZERO), dur(1, 2, 3).checked_sub(&dur(1, 2, 3)));
+        assert_eq!(None, dur(0, 1, 0).checked_sub(&dur(0, 0, 1)));
+        assert_eq!(None, dur(1, 0, 0).checked_sub(&dur(0, 13, 0)));
+        assert_eq!(None, dur(1, 0, 5).checked_sub(&dur(1, 0, 6)));
+    }
+
+    #[test]
+    fn mul() {
+        assert_eq!(Some(dur(0, 3, 45)), dur(0, 1, 15).checked_mul(3));
+        assert_eq!(Some(dur(1, 6, 0)), dur(0, 6, 0).checked_mul(3));
+        assert_eq!(Some(CalendarDuration::ZERO), dur(31, 9, 23).checked_mul(0));
+        assert_eq!(Some(dur(u32::MAX, 0, 0)), dur(1, 0, 0).checked_mul(u32::MAX));
+        assert_eq!(None, dur(1, 1, 0).checked_mul(u32::MAX));
+        assert_eq!(None, dur(0, 0, 2).checked_mul(u32::MAX));
+        assert_eq!(Some(dur(357_913_941, 3, 0)), dur(0, 1, 0).checked_mul(u32::MAX));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn operators() {
+        assert_eq!(dur(3, 6, 0), dur(2, 0, 0) + dur(0, 18, 0));
+        assert_eq!(dur(31, 9, 23), dur(31, 9, 23) + CalendarDuration::ZERO);
+        assert_eq!(dur(1, 6, 5), dur(3, 0, 10) - dur(0, 18, 5));
+        assert_eq!(CalendarDuration::ZERO, dur(1, 2, 3) - dur(1, 2, 3));
+        assert_eq!(dur(0, 3, 45), dur(0, 1, 15) * 3);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic(expected = "overflow when adding durations")]
+    fn add_overflow() {
+        let _ = dur(u32::MAX, 0, 0) + dur(1, 0, 0);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic(expected = "overflow when subtracting durations")]
+    fn sub_negative() {
+        let _ = dur(0, 0, 0) - dur(0, 0, 1);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn sum() {
+        let installments = [dur(0, 1, 15), dur(0, 1, 15), dur(0, 1, 15)];
+        assert_eq!(dur(0, 3, 45), installments.iter().sum());
+        assert_eq!(dur(0, 3, 45), installments.into_iter().sum());
+        assert_eq!(dur(2, 6, 0), [dur(0, 18, 0), dur(1, 0, 0)].into_iter().sum());
+        assert_eq!(CalendarDuration::ZERO, core::iter::empty::<CalendarDuration>().sum());
+    }
+}
@@ -0,0 +1,488 @@
+use crate::{CalendarDuration, CalendarDurationError, CalendarDurationExt};
+
+/// What to do with a day of the month which doesn't exist in the month it is moved to, like an
+/// anniversary of January 31st in February.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum OverflowPolicy {
+    /// Move forward to the next valid date, as
+    /// [`checked_from_ymd_or_next`](CalendarDurationExt::checked_from_ymd_or_next) does: one
+    /// month after January 31st is March 1st (for "February 31st"). This is the crate's usual
+    /// behavior.
+    #[default]
+    NextValid,
+
+    /// Clamp to the last day of the month, as
+    /// [`checked_from_ymd_or_clamped`](CalendarDurationExt::checked_from_ymd_or_clamped) does:
+    /// one month after January 31st is February 28th (or 29th). This is the usual convention for
+    /// billing and subscriptions.
+    ClampToEndOfMonth,
+}
+
+impl OverflowPolicy {
+    /// Construct a date from the given year, month, and day, resolving an invalid day according
+    /// to the policy.
+    pub(crate) fn resolve<D: CalendarDurationExt>(self, y: i32, m: u8, d: u8) -> Option<D> {
+        match self {
+            OverflowPolicy::NextValid => D::checked_from_ymd_or_next(y, m, d),
+            OverflowPolicy::ClampToEndOfMonth => D::checked_from_ymd_or_clamped(y, m, d),
+        }
+    }
+}
+
+/// Return the number of whole years from the earlier date to the later one, and the anniversary
+/// of the earlier date that many years after it.
+///
+/// The years are found from the difference between the years of the two dates, less one if the
+/// anniversary in the later date's year is after it.
+fn whole_years<D: CalendarDurationExt>(earlier: D, later: D, policy: OverflowPolicy) -> (i64, D) {
+    let (start_y, m, d) = earlier.ymd();
+    // Anniversaries only move forward from the year they're in, so the one in the later date's
+    // year is the last candidate. Back off from it until one is on or before the later date. A
+    // date which can't be represented is after the later date, which can be.
+    let mut years = (i64::from(later.ymd().0) - i64::from(start_y)).max(0);
+    while years > 0 {
+        match policy.resolve::<D>((i64::from(start_y) + years) as i32, m, d) {
+            Some(anniversary) if anniversary <= later => return (years, anniversary),
+            _ => years -= 1,
+        }
+    }
+    (0, earlier)
+}
+
+/// Compute the calendar duration between two dates, in either order, with anniversaries which
+/// fall on invalid dates resolved according to the policy.
+///
+/// The years are found with [`whole_years`]. The months are then counted one at a time, which is
+/// at most a year's worth, and the days left over are counted with
+/// [`days_since`](CalendarDurationExt::days_since).
+pub(crate) fn duration_from<D: CalendarDurationExt>(
+    a: D,
+    b: D,
+    policy: OverflowPolicy,
+) -> Result<CalendarDuration, CalendarDurationError> {
+    let (later, earlier) = if a > b {
+        (a, b)
+    } else {
+        (b, a)
+    };
+
+    let (start_y, mut m, d) = earlier.ymd();
+    let (years, mut earlier) = whole_years(earlier, later, policy);
+    let mut y = (i64::from(start_y) + years) as i32;
+
+    let mut months = 0;
+    while let Some((next_y, next_m)) = D::next_month(y, m) {
+        let Some(next) = policy.resolve::<D>(next_y, next_m, d) else { break };
+        if later < next {
+            break;
+        }
+
+        months += 1;
+        y = next_y;
+        m = next_m;
+        earlier = next;
+    }
+
+    let days = later.days_since(earlier).try_into()
+        .map_err(|_| CalendarDurationError::OutOfRange)?;
+
+    Ok(CalendarDuration { years: years as u32, months, days })
+}
+
+/// Compute the calendar duration between two dates, in either order, counting both of them: the
+/// same as [`duration_from`] with the later date a day later.
+///
+/// When the later date is the last one the type can represent, the day after it can't be made,
+/// and neither can an anniversary which falls on that day, so the duration up to the later date
+/// is extended by a day, and the next anniversaries are compared with the day after it by their
+/// year, month, and day numbers instead.
+pub(crate) fn duration_from_inclusive<D: CalendarDurationExt>(a: D, b: D)
+    -> Result<CalendarDuration, CalendarDurationError>
+{
+    let (later, earlier) = if a > b {
+        (a, b)
+    } else {
+        (b, a)
+    };
+    if let Some(after) = later.checked_succ() {
+        return duration_from(after, earlier, OverflowPolicy::NextValid);
+    }
+
+    let dur = duration_from(later, earlier, OverflowPolicy::NextValid)?;
+    let (y, m, d) = later.ymd();
+    let after = if d < D::last_day_of_month(y, m) {
+        (y, m, d + 1)
+    } else {
+        let (y, m) = D::next_month(y, m).ok_or(CalendarDurationError::OutOfRange)?;
+        (y, m, 1)
+    };
+
+    // Where the anniversary in the given month falls, moved forward as by
+    // `checked_from_ymd_or_next` if the month is too short.
+    let (start_y, start_m, start_d) = earlier.ymd();
+    let anniversary = |y: i32, m: u8| {
+        if start_d <= D::last_day_of_month(y, m) {
+            Some((y, m, start_d))
+        } else {
+            D::next_month(y, m).map(|(y, m)| (y, m, 1))
+        }
+    };
+    let year = (i64::from(start_y) + i64::from(dur.years)) as i32;
+    let next_year = year.checked_add(1).and_then(|y| anniversary(y, start_m));
+    if next_year == Some(after) {
+        return Ok(CalendarDuration { years: dur.years + 1, months: 0, days: 0 });
+    }
+    let mut month = Some((year, start_m));
+    for _ in 0 ..= dur.months {
+        month = month.and_then(|(y, m)| D::next_month(y, m));
+    }
+    if month.and_then(|(y, m)| anniversary(y, m)) == Some(after) {
+        return Ok(CalendarDuration { months: dur.months + 1, days: 0, ..dur });
+    }
+    let days = dur.days.checked_add(1).ok_or(CalendarDurationError::OutOfRange)?;
+    Ok(CalendarDuration { days, ..dur })
+}
+
+/// Return the number of whole years between two dates, in either order, which is the same as the
+/// years of [`duration_from`].
+pub(crate) fn whole_years_between<D: CalendarDurationExt>(a: D, b: D) -> u32 {
+    whole_years(a.min(b), a.max(b), OverflowPolicy::NextValid).0 as u32
+}
+
+/// Return the number of whole months between two dates, in either order, which is the same as the
+/// years of [`duration_from`] times 12 plus its months, or `u32::MAX` if that doesn't fit.
+///
+/// Rather than making a date for each month after the last anniversary, this guesses the months
+/// from the month numbers of the two dates, and backs off if the guess is too many, which it can
+/// be by one when a day is moved into the next month.
+pub(crate) fn whole_months_between<D: CalendarDurationExt>(a: D, b: D) -> u32 {
+    let policy = OverflowPolicy::NextValid;
+    let (earlier, later) = (a.min(b), a.max(b));
+    let (start_y, m, d) = earlier.ymd();
+    let (years, _) = whole_years(earlier, later, policy);
+    let y = (i64::from(start_y) + years) as i32;
+
+    // The later date is less than a year after the anniversary, so this counts at most a year's
+    // worth of month numbers.
+    let later_ym = (later.ymd().0, later.ymd().1);
+    let mut months = 0;
+    let mut ym = (y, m);
+    while ym < later_ym {
+        let Some(next) = D::next_month(ym.0, ym.1) else { break };
+        ym = next;
+        months += 1;
+    }
+    while months > 0 {
+        let month = (0 .. months).try_fold((y, m), |(y, m), _| D::next_month(y, m));
+        match month.and_then(|(y, m)| policy.resolve::<D>(y, m, d)) {
+            Some(date) if date <= later => break,
+            _ => months -= 1,
+        }
+    }
+    u32::try_from(years * 12 + months.max(0)).unwrap_or(u32::MAX)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// A toy lunisolar calendar with 30-day months, where every third year has a leap month,
+    /// numbered 13, after the twelfth.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+    struct Lunisolar(i32, u8, u8);
+
+    impl CalendarDurationExt for Lunisolar {
+        fn ymd(self) -> (i32, u8, u8) {
+            (self.0, self.1, self.2)
+        }
+
+        fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+            ((1 ..= Self::months_in_year(y)).contains(&m) && (1 ..= 30).contains(&d))
+                .then_some(Self(y, m, d))
+        }
+
+        fn checked_from_ymd_or_next(y: i32, m: u8, d: u8) -> Option<Self> {
+            // Only the leap month can be missing, and it moves to the first month of next year.
+            Self::from_ymd(y, m, d).or_else(|| (m == 13).then_some(Self(y + 1, 1, d)))
+        }
+
+        fn months_in_year(y: i32) -> u8 {
+            if y % 3 == 0 { 13 } else { 12 }
+        }
+
+        fn last_day_of_month(_y: i32, _m: u8) -> u8 {
+            30
+        }
+
+        // Day 0 is the first day of year 0, and every three years have 37 months.
+        fn to_day_number(self) -> i64 {
+            let Self(y, m, d) = self;
+            let y = i64::from(y);
+            let months = 12 * y + (y + 2).div_euclid(3) + i64::from(m) - 1;
+            months * 30 + i64::from(d) - 1
+        }
+
+        fn from_day_number(days: i64) -> Option<Self> {
+            let (months, d) = (days.div_euclid(30), days.rem_euclid(30) as u8 + 1);
+            let (y, m) = match months.rem_euclid(37) {
+                m @ 0 ..= 12 => (0, m + 1),
+                m @ 13 ..= 24 => (1, m - 12),
+                m => (2, m - 24),
+            };
+            let y = i32::try_from(months.div_euclid(37) * 3 + y).ok()?;
+            Self::from_ymd(y, m as u8, d)
+        }
+
+        #[cfg(not(feature = "strict"))]
+        fn succ(self) -> Self {
+            self.checked_succ().unwrap()
+        }
+
+        fn min_value() -> Self {
+            Self(i32::MIN, 1, 1)
+        }
+
+        fn max_value() -> Self {
+            Self(i32::MAX, Self::months_in_year(i32::MAX), 30)
+        }
+
+        fn checked_succ(self) -> Option<Self> {
+            let Self(y, m, d) = self;
+            if d < 30 {
+                Some(Self(y, m, d + 1))
+            } else {
+                let (y, m) = Self::next_month(y, m)?;
+                Some(Self(y, m, 1))
+            }
+        }
+    }
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn leap_month() {
+        let diff = |a: Lunisolar, b: Lunisolar| a.try_calendar_duration_from(b);
+
+        // Year 3 has a thirteenth month, so there are two months from its twelfth month to the
+        // first month of year 4.
+        assert_eq!(Ok(dur(0, 2, 0)), diff(Lunisolar(4, 1, 5), Lunisolar(3, 12, 5)));
+        assert_eq!(Ok(dur(0, 1, 0)), diff(Lunisolar(4, 1, 5), Lunisolar(3, 13, 5)));
+        assert_eq!(Ok(dur(0, 1, 0)), diff(Lunisolar(5, 1, 5), Lunisolar(4, 12, 5)));
+        assert_eq!(Ok(dur(0, 12, 29)), diff(Lunisolar(4, 1, 4), Lunisolar(3, 1, 5)));
+        assert_eq!(Ok(dur(1, 0, 0)), diff(Lunisolar(4, 1, 5), Lunisolar(3, 1, 5)));
+        assert_eq!(Ok(dur(1, 1, 0)), diff(Lunisolar(4, 2, 5), Lunisolar(3, 1, 5)));
+
+        // Adding and subtracting months counts the leap month too.
+        let add = |date: Lunisolar, dur| date.add_calendar_duration(&dur);
+        let sub = |date: Lunisolar, dur| date.sub_calendar_duration(&dur);
+        assert_eq!(Some(Lunisolar(3, 13, 5)), add(Lunisolar(3, 12, 5), dur(0, 1, 0)));
+        assert_eq!(Some(Lunisolar(4, 1, 5)), add(Lunisolar(3, 12, 5), dur(0, 2, 0)));
+        assert_eq!(Some(Lunisolar(4, 5, 5)), add(Lunisolar(3, 5, 5), dur(0, 13, 0)));
+        assert_eq!(Some(Lunisolar(3, 6, 5)), add(Lunisolar(2, 5, 5), dur(0, 13, 0)));
+        assert_eq!(Some(Lunisolar(3, 12, 5)), sub(Lunisolar(4, 1, 5), dur(0, 2, 0)));
+        assert_eq!(Some(Lunisolar(4, 1, 1)), add(Lunisolar(3, 13, 30), dur(0, 0, 1)));
+        assert_eq!(Some(Lunisolar(3, 13, 30)), sub(Lunisolar(4, 1, 1), dur(0, 0, 1)));
+        // Year 4 has no leap month, so a year after one is moved to the start of year 5.
+        assert_eq!(Some(Lunisolar(5, 1, 5)), add(Lunisolar(3, 13, 5), dur(1, 0, 0)));
+    }
+
+    #[test]
+    fn leap_month_normalized() {
+        // 13 months is a year from a leap year's month, but a year and a month across a common
+        // year, and the same goes for 390 days.
+        let normalized = |dur: CalendarDuration, date| dur.checked_normalize_at(date);
+        assert_eq!(Some(dur(1, 0, 0)), normalized(dur(0, 13, 0), Lunisolar(3, 5, 10)));
+        assert_eq!(Some(dur(1, 1, 0)), normalized(dur(0, 13, 0), Lunisolar(2, 5, 10)));
+        assert_eq!(Some(dur(1, 0, 0)), normalized(dur(0, 0, 390), Lunisolar(3, 1, 1)));
+        assert_eq!(Some(dur(1, 1, 0)), normalized(dur(0, 0, 390), Lunisolar(4, 1, 1)));
+        assert_eq!(Some(dur(2, 1, 0)), normalized(dur(1, 14, 0), Lunisolar(2, 12, 1)));
+
+        // Every day number round trips, and the weekday comes from it.
+        for n in -1000 .. 1000 {
+            let date = Lunisolar::from_day_number(n).unwrap();
+            assert_eq!(n, date.to_day_number());
+            assert_eq!(date.checked_succ(), Lunisolar::from_day_number(n + 1));
+            assert_eq!(crate::gregorian::weekday_from_days(n), date.weekday_from_monday());
+        }
+    }
+
+    #[test]
+    fn inconsistent_date_type() {
+        /// Counts days backwards, so the days left over after the months are negative.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct Backwards(crate::PlainDate);
+
+        impl CalendarDurationExt for Backwards {
+            fn ymd(self) -> (i32, u8, u8) {
+                self.0.ymd()
+            }
+
+            fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+                crate::PlainDate::from_ymd(y, m, d).map(Backwards)
+            }
+
+            #[cfg(not(feature = "strict"))]
+            fn succ(self) -> Self {
+                self.checked_succ().unwrap()
+            }
+
+            fn checked_succ(self) -> Option<Self> {
+                self.0.checked_succ().map(Backwards)
+            }
+
+            fn min_value() -> Self {
+                Backwards(crate::PlainDate::min_value())
+            }
+
+            fn max_value() -> Self {
+                Backwards(crate::PlainDate::max_value())
+            }
+
+            fn days_since(self, other: Self) -> i64 {
+                other.0.days_since(self.0)
+            }
+        }
+
+        let date = |d| Backwards(crate::PlainDate::new(2024, 1, d).unwrap());
+        assert_eq!(Err(CalendarDurationError::OutOfRange),
+            date(5).try_calendar_duration_from(date(1)));
+        assert_eq!(Ok(dur(0, 0, 0)), date(5).try_calendar_duration_from(date(5)));
+    }
+
+    #[test]
+    fn leap_month_clamped() {
+        // The leap month doesn't exist in years 10 and 11, so its anniversaries there can't be
+        // clamped to a valid date, but the one in year 12 can. Counting the years one at a time
+        // used to stop at the first missing anniversary, giving 46 months.
+        assert_eq!(Ok(dur(3, 9, 16)),
+            duration_from(Lunisolar(9, 13, 21), Lunisolar(13, 10, 7),
+                OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    /// The original algorithm, which counts the years, months, and days one at a time.
+    fn loop_duration_from<D: CalendarDurationExt>(a: D, b: D, policy: OverflowPolicy)
+        -> Result<CalendarDuration, CalendarDurationError>
+    {
+        let (later, mut earlier) = if a > b { (a, b) } else { (b, a) };
+        let (mut y, mut m, d) = earlier.ymd();
+        let mut years = 0u32;
+        let max_year = D::max_value().ymd().0;
+        while let Some(next) = Some(y).filter(|&y| y < max_year)
+            .and_then(|y| policy.resolve::<D>(y + 1, m, d))
+        {
+            if later < next {
+                break;
+            }
+            years += 1;
+            y += 1;
+            earlier = next;
+        }
+        let mut months = 0;
+        while let Some((next_y, next_m)) = D::next_month(y, m) {
+            let Some(next) = policy.resolve::<D>(next_y, next_m, d) else { break };
+            if later < next {
+                break;
+            }
+            months += 1;
+            y = next_y;
+            m = next_m;
+            earlier = next;
+        }
+        let mut days = 0;
+        while later > earlier {
+            days += 1;
+            earlier = earlier.checked_succ().ok_or(CalendarDurationError::OutOfRange)?;
+        }
+        Ok(CalendarDuration { years, months, days })
+    }
+
+    /// A simple xorshift generator, so the tests using it are repeatable.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    /// Compare the algorithms for pseudo-random pairs of dates made from pseudo-random numbers
+    /// of days since 1970-01-01, some near each other and some far apart.
+    fn compare_with_loop<D: CalendarDurationExt + core::fmt::Debug>(
+        from_day: impl Fn(i64) -> Option<D>,
+        span: i64,
+        policies: &[OverflowPolicy],
+    ) {
+        let mut state = 0x2545_f491_4f6c_dd1d_u64;
+        let mut random = |n: i64| (xorshift(&mut state) % n as u64) as i64 - n / 2;
+        for i in 0 .. 2000 {
+            let a = random(span);
+            let b = if i % 2 == 0 { a + random(800) } else { random(span) };
+            let (Some(a), Some(b)) = (from_day(a), from_day(b)) else { continue };
+            for &policy in policies {
+                assert_eq!(loop_duration_from(a, b, policy), duration_from(a, b, policy),
+                    "{a:?} to {b:?} with {policy:?}");
+            }
+        }
+    }
+
+    /// Check that the whole years and months agree with the duration, for random pairs of dates.
+    fn compare_whole<D: CalendarDurationExt + core::fmt::Debug>(
+        from_day: impl Fn(i64) -> Option<D>,
+        span: i64,
+    ) {
+        let mut state = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut random = |n: i64| (xorshift(&mut state) % n as u64) as i64 - n / 2;
+        for i in 0 .. 2000 {
+            let a = random(span);
+            let b = if i % 2 == 0 { a + random(800) } else { random(span) };
+            let (Some(a), Some(b)) = (from_day(a), from_day(b)) else { continue };
+            let dur = duration_from(a, b, OverflowPolicy::NextValid).unwrap();
+            assert_eq!(dur.years, a.whole_years_since(b), "{a:?} to {b:?}");
+            assert_eq!(dur.years * 12 + dur.months, b.whole_months_since(a), "{a:?} to {b:?}");
+        }
+    }
+
+    #[test]
+    fn whole_years_and_months() {
+        compare_whole(crate::PlainDate::from_epoch_day, 400_000);
+        compare_whole(crate::HijriDate::from_epoch_day, 400_000);
+        compare_whole(|n| {
+            let n = n.rem_euclid(400 * 60);
+            Lunisolar::from_ymd((n / 400) as i32, (n % 400 / 30 + 1) as u8, (n % 30 + 1) as u8)
+        }, 400 * 60);
+        #[cfg(feature = "chrono")]
+        compare_whole(|n| chrono::NaiveDate::from_num_days_from_ce_opt(n as i32 + 719_163),
+            400_000);
+        #[cfg(feature = "time")]
+        compare_whole(|n| time::Date::from_julian_day(n as i32 + 2_440_588).ok(), 400_000);
+
+        // Every day of a few years either side of a February 29th birthday.
+        let born = crate::PlainDate::new(2000, 2, 29).unwrap();
+        for n in born.to_epoch_day() .. born.to_epoch_day() + 366 * 5 {
+            let day = crate::PlainDate::from_epoch_day(n).unwrap();
+            let dur = duration_from(day, born, OverflowPolicy::NextValid).unwrap();
+            assert_eq!((dur.years, dur.years * 12 + dur.months),
+                (day.whole_years_since(born), day.whole_months_since(born)), "{day}");
+        }
+    }
+
+    #[test]
+    fn same_as_loop() {
+        let both = [OverflowPolicy::NextValid, OverflowPolicy::ClampToEndOfMonth];
+        compare_with_loop(crate::PlainDate::from_epoch_day, 400_000, &both);
+        compare_with_loop(crate::HijriDate::from_epoch_day, 400_000, &both);
+        // Not a day number, but dates are still further apart the further apart the numbers are.
+        // Only moving a missing leap month forward is compared; see `leap_month_clamped`.
+        compare_with_loop(|n| {
+            let n = n.rem_euclid(400 * 60);
+            Lunisolar::from_ymd((n / 400) as i32, (n % 400 / 30 + 1) as u8, (n % 30 + 1) as u8)
+        }, 400 * 60, &[OverflowPolicy::NextValid]);
+        #[cfg(feature = "chrono")]
+        compare_with_loop(|n| chrono::NaiveDate::from_num_days_from_ce_opt(n as i32 + 719_163),
+            400_000, &both);
+        #[cfg(feature = "time")]
+        compare_with_loop(|n| time::Date::from_julian_day(n as i32 + 2_440_588).ok(), 400_000,
+            &both);
+    }
+}
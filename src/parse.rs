@@ -0,0 +1,464 @@
+use crate::iso8601::parse_calendar_iso8601;
+use crate::CalendarDuration;
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// Error returned when parsing a [`CalendarDuration`] from a string fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+
+    /// A number was expected, but this was found instead.
+    InvalidNumber(String),
+
+    /// A unit was expected, but this was found instead.
+    UnknownUnit(String),
+
+    /// A number was not followed by a unit.
+    MissingUnit,
+
+    /// The same unit was given more than once.
+    DuplicateUnit(String),
+
+    /// The number given for the unit is too large.
+    OutOfRange(String),
+
+    /// The duration was followed by something unexpected.
+    TrailingInput(String),
+
+    /// A relative duration didn't say whether it was in the past or the future.
+    MissingDirection,
+
+    /// The input contained a time of day, which a calendar duration can't represent.
+    TimeOfDay(String),
+
+    /// The input isn't an ISO 8601 duration: it doesn't start with `P`, has no components, or has
+    /// components out of order.
+    InvalidIso8601(String),
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => f.write_str("empty duration"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number {s:?}"),
+            ParseError::UnknownUnit(s) => write!(f, "unknown unit {s:?}"),
+            ParseError::MissingUnit => f.write_str("number without a unit"),
+            ParseError::DuplicateUnit(s) => write!(f, "unit {s:?} given more than once"),
+            ParseError::OutOfRange(s) => write!(f, "number of {s} out of range"),
+            ParseError::TrailingInput(s) => write!(f, "unexpected {s:?} after duration"),
+            ParseError::TimeOfDay(s) => {
+                write!(f, "time of day {s:?} not supported in a calendar duration")
+            }
+            ParseError::InvalidIso8601(s) => write!(f, "invalid ISO 8601 duration {s:?}"),
+            ParseError::MissingDirection => {
+                f.write_str("expected \"in ...\", \"... from now\", or \"... ago\"")
+            }
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+/// One of the components of a [`CalendarDuration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Unit {
+    Years,
+    Months,
+    Days,
+}
+
+/// The English names of the units, singular and plural.
+pub(crate) const UNIT_NAMES: [(Unit, &str, &str); 3] = [
+    (Unit::Years, "year", "years"),
+    (Unit::Months, "month", "months"),
+    (Unit::Days, "day", "days"),
+];
+
+impl Unit {
+    /// Look up a unit by its singular or plural English name, ignoring case.
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        UNIT_NAMES.iter()
+            .find(|(_, singular, plural)| {
+                name.eq_ignore_ascii_case(singular) || name.eq_ignore_ascii_case(plural)
+            })
+            .map(|(unit, _, _)| *unit)
+    }
+
+    pub(crate) fn plural(self) -> &'static str {
+        UNIT_NAMES[self as usize].2
+    }
+}
+
+/// Accumulates parsed components into a [`CalendarDuration`], rejecting duplicates and values
+/// which don't fit.
+#[derive(Default)]
+pub(crate) struct Builder {
+    years: Option<u64>,
+    months: Option<u64>,
+    weeks: Option<u64>,
+    days: Option<u64>,
+}
+
+impl Builder {
+    pub(crate) fn set(&mut self, unit: Unit, value: u64) -> Result<(), ParseError> {
+        let slot = match unit {
+            Unit::Years => &mut self.years,
+            Unit::Months => &mut self.months,
+            Unit::Days => &mut self.days,
+        };
+        if slot.replace(value).is_some() {
+            return Err(ParseError::DuplicateUnit(unit.plural().to_owned()));
+        }
+        Ok(())
+    }
+
+    /// Set a number of weeks, which are added to the days.
+    pub(crate) fn set_weeks(&mut self, value: u64) -> Result<(), ParseError> {
+        if self.weeks.replace(value).is_some() {
+            return Err(ParseError::DuplicateUnit("weeks".to_owned()));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.years.is_none() && self.months.is_none() && self.weeks.is_none()
+            && self.days.is_none()
+    }
+
+    pub(crate) fn build(self) -> Result<CalendarDuration, ParseError> {
+        let out_of_range = |unit: Unit| move |_| ParseError::OutOfRange(unit.plural().to_owned());
+        let days = self.weeks.unwrap_or(0)
+            .checked_mul(7)
+            .and_then(|d| d.checked_add(self.days.unwrap_or(0)))
+            .ok_or(ParseError::OutOfRange(Unit::Days.plural().to_owned()))?;
+        Ok(CalendarDuration {
+            years: self.years.unwrap_or(0).try_into().map_err(out_of_range(Unit::Years))?,
+            months: self.months.unwrap_or(0).try_into().map_err(out_of_range(Unit::Months))?,
+            days: days.try_into().map_err(out_of_range(Unit::Days))?,
+        })
+    }
+}
+
+/// Parse the shorthand form, like `1y 2m 3d` or `18m`, with each unit at most once. This is also
+/// the form the `rusqlite` feature stored durations in before ISO 8601.
+#[cfg(any(feature = "clap", feature = "rusqlite"))]
+pub(crate) fn parse_shorthand(s: &str) -> Result<CalendarDuration, ParseError> {
+    let mut builder = Builder::default();
+    for part in s.split_whitespace() {
+        let Some(designator) = part.chars().last().filter(char::is_ascii_alphabetic) else {
+            return Err(ParseError::MissingUnit);
+        };
+        let number = &part[.. part.len() - 1];
+        let value = number.parse().map_err(|_| ParseError::InvalidNumber(number.to_owned()))?;
+        match designator {
+            'y' => builder.set(Unit::Years, value)?,
+            'm' => builder.set(Unit::Months, value)?,
+            'd' => builder.set(Unit::Days, value)?,
+            _ => return Err(ParseError::UnknownUnit(designator.to_string())),
+        }
+    }
+    if builder.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    builder.build()
+}
+
+const SMALL_NUMBERS: [&str; 20] = [
+    "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    "eleven", "twelve", "thirteen", "fourteen", "fifteen", "sixteen", "seventeen", "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 8] = [
+    "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// Return the value of a single number word below one hundred, like "seven", "forty", or the
+/// hyphenated "forty-seven".
+fn number_word(word: &str) -> Option<u64> {
+    if let Some((tens, ones)) = word.split_once('-') {
+        let tens = number_word(tens).filter(|&n| n >= 20 && n % 10 == 0)?;
+        let ones = number_word(ones).filter(|&n| (1..10).contains(&n))?;
+        return Some(tens + ones);
+    }
+    if let Some(n) = SMALL_NUMBERS.iter().position(|&s| s == word) {
+        return Some(n as u64);
+    }
+    TENS.iter().position(|&s| s == word).map(|n| 20 + 10 * n as u64)
+}
+
+fn is_number_word(word: &str) -> bool {
+    number_word(word).is_some() || word.bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Parse a number written with digits, with English number words ("two hundred and
+/// twenty-one"), or as "a"/"an" meaning one, starting at `tokens[*pos]` and advancing `pos` past
+/// it.
+fn parse_number(tokens: &[&str], pos: &mut usize) -> Result<u64, ParseError> {
+    let first = *tokens.get(*pos).ok_or(ParseError::MissingUnit)?;
+    let invalid = || ParseError::InvalidNumber(first.to_owned());
+    *pos += 1;
+    if first.bytes().all(|b| b.is_ascii_digit()) {
+        return first.parse().map_err(|_| invalid());
+    }
+
+    let mut total = 0u64;
+    let mut current = match first {
+        "a" | "an" => 1,
+        _ => number_word(first).ok_or_else(invalid)?,
+    };
+    let mut after_multiplier = false;
+    while let Some(&word) = tokens.get(*pos) {
+        match word {
+            "hundred" => current = current.checked_mul(100).ok_or_else(invalid)?,
+            "thousand" | "million" => {
+                let scale = if word == "thousand" { 1_000 } else { 1_000_000 };
+                total = current.checked_mul(scale)
+                    .and_then(|n| n.checked_add(total))
+                    .ok_or_else(invalid)?;
+                current = 0;
+            }
+            // "one hundred and five": only part of the number if another number word follows.
+            "and" if after_multiplier => {
+                match tokens.get(*pos + 1) {
+                    Some(next) if number_word(next).is_some() => {}
+                    _ => break,
+                }
+            }
+            _ => match number_word(word) {
+                Some(n) if after_multiplier || current == 0 => current += n,
+                // "twenty one", without the hyphen.
+                Some(n) if n < 10 && current % 100 >= 20 && current % 10 == 0 => current += n,
+                _ => break,
+            },
+        }
+        after_multiplier = matches!(word, "hundred" | "thousand" | "million" | "and");
+        *pos += 1;
+    }
+    Ok(total + current)
+}
+
+impl CalendarDuration {
+    /// Parse a duration written in English words, like "three years and two months",
+    /// "twenty-one days", "a year and a day", or "3 years, two months".
+    ///
+    /// Numbers can be written with digits or as number words, including hyphenated forms and
+    /// multipliers ("two hundred and five"). "a" and "an" mean one. Weeks are accepted and counted
+    /// as seven days each. Components can be separated with commas and/or "and", and each unit may
+    /// only be given once. Parsing ignores case.
+    pub fn parse_english(s: &str) -> Result<Self, ParseError> {
+        let lower = s.to_lowercase();
+        let tokens = lower
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|t| !t.is_empty())
+            .collect::<Vec<_>>();
+
+        let mut builder = Builder::default();
+        let mut pos = 0;
+        while let Some(&token) = tokens.get(pos) {
+            if token == "and" && !builder.is_empty() {
+                pos += 1;
+                continue;
+            }
+            if !builder.is_empty() && !is_number_word(token) && token != "a" && token != "an" {
+                return Err(ParseError::TrailingInput(token.to_owned()));
+            }
+            let value = parse_number(&tokens, &mut pos)?;
+            let unit_word = *tokens.get(pos).ok_or(ParseError::MissingUnit)?;
+            pos += 1;
+            if unit_word == "week" || unit_word == "weeks" {
+                builder.set_weeks(value)?;
+                continue;
+            }
+            let unit = Unit::from_name(unit_word)
+                .ok_or_else(|| ParseError::UnknownUnit(unit_word.to_owned()))?;
+            builder.set(unit, value)?;
+        }
+
+        if builder.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        builder.build()
+    }
+}
+
+impl CalendarDuration {
+    /// Parse a duration written the way [`Display`] writes it, like "31 years, 9 months, 23 days",
+    /// "1 year", or "same day", or in ISO 8601 form, like `P31Y9M23D`.
+    ///
+    /// The English form is a comma-separated list of numbers written with digits, each followed
+    /// by "year(s)", "month(s)", or "day(s)", and ignores case. Other units, such as weeks or
+    /// hours, are errors; see [`parse_english`](Self::parse_english) for a more lenient parser.
+    /// The ISO 8601 form is parsed as with [`MixedDuration::parse_iso8601`], but can't have a
+    /// time section.
+    ///
+    /// Parsing the output of [`Display`] or [`to_iso8601`](Self::to_iso8601) gives back the same
+    /// duration.
+    ///
+    /// [`MixedDuration::parse_iso8601`]: crate::MixedDuration::parse_iso8601
+    pub fn parse(s: &str) -> Result<Self, ParseError> {
+        let s = s.trim();
+        if s.starts_with(['P', 'p']) {
+            return parse_calendar_iso8601(s);
+        }
+        if s.eq_ignore_ascii_case("same day") {
+            return Ok(CalendarDuration { years: 0, months: 0, days: 0 });
+        }
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut builder = Builder::default();
+        for part in s.split(',') {
+            let mut words = part.split_whitespace();
+            let number = words.next().ok_or(ParseError::Empty)?;
+            if !number.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::InvalidNumber(number.to_owned()));
+            }
+            let value = number.parse().map_err(|_| ParseError::InvalidNumber(number.to_owned()))?;
+            let unit_word = words.next().ok_or(ParseError::MissingUnit)?;
+            let unit = Unit::from_name(unit_word)
+                .ok_or_else(|| ParseError::UnknownUnit(unit_word.to_owned()))?;
+            builder.set(unit, value)?;
+            if let Some(rest) = words.next() {
+                return Err(ParseError::TrailingInput(rest.to_owned()));
+            }
+        }
+        builder.build()
+    }
+}
+
+impl FromStr for CalendarDuration {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::ToString};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn number_words() {
+        assert_eq!(Ok(dur(3, 2, 0)), CalendarDuration::parse_english("three years and two months"));
+        assert_eq!(Ok(dur(0, 0, 21)), CalendarDuration::parse_english("twenty-one days"));
+        assert_eq!(Ok(dur(0, 0, 21)), CalendarDuration::parse_english("twenty one days"));
+        assert_eq!(Ok(dur(0, 0, 13)), CalendarDuration::parse_english("Thirteen Days"));
+        assert_eq!(Ok(dur(0, 11, 0)), CalendarDuration::parse_english("eleven months"));
+        assert_eq!(Ok(dur(0, 0, 0)), CalendarDuration::parse_english("zero days"));
+    }
+
+    #[test]
+    fn hundreds() {
+        assert_eq!(Ok(dur(100, 0, 0)), CalendarDuration::parse_english("one hundred years"));
+        assert_eq!(Ok(dur(100, 0, 0)), CalendarDuration::parse_english("a hundred years"));
+        assert_eq!(Ok(dur(105, 0, 0)),
+            CalendarDuration::parse_english("one hundred and five years"));
+        assert_eq!(Ok(dur(999, 0, 0)),
+            CalendarDuration::parse_english("nine hundred ninety-nine years"));
+        assert_eq!(Ok(dur(2024, 0, 0)),
+            CalendarDuration::parse_english("two thousand and twenty-four years"));
+        assert_eq!(Ok(dur(101, 0, 1)),
+            CalendarDuration::parse_english("one hundred and one years and one day"));
+    }
+
+    #[test]
+    fn articles() {
+        assert_eq!(Ok(dur(1, 0, 1)), CalendarDuration::parse_english("a year and a day"));
+        assert_eq!(Ok(dur(0, 1, 0)), CalendarDuration::parse_english("a month"));
+    }
+
+    #[test]
+    fn weeks() {
+        assert_eq!(Ok(dur(0, 0, 14)), CalendarDuration::parse_english("two weeks"));
+        assert_eq!(Ok(dur(0, 0, 17)), CalendarDuration::parse_english("2 weeks and 3 days"));
+        assert_eq!(Err(ParseError::DuplicateUnit("weeks".to_owned())),
+            CalendarDuration::parse_english("a week and a week"));
+    }
+
+    #[test]
+    fn mixed_numerals() {
+        assert_eq!(Ok(dur(3, 2, 0)), CalendarDuration::parse_english("3 years and two months"));
+        assert_eq!(Ok(dur(31, 9, 23)),
+            CalendarDuration::parse_english("31 years, 9 months, 23 days"));
+        assert_eq!(Ok(dur(1, 6, 5)),
+            CalendarDuration::parse_english("1 year, six months, and 5 days"));
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(ParseError::Empty), CalendarDuration::parse_english(""));
+        assert_eq!(Err(ParseError::InvalidNumber("blorp".to_owned())),
+            CalendarDuration::parse_english("blorp days"));
+        assert_eq!(Err(ParseError::UnknownUnit("fortnights".to_owned())),
+            CalendarDuration::parse_english("two fortnights"));
+        assert_eq!(Err(ParseError::MissingUnit),
+            CalendarDuration::parse_english("two years and six"));
+        assert_eq!(Err(ParseError::DuplicateUnit("years".to_owned())),
+            CalendarDuration::parse_english("a year and two years"));
+        assert_eq!(Err(ParseError::OutOfRange("days".to_owned())),
+            CalendarDuration::parse_english("5000000000 days"));
+        assert_eq!(Err(ParseError::OutOfRange("days".to_owned())),
+            CalendarDuration::parse_english("700000000 weeks"));
+        assert_eq!(Err(ParseError::TrailingInput("ago".to_owned())),
+            CalendarDuration::parse_english("two years ago"));
+        assert_eq!(Err(ParseError::InvalidNumber("twenty-twenty".to_owned())),
+            CalendarDuration::parse_english("twenty-twenty days"));
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for years in [0, 1, 31] {
+            for months in [0, 1, 9] {
+                for days in [0, 1, 23] {
+                    let d = dur(years, months, days);
+                    assert_eq!(Ok(d.clone()), d.to_string().parse(), "{d}");
+                    assert_eq!(Ok(d.clone()), format!("{d:#}").parse(), "{d:#}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_forms() {
+        assert_eq!(Ok(dur(31, 9, 23)), CalendarDuration::parse("31 Years, 9 MONTHS, 23 days"));
+        assert_eq!(Ok(dur(1, 0, 0)), CalendarDuration::parse(" 1 year "));
+        assert_eq!(Ok(dur(0, 0, 0)), CalendarDuration::parse("Same Day"));
+        assert_eq!(Ok(dur(0, 2, 3)), CalendarDuration::parse("3 days,2 months"));
+        assert_eq!(Ok(dur(31, 9, 23)), CalendarDuration::parse("P31Y9M23D"));
+        assert_eq!(Ok(dur(0, 0, 0)), CalendarDuration::parse("P0D"));
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(Err(ParseError::Empty), CalendarDuration::parse(""));
+        assert_eq!(Err(ParseError::UnknownUnit("weeks".to_owned())),
+            CalendarDuration::parse("2 weeks"));
+        assert_eq!(Err(ParseError::UnknownUnit("hours".to_owned())),
+            CalendarDuration::parse("1 day, 3 hours"));
+        assert_eq!(Err(ParseError::InvalidNumber("two".to_owned())),
+            CalendarDuration::parse("two years"));
+        assert_eq!(Err(ParseError::InvalidNumber("-1".to_owned())),
+            CalendarDuration::parse("-1 years"));
+        assert_eq!(Err(ParseError::DuplicateUnit("days".to_owned())),
+            CalendarDuration::parse("1 day, 2 days"));
+        assert_eq!(Err(ParseError::TrailingInput("ago".to_owned())),
+            CalendarDuration::parse("2 years ago"));
+        assert_eq!(Err(ParseError::MissingUnit), CalendarDuration::parse("1 year, 2"));
+        assert_eq!(Err(ParseError::Empty), CalendarDuration::parse("1 year,"));
+        assert_eq!(Err(ParseError::TimeOfDay("P1DT2H".to_owned())),
+            CalendarDuration::parse("P1DT2H"));
+        assert_eq!(Err("number of years out of range".to_owned()),
+            "5000000000 years".parse::<CalendarDuration>().map_err(|e| e.to_string()));
+    }
+}
@@ -0,0 +1,157 @@
+use crate::{AddOptions, CalendarDuration, CalendarDurationExt, OverflowPolicy};
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+/// How the end of a [`CalendarPeriod`] is given.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum EndConvention {
+    /// The end is the first date after the period, like the end of a [`DateRange`]: a year
+    /// starting July 1st ends the next July 1st.
+    ///
+    /// [`DateRange`]: crate::DateRange
+    #[default]
+    Exclusive,
+
+    /// The end is the last date in the period, the day before the exclusive end, as is usual for
+    /// tenancies and insurance policies: a year starting July 1st ends the next June 30th.
+    InclusiveMinusOne,
+}
+
+/// A period of time starting on a given date and lasting for a given calendar duration, such as a
+/// lease or an insurance policy.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CalendarPeriod<D> {
+    /// The first date in the period.
+    pub start: D,
+
+    /// The length of the period.
+    pub length: CalendarDuration,
+
+    /// How a day of the month which doesn't exist at the end of the period is resolved.
+    pub policy: OverflowPolicy,
+}
+
+impl<D: CalendarDurationExt> CalendarPeriod<D> {
+    /// Make a period of the given length starting on the given date, which moves a day that
+    /// doesn't exist at the end of the period forward to the next valid date.
+    pub fn new(start: D, length: CalendarDuration) -> Self {
+        Self { start, length, policy: OverflowPolicy::NextValid }
+    }
+
+    /// Use the given policy for a day of the month which doesn't exist at the end of the period.
+    pub fn with_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Return the end of the period, according to the given convention, or `None` if it can't be
+    /// represented by the date type.
+    pub fn end(&self, convention: EndConvention) -> Option<D> {
+        self.end_of_nth(0, convention)
+    }
+
+    /// Return the start and end of each of `count` consecutive periods of this length, the first
+    /// one being this period. The end is given according to the convention.
+    ///
+    /// Every period is measured from the start of the first one, so a day of the month which
+    /// doesn't exist in a short month doesn't shift the periods after it: monthly periods starting
+    /// January 31st start again on March 31st.
+    ///
+    /// Returns `None` if any of the dates can't be represented by the date type.
+    #[cfg(feature = "alloc")]
+    pub fn schedule(&self, count: u32, convention: EndConvention) -> Option<Vec<(D, D)>> {
+        (0 .. count)
+            .map(|i| Some((self.start_of_nth(i)?, self.end_of_nth(i, convention)?)))
+            .collect()
+    }
+
+    fn start_of_nth(&self, n: u32) -> Option<D> {
+        if n == 0 {
+            return Some(self.start);
+        }
+        self.start.add_calendar_duration_with(
+            &scale(&self.length, n)?,
+            &AddOptions::new().overflow_policy(self.policy))
+    }
+
+    fn end_of_nth(&self, n: u32, convention: EndConvention) -> Option<D> {
+        let end = self.start_of_nth(n.checked_add(1)?)?;
+        match convention {
+            EndConvention::Exclusive => Some(end),
+            EndConvention::InclusiveMinusOne => D::from_day_number(end.to_day_number() - 1),
+        }
+    }
+}
+
+/// Multiply each component of the duration by `n`.
+pub(crate) fn scale(dur: &CalendarDuration, n: u32) -> Option<CalendarDuration> {
+    Some(CalendarDuration {
+        years: dur.years.checked_mul(n)?,
+        months: dur.months.checked_mul(n)?,
+        days: dur.days.checked_mul(n)?,
+    })
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    #[cfg(feature = "alloc")]
+    use alloc::vec;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    const ONE_MONTH: CalendarDuration = CalendarDuration { years: 0, months: 1, days: 0 };
+    const ONE_YEAR: CalendarDuration = CalendarDuration { years: 1, months: 0, days: 0 };
+
+    #[test]
+    fn lease() {
+        let lease = CalendarPeriod::new(date(2024, 7, 1), ONE_YEAR);
+        assert_eq!(Some(date(2025, 7, 1)), lease.end(EndConvention::Exclusive));
+        assert_eq!(Some(date(2025, 6, 30)), lease.end(EndConvention::InclusiveMinusOne));
+    }
+
+    #[test]
+    fn month_end() {
+        let period = CalendarPeriod::new(date(2023, 1, 31), ONE_MONTH);
+        assert_eq!(Some(date(2023, 3, 1)), period.end(EndConvention::Exclusive));
+        assert_eq!(Some(date(2023, 2, 28)), period.end(EndConvention::InclusiveMinusOne));
+
+        let period = period.with_policy(OverflowPolicy::ClampToEndOfMonth);
+        assert_eq!(Some(date(2023, 2, 28)), period.end(EndConvention::Exclusive));
+        assert_eq!(Some(date(2023, 2, 27)), period.end(EndConvention::InclusiveMinusOne));
+
+        // The year crosses into the next.
+        let period = CalendarPeriod::new(date(2023, 12, 31), ONE_MONTH);
+        assert_eq!(Some(date(2024, 1, 30)), period.end(EndConvention::InclusiveMinusOne));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn schedule() {
+        let period = CalendarPeriod::new(date(2023, 1, 31), ONE_MONTH)
+            .with_policy(OverflowPolicy::ClampToEndOfMonth);
+        assert_eq!(Some(vec![
+            (date(2023, 1, 31), date(2023, 2, 27)),
+            (date(2023, 2, 28), date(2023, 3, 30)),
+            (date(2023, 3, 31), date(2023, 4, 29)),
+        ]), period.schedule(3, EndConvention::InclusiveMinusOne));
+
+        let period = CalendarPeriod::new(date(2023, 1, 31), ONE_MONTH);
+        assert_eq!(Some(vec![
+            (date(2023, 1, 31), date(2023, 3, 1)),
+            (date(2023, 3, 1), date(2023, 3, 31)),
+        ]), period.schedule(2, EndConvention::Exclusive));
+        assert_eq!(Some(vec![]), period.schedule(0, EndConvention::Exclusive));
+    }
+
+    #[test]
+    fn out_of_range() {
+        let period = CalendarPeriod::new(NaiveDate::MAX, ONE_MONTH);
+        assert_eq!(None, period.end(EndConvention::Exclusive));
+        #[cfg(feature = "alloc")]
+        assert_eq!(None, period.schedule(1, EndConvention::Exclusive));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::parse::{Builder, Unit};
+use crate::{CalendarDuration, ParseError};
+use alloc::{borrow::ToOwned, format, string::String, vec};
+
+impl CalendarDuration {
+    /// Parse a PostgreSQL `interval` value in its textual form, as output by PostgreSQL with the
+    /// default `IntervalStyle`, like `1 year 2 mons 3 days`.
+    ///
+    /// Each component is optional and may be singular or plural; `month` and `months` are accepted
+    /// as well as PostgreSQL's `mon` and `mons`, and a leading `@` (from the `postgres_verbose`
+    /// style) is ignored. A time-of-day part like `04:05:06` is rejected with
+    /// [`ParseError::TimeOfDay`], except when it is all zeros, which is how PostgreSQL writes the
+    /// zero interval (`00:00:00`). Negative components are rejected.
+    pub fn parse_pg_interval(s: &str) -> Result<Self, ParseError> {
+        let mut tokens = s.split_whitespace().peekable();
+        if tokens.peek() == Some(&"@") {
+            tokens.next();
+        }
+
+        let mut builder = Builder::default();
+        let mut any = false;
+        while let Some(token) = tokens.next() {
+            any = true;
+            if token.contains(':') {
+                if token.bytes().all(|b| matches!(b, b'0' | b':' | b'.')) {
+                    continue;
+                }
+                return Err(ParseError::TimeOfDay(token.to_owned()));
+            }
+            let value = token.parse::<u64>()
+                .map_err(|_| ParseError::InvalidNumber(token.to_owned()))?;
+            let unit_word = tokens.next().ok_or(ParseError::MissingUnit)?;
+            let unit = if unit_word.eq_ignore_ascii_case("mon")
+                || unit_word.eq_ignore_ascii_case("mons")
+            {
+                Unit::Months
+            } else {
+                Unit::from_name(unit_word)
+                    .ok_or_else(|| ParseError::UnknownUnit(unit_word.to_owned()))?
+            };
+            builder.set(unit, value)?;
+        }
+
+        if !any {
+            return Err(ParseError::Empty);
+        }
+        builder.build()
+    }
+
+    /// Format the duration as a PostgreSQL `interval` value, in the same form PostgreSQL outputs
+    /// with the default `IntervalStyle`, like `1 year 2 mons 3 days`. Zero components are
+    /// omitted, and the zero duration is `00:00:00`.
+    pub fn to_pg_interval_string(&self) -> String {
+        let mut parts = vec![];
+        for (n, singular, plural) in [
+            (u64::from(self.years), "year", "years"),
+            (u64::from(self.months), "mon", "mons"),
+            (u64::from(self.days), "day", "days"),
+        ] {
+            if n != 0 {
+                parts.push(format!("{n} {}", if n == 1 { singular } else { plural }));
+            }
+        }
+        if parts.is_empty() {
+            return "00:00:00".to_owned();
+        }
+        parts.join(" ")
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn postgres_output() {
+        // Strings output by PostgreSQL 16 for `SELECT interval '...'`.
+        for (s, d) in [
+            ("1 year 2 mons 3 days", dur(1, 2, 3)),
+            ("1 year 2 mons", dur(1, 2, 0)),
+            ("1 mon", dur(0, 1, 0)),
+            ("2 years", dur(2, 0, 0)),
+            ("3 days", dur(0, 0, 3)),
+            ("45 days", dur(0, 0, 45)),
+            ("31 years 9 mons 23 days", dur(31, 9, 23)),
+            ("00:00:00", dur(0, 0, 0)),
+        ] {
+            assert_eq!(Ok(d.clone()), CalendarDuration::parse_pg_interval(s), "{s}");
+            assert_eq!(s, d.to_pg_interval_string());
+        }
+    }
+
+    #[test]
+    fn other_forms() {
+        assert_eq!(Ok(dur(1, 2, 3)), CalendarDuration::parse_pg_interval("@ 1 year 2 mons 3 days"));
+        assert_eq!(Ok(dur(0, 6, 1)), CalendarDuration::parse_pg_interval("6 months 1 day"));
+        assert_eq!(Ok(dur(1, 0, 0)), CalendarDuration::parse_pg_interval("1 YEAR"));
+        assert_eq!(Ok(dur(0, 0, 2)), CalendarDuration::parse_pg_interval("2 days 00:00:00"));
+    }
+
+    #[test]
+    fn round_trip() {
+        for d in [dur(0, 0, 1), dur(1, 1, 1), dur(100, 11, 30), dur(0, 11, 0)] {
+            let s = d.to_pg_interval_string();
+            assert_eq!(Ok(d), CalendarDuration::parse_pg_interval(&s));
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(ParseError::TimeOfDay("04:05:06".to_owned())),
+            CalendarDuration::parse_pg_interval("1 day 04:05:06"));
+        assert_eq!(Err(ParseError::TimeOfDay("-00:00:01".to_owned())),
+            CalendarDuration::parse_pg_interval("-00:00:01"));
+        assert_eq!(Err(ParseError::InvalidNumber("-1".to_owned())),
+            CalendarDuration::parse_pg_interval("-1 days"));
+        assert_eq!(Err(ParseError::UnknownUnit("hours".to_owned())),
+            CalendarDuration::parse_pg_interval("3 hours"));
+        assert_eq!(Err(ParseError::DuplicateUnit("months".to_owned())),
+            CalendarDuration::parse_pg_interval("1 mon 2 months"));
+        assert_eq!(Err(ParseError::MissingUnit), CalendarDuration::parse_pg_interval("1 year 2"));
+        assert_eq!(Err(ParseError::Empty), CalendarDuration::parse_pg_interval(""));
+    }
+}
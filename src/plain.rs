@@ -0,0 +1,466 @@
+use crate::gregorian::{
+    civil_from_days, days_from_civil, is_leap_year, iso_week_from_days,
+    iso_week_start, iso_weeks_in_year, month_length, weekday_from_days,
+};
+use crate::CalendarDurationExt;
+#[cfg(any(feature = "chrono", feature = "time"))]
+use crate::CalendarDurationError;
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+/// A date in the proleptic Gregorian calendar, for computing durations without any date library.
+///
+/// Any `i32` year can be represented.
+///
+/// It can be written and parsed in the ISO 8601 calendar date (`2024-03-05`), ordinal date
+/// (`2024-065`), and week date (`2024-W10-2`) forms. The [`Display`] implementation uses the
+/// calendar date form.
+///
+/// With the `chrono` or `time` feature, a `NaiveDate` or `time::Date` converts into a `PlainDate`
+/// with [`From`], since every date they hold fits. Going the other way is [`TryFrom`], because a
+/// `PlainDate` can hold years far beyond either library's range, and those dates fail with
+/// [`crate::CalendarDurationError::OutOfRange`] rather than being clamped or wrapped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PlainDate {
+    year: i32,
+    month: u8,
+    day: u8,
+}
+
+/// Error returned when parsing a [`PlainDate`] from a string fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DateParseError {
+    /// The input isn't in the shape of any of the accepted forms.
+    Malformed,
+
+    /// The year is out of range.
+    InvalidYear,
+
+    /// The month isn't between 1 and 12.
+    InvalidMonth,
+
+    /// The day doesn't exist in the month.
+    InvalidDay,
+
+    /// The day doesn't exist in the year, in an ordinal date.
+    InvalidDayOfYear,
+
+    /// The week doesn't exist in the week-numbering year, in a week date.
+    InvalidWeek,
+
+    /// The day of the week isn't between 1 (Monday) and 7 (Sunday), in a week date.
+    InvalidWeekday,
+}
+
+impl Display for DateParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DateParseError::Malformed => "not an ISO 8601 date",
+            DateParseError::InvalidYear => "year out of range",
+            DateParseError::InvalidMonth => "invalid month",
+            DateParseError::InvalidDay => "invalid day of the month",
+            DateParseError::InvalidDayOfYear => "invalid day of the year",
+            DateParseError::InvalidWeek => "invalid week",
+            DateParseError::InvalidWeekday => "invalid day of the week",
+        })
+    }
+}
+
+impl core::error::Error for DateParseError {}
+
+/// Split a year off the start of the input: four digits, or a sign and at least four digits.
+fn parse_year(s: &str) -> Result<(i32, &str), DateParseError> {
+    let signed = s.starts_with(['+', '-']);
+    let digits = s[usize::from(signed) ..].bytes().take_while(u8::is_ascii_digit).count();
+    let end = usize::from(signed) + digits;
+    if digits < 4 || (!signed && digits > 4) {
+        return Err(DateParseError::Malformed);
+    }
+    let year = s[.. end].parse().map_err(|_| DateParseError::InvalidYear)?;
+    Ok((year, &s[end ..]))
+}
+
+/// Parse exactly the given number of digits, which must be the whole input.
+fn parse_digits(s: &str, len: usize) -> Result<u16, DateParseError> {
+    if s.len() != len || !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(DateParseError::Malformed);
+    }
+    s.parse().map_err(|_| DateParseError::Malformed)
+}
+
+fn write_year(f: &mut Formatter<'_>, year: i32) -> fmt::Result {
+    if (0 ..= 9999).contains(&year) {
+        write!(f, "{year:04}")
+    } else {
+        // Years outside four digits need a sign in ISO 8601.
+        write!(f, "{year:+05}")
+    }
+}
+
+impl PlainDate {
+    /// Make a date from the year, month, and day, if it is valid.
+    pub const fn new(year: i32, month: u8, day: u8) -> Option<Self> {
+        if month < 1 || month > 12 || day < 1 || day > month_length(year, month) {
+            return None;
+        }
+        Some(Self { year, month, day })
+    }
+
+    /// The year. Year 0 is 1 BC, and so on.
+    pub const fn year(self) -> i32 {
+        self.year
+    }
+
+    /// The month, from 1 to 12.
+    pub const fn month(self) -> u8 {
+        self.month
+    }
+
+    /// The day of the month.
+    pub const fn day(self) -> u8 {
+        self.day
+    }
+
+    /// Return the number of days from 1970-01-01 to the date.
+    pub fn to_epoch_day(self) -> i64 {
+        days_from_civil(self.year, self.month, self.day)
+    }
+
+    /// Return the date the given number of days after 1970-01-01, or `None` if its year can't be
+    /// represented.
+    pub fn from_epoch_day(days: i64) -> Option<Self> {
+        let (y, m, d) = civil_from_days(days);
+        // Check for a year out of range, which the conversion would have truncated.
+        (days_from_civil(y, m, d) == days).then_some(Self { year: y, month: m, day: d })
+    }
+
+    /// Parse a date in any of the accepted forms, telling them apart by their shape. This is the
+    /// same as [`str::parse`].
+    pub fn parse(s: &str) -> Result<Self, DateParseError> {
+        let (_, rest) = parse_year(s)?;
+        if rest.starts_with("-W") {
+            Self::parse_week_date(s)
+        } else if rest.len() == 4 {
+            Self::parse_ordinal(s)
+        } else {
+            Self::parse_iso(s)
+        }
+    }
+
+    /// Parse an ISO 8601 calendar date, like `2024-03-05`.
+    ///
+    /// The year has four digits, or a sign and at least four digits, like `-0044` or `+10000`.
+    pub fn parse_iso(s: &str) -> Result<Self, DateParseError> {
+        let (year, rest) = parse_year(s)?;
+        let rest = rest.strip_prefix('-').ok_or(DateParseError::Malformed)?;
+        let (month, day) = rest.split_once('-').ok_or(DateParseError::Malformed)?;
+        let (month, day) = (parse_digits(month, 2)?, parse_digits(day, 2)?);
+        if !(1 ..= 12).contains(&month) {
+            return Err(DateParseError::InvalidMonth);
+        }
+        Self::new(year, month as u8, day as u8).ok_or(DateParseError::InvalidDay)
+    }
+
+    /// Parse an ISO 8601 ordinal date, like `2024-065` for the 65th day of 2024.
+    pub fn parse_ordinal(s: &str) -> Result<Self, DateParseError> {
+        let (year, rest) = parse_year(s)?;
+        let rest = rest.strip_prefix('-').ok_or(DateParseError::Malformed)?;
+        let ordinal = parse_digits(rest, 3)?;
+        let days_in_year = if is_leap_year(year) { 366 } else { 365 };
+        if !(1 ..= days_in_year).contains(&ordinal) {
+            return Err(DateParseError::InvalidDayOfYear);
+        }
+        Self::from_epoch_day(days_from_civil(year, 1, 1) + i64::from(ordinal) - 1)
+            .ok_or(DateParseError::InvalidYear)
+    }
+
+    /// Parse an ISO 8601 week date, like `2024-W10-2` for the Tuesday of week 10 of 2024.
+    ///
+    /// The year is the ISO week-numbering year, which can differ from the calendar year of the
+    /// date near the start and end of the year.
+    pub fn parse_week_date(s: &str) -> Result<Self, DateParseError> {
+        let (year, rest) = parse_year(s)?;
+        let rest = rest.strip_prefix("-W").ok_or(DateParseError::Malformed)?;
+        let (week, weekday) = rest.split_once('-').ok_or(DateParseError::Malformed)?;
+        let (week, weekday) = (parse_digits(week, 2)?, parse_digits(weekday, 1)?);
+        if week < 1 || week > u16::from(iso_weeks_in_year(year)) {
+            return Err(DateParseError::InvalidWeek);
+        }
+        if !(1 ..= 7).contains(&weekday) {
+            return Err(DateParseError::InvalidWeekday);
+        }
+        Self::from_epoch_day(iso_week_start(year, week as u8) + i64::from(weekday) - 1)
+            .ok_or(DateParseError::InvalidYear)
+    }
+
+    /// Return a value which displays the date in ISO 8601 ordinal form, like `2024-065`.
+    pub fn ordinal(self) -> impl Display {
+        struct Ordinal(PlainDate);
+        impl Display for Ordinal {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let date = self.0;
+                write_year(f, date.year)?;
+                let ordinal = date.to_epoch_day() - days_from_civil(date.year, 1, 1) + 1;
+                write!(f, "-{ordinal:03}")
+            }
+        }
+        Ordinal(self)
+    }
+
+    /// Return a value which displays the date in ISO 8601 week date form, like `2024-W10-2`.
+    pub fn week_date(self) -> impl Display {
+        struct WeekDate(PlainDate);
+        impl Display for WeekDate {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                let days = self.0.to_epoch_day();
+                let (year, week) = iso_week_from_days(days);
+                write_year(f, year)?;
+                write!(f, "-W{week:02}-{}", weekday_from_days(days) + 1)
+            }
+        }
+        WeekDate(self)
+    }
+}
+
+impl Display for PlainDate {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write_year(f, self.year)?;
+        write!(f, "-{:02}-{:02}", self.month, self.day)
+    }
+}
+
+impl FromStr for PlainDate {
+    type Err = DateParseError;
+
+    fn from_str(s: &str) -> Result<Self, DateParseError> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for PlainDate {
+    fn from(date: chrono::NaiveDate) -> Self {
+        let (year, month, day) = date.ymd();
+        Self { year, month, day }
+    }
+}
+
+/// Fails if the date is outside the range of `NaiveDate`, which is years -262143 through 262142.
+#[cfg(feature = "chrono")]
+impl TryFrom<PlainDate> for chrono::NaiveDate {
+    type Error = CalendarDurationError;
+
+    fn try_from(date: PlainDate) -> Result<Self, CalendarDurationError> {
+        <Self as CalendarDurationExt>::from_ymd(date.year, date.month, date.day)
+            .ok_or(CalendarDurationError::OutOfRange)
+    }
+}
+
+#[cfg(feature = "time")]
+impl From<time::Date> for PlainDate {
+    fn from(date: time::Date) -> Self {
+        let (year, month, day) = date.ymd();
+        Self { year, month, day }
+    }
+}
+
+/// Fails if the date is outside the range of `time::Date`, which is years -9999 through 9999,
+/// unless time's `large-dates` feature is enabled.
+#[cfg(feature = "time")]
+impl TryFrom<PlainDate> for time::Date {
+    type Error = CalendarDurationError;
+
+    fn try_from(date: PlainDate) -> Result<Self, CalendarDurationError> {
+        <Self as CalendarDurationExt>::from_ymd(date.year, date.month, date.day)
+            .ok_or(CalendarDurationError::OutOfRange)
+    }
+}
+
+impl CalendarDurationExt for PlainDate {
+    fn ymd(self) -> (i32, u8, u8) {
+        (self.year, self.month, self.day)
+    }
+
+    fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+        Self::new(y, m, d)
+    }
+
+    #[cfg(not(feature = "strict"))]
+    fn succ(self) -> Self {
+        self.checked_succ().expect("date out of range")
+    }
+
+    fn checked_succ(self) -> Option<Self> {
+        Self::from_epoch_day(self.to_epoch_day() + 1)
+    }
+
+    fn min_value() -> Self {
+        Self { year: i32::MIN, month: 1, day: 1 }
+    }
+
+    fn max_value() -> Self {
+        Self { year: i32::MAX, month: 12, day: 31 }
+    }
+
+    fn to_day_number(self) -> i64 {
+        self.to_epoch_day()
+    }
+
+    fn from_day_number(days: i64) -> Option<Self> {
+        Self::from_epoch_day(days)
+    }
+
+    fn days_since(self, other: Self) -> i64 {
+        self.to_epoch_day() - other.to_epoch_day()
+    }
+
+    fn weekday_from_monday(self) -> u8 {
+        weekday_from_days(self.to_epoch_day())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::{CalendarDuration, CalendarDurationError};
+
+    fn date(y: i32, m: u8, d: u8) -> PlainDate {
+        PlainDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn calendar_dates() {
+        for (s, d) in [
+            ("2024-03-05", date(2024, 3, 5)),
+            ("0000-01-01", date(0, 1, 1)),
+            ("-0044-03-15", date(-44, 3, 15)),
+            ("+10000-12-31", date(10000, 12, 31)),
+        ] {
+            assert_eq!(Ok(d), s.parse(), "{s}");
+            assert_eq!(Ok(d), PlainDate::parse_iso(s), "{s}");
+            assert_eq!(s, d.to_string());
+        }
+        assert_eq!(Ok(date(2024, 3, 5)), PlainDate::parse("+2024-03-05"));
+    }
+
+    #[test]
+    fn ordinal_dates() {
+        for (s, d) in [
+            ("2024-065", date(2024, 3, 5)),
+            ("2023-365", date(2023, 12, 31)),
+            ("2024-366", date(2024, 12, 31)),
+            ("-0001-001", date(-1, 1, 1)),
+        ] {
+            assert_eq!(Ok(d), s.parse(), "{s}");
+            assert_eq!(Ok(d), PlainDate::parse_ordinal(s), "{s}");
+            assert_eq!(s, d.ordinal().to_string());
+        }
+    }
+
+    #[test]
+    fn week_dates() {
+        for (s, d) in [
+            ("2024-W10-2", date(2024, 3, 5)),
+            ("2020-W53-5", date(2021, 1, 1)),
+            ("2026-W53-7", date(2027, 1, 3)),
+            ("2025-W01-1", date(2024, 12, 30)),
+            ("-0002-W53-7", date(-1, 1, 3)),
+        ] {
+            assert_eq!(Ok(d), s.parse(), "{s}");
+            assert_eq!(Ok(d), PlainDate::parse_week_date(s), "{s}");
+            assert_eq!(s, d.week_date().to_string());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        use DateParseError::*;
+        let parse = PlainDate::parse;
+        assert_eq!(Err(Malformed), parse(""));
+        assert_eq!(Err(Malformed), parse("24-03-05"));
+        assert_eq!(Err(Malformed), parse("2024-3-5"));
+        assert_eq!(Err(Malformed), parse("20240305"));
+        assert_eq!(Err(Malformed), parse("2024-03-05T00:00"));
+        assert_eq!(Err(InvalidYear), parse("+99999999999-01-01"));
+        assert_eq!(Err(InvalidMonth), parse("2024-13-01"));
+        assert_eq!(Err(InvalidDay), parse("2023-02-29"));
+        assert_eq!(Err(InvalidDay), parse("2024-04-31"));
+        assert_eq!(Err(InvalidDayOfYear), parse("2023-366"));
+        assert_eq!(Err(InvalidDayOfYear), parse("2023-000"));
+        assert_eq!(Err(InvalidWeek), parse("2024-W53-1"));
+        assert_eq!(Err(InvalidWeek), parse("2024-W00-1"));
+        assert_eq!(Err(InvalidWeekday), parse("2024-W10-8"));
+
+        // The explicit parsers only accept their own form.
+        assert_eq!(Err(Malformed), PlainDate::parse_iso("2024-065"));
+        assert_eq!(Err(Malformed), PlainDate::parse_ordinal("2024-W10-2"));
+        assert_eq!(Err(Malformed), PlainDate::parse_week_date("2024-03-05"));
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(PlainDate::new(2024, 2, 29).is_some());
+        assert!(PlainDate::new(2023, 2, 29).is_none());
+        // Centuries are leap years only if divisible by 400.
+        assert!(PlainDate::new(1900, 2, 29).is_none());
+        assert!(PlainDate::new(2100, 2, 29).is_none());
+        assert!(PlainDate::new(2000, 2, 29).is_some());
+        assert!(PlainDate::new(1600, 2, 29).is_some());
+        assert!(PlainDate::new(0, 2, 29).is_some());
+        assert!(PlainDate::new(-100, 2, 29).is_none());
+        assert!(PlainDate::new(2024, 4, 31).is_none());
+        assert!(PlainDate::new(2024, 13, 1).is_none());
+        assert!(PlainDate::new(2024, 0, 1).is_none());
+        assert!(PlainDate::new(2024, 1, 0).is_none());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_conversions() {
+        use chrono::NaiveDate;
+        for (y, m, d) in [(2024, 2, 29), (1970, 1, 1), (-262143, 1, 1), (262142, 12, 31)] {
+            let chrono = NaiveDate::from_ymd_opt(y, u32::from(m), u32::from(d)).unwrap();
+            assert_eq!(date(y, m, d), PlainDate::from(chrono));
+            assert_eq!(Ok(chrono), NaiveDate::try_from(date(y, m, d)));
+        }
+        assert_eq!(Err(CalendarDurationError::OutOfRange),
+            NaiveDate::try_from(date(262143, 1, 1)));
+        // Durations are the same either way.
+        let (a, b) = (NaiveDate::MIN, NaiveDate::from_ymd_opt(2020, 4, 8).unwrap());
+        assert_eq!(a.try_calendar_duration_from(b),
+            PlainDate::from(a).try_calendar_duration_from(PlainDate::from(b)));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_conversions() {
+        for (y, m, d) in [(2024, 2, 29), (1970, 1, 1), (-9999, 1, 1), (9999, 12, 31)] {
+            let time = time::Date::from_calendar_date(y, m.try_into().unwrap(), d).unwrap();
+            assert_eq!(date(y, m, d), PlainDate::from(time));
+            assert_eq!(Ok(time), time::Date::try_from(date(y, m, d)));
+        }
+        assert_eq!(Err(CalendarDurationError::OutOfRange),
+            time::Date::try_from(date(10000, 1, 1)));
+    }
+
+    #[test]
+    fn epoch_days() {
+        assert_eq!(0, date(1970, 1, 1).to_epoch_day());
+        assert_eq!(Some(date(2000, 3, 1)), PlainDate::from_epoch_day(11017));
+        assert_eq!(None, PlainDate::from_epoch_day(i64::MAX / 2));
+        assert_eq!(None, date(i32::MAX, 12, 31).checked_succ());
+        assert_eq!(date(i32::MIN, 1, 1), PlainDate::min_value());
+        assert_eq!(date(i32::MAX, 12, 31), PlainDate::max_value());
+        assert_eq!(Ok(CalendarDuration { years: 0, months: 11, days: 30 }),
+            PlainDate::max_value().try_calendar_duration_from(date(i32::MAX, 1, 1)));
+        assert_eq!(Some(date(2024, 3, 1)), date(2024, 2, 29).checked_succ());
+        assert_eq!(Some(date(2025, 1, 1)), date(2024, 12, 31).checked_succ());
+        assert_eq!(Some(date(2023, 3, 1)), date(2023, 2, 28).checked_succ());
+        // There's no day after the last one to count up to.
+        assert_eq!(Err(CalendarDurationError::OutOfRange),
+            date(i32::MAX, 1, 1).try_calendar_duration_from_inclusive(PlainDate::max_value()));
+        assert_eq!(Ok(CalendarDuration { years: 0, months: 0, days: 30 }),
+            date(i32::MAX, 12, 1).try_calendar_duration_from_inclusive(date(i32::MAX, 12, 30)));
+    }
+}
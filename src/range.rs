@@ -0,0 +1,492 @@
+use crate::gregorian::{iso_week_from_days, iso_week_start, weekday_from_days};
+use crate::{CalendarDuration, CalendarDurationExt, Days, Recurrence};
+#[cfg(feature = "alloc")]
+use crate::duration_between;
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+use core::iter::FusedIterator;
+
+/// A range of dates, including the start date and excluding the end date.
+///
+/// The length of the range is the same as [`end.calendar_duration_from(start)`]. A range whose end
+/// is not after its start is empty.
+///
+/// [`end.calendar_duration_from(start)`]: CalendarDurationExt::calendar_duration_from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange<D> {
+    /// The first date in the range.
+    pub start: D,
+
+    /// The date after the last date in the range.
+    pub end: D,
+}
+
+impl<D: CalendarDurationExt> DateRange<D> {
+    /// Make a new range from the start date up to (but not including) the end date.
+    pub fn new(start: D, end: D) -> Self {
+        Self { start, end }
+    }
+
+    /// Return whether the range contains no dates.
+    pub fn is_empty(&self) -> bool {
+        self.end <= self.start
+    }
+
+    /// Return whether the given date is within the range.
+    pub fn contains(&self, date: D) -> bool {
+        self.start <= date && date < self.end
+    }
+
+    /// Split the range at the start of each ISO 8601 week (on Mondays), giving each part with the
+    /// week it is in. The first and last parts may be less than a whole week. An empty range gives
+    /// no parts.
+    #[cfg(feature = "alloc")]
+    pub fn split_by_iso_week(&self) -> Vec<(IsoWeek, DateRange<D>)> {
+        let end = self.end.to_day_number();
+        let mut start = self.start.to_day_number();
+        let mut parts = vec![];
+        while start < end {
+            let next_monday = start - i64::from(weekday_from_days(start)) + 7;
+            let part_end = next_monday.min(end);
+            let (year, week) = iso_week_from_days(start);
+            // Both ends are within the range, so they can be represented.
+            let (Some(a), Some(b)) = (D::from_day_number(start), D::from_day_number(part_end))
+            else {
+                break;
+            };
+            parts.push((IsoWeek { year, week }, DateRange::new(a, b)));
+            start = part_end;
+        }
+        parts
+    }
+
+    /// Iterate over the dates in the range at each step from the start, like the start dates of
+    /// the monthly billing periods within it.
+    ///
+    /// Each date is measured from the start of the range, as with
+    /// [`recurrences`](CalendarDurationExt::recurrences), so monthly from January 31st gives March
+    /// 31st after March 1st (for "February 31st") rather than getting stuck on the 1st. The end
+    /// date is never included.
+    ///
+    /// Panics if the step is zero. See [`checked_step_by`](Self::checked_step_by) for a version
+    /// which doesn't.
+    #[cfg(not(feature = "strict"))]
+    pub fn step_by(&self, step: CalendarDuration) -> CalendarRange<D> {
+        self.checked_step_by(step).expect("step must not be zero")
+    }
+
+    /// Iterate over the dates in the range at each step from the start, as with
+    /// [`step_by`](Self::step_by), or return `None` if the step is zero.
+    pub fn checked_step_by(&self, step: CalendarDuration) -> Option<CalendarRange<D>> {
+        if step.is_zero() {
+            return None;
+        }
+        Some(CalendarRange {
+            recurrence: Recurrence::new(self.start, step),
+            end: self.end,
+            next: Some(0),
+        })
+    }
+}
+
+/// An iterator over the dates in a [`DateRange`] at each step from its start. See
+/// [`DateRange::step_by`] and [`CalendarDurationExt::calendar_range`].
+#[derive(Debug, Clone)]
+pub struct CalendarRange<D> {
+    recurrence: Recurrence<D>,
+    end: D,
+    next: Option<u32>,
+}
+
+impl<D: CalendarDurationExt> Iterator for CalendarRange<D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<D> {
+        let n = self.next?;
+        let date = self.recurrence.nth(n).filter(|&date| date < self.end);
+        self.next = date.and(n.checked_add(1));
+        date
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let Some(n) = self.next else {
+            return (0, Some(0));
+        };
+        // The last date in the range is in the cycle containing the day before the end.
+        let Some(last_day) = self.end.sub_calendar_duration(&Days(1).into()) else {
+            return (0, Some(0));
+        };
+        if last_day < self.recurrence.anchor {
+            return (0, Some(0));
+        }
+        match self.recurrence.cycle_containing(last_day) {
+            Some(cycle) => {
+                let remaining = (cycle.index as usize + 1).saturating_sub(n as usize);
+                (remaining, Some(remaining))
+            }
+            None => (0, None),
+        }
+    }
+}
+
+impl<D: CalendarDurationExt> FusedIterator for CalendarRange<D> {}
+
+/// An ISO 8601 week: weeks start on Monday, and belong to the year their Thursday is in, so the
+/// first days of January can be in the last week of the previous year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct IsoWeek {
+    /// The ISO week-numbering year.
+    pub year: i32,
+
+    /// The week number, from 1 to 52 or 53.
+    pub week: u8,
+}
+
+impl IsoWeek {
+    /// Return the ISO 8601 week the date is in, for any date type.
+    pub fn of<D: CalendarDurationExt>(date: D) -> IsoWeek {
+        let (year, week) = iso_week_from_days(date.to_day_number());
+        IsoWeek { year, week }
+    }
+
+    /// Return the number of weeks from this week to the other one, which is negative if the other
+    /// week is earlier. Weeks in different years are counted across the year boundary, so week 53
+    /// of 2020 is one week before week 1 of 2021.
+    pub fn weeks_until(self, other: IsoWeek) -> i64 {
+        (iso_week_start(other.year, other.week) - iso_week_start(self.year, self.week)) / 7
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::IsoWeek> for IsoWeek {
+    fn from(week: chrono::IsoWeek) -> Self {
+        Self { year: week.year(), week: week.week() as u8 }
+    }
+}
+
+/// Count the whole ISO weeks from one date to the other, and the days left over.
+pub(crate) fn iso_weeks_between<D: CalendarDurationExt>(a: D, b: D) -> (i64, u8) {
+    let (start, end) = (a.min(b).to_day_number(), a.max(b).to_day_number());
+    let days = end - start;
+    // The whole weeks run from the first Monday on or after the start to the last Monday on or
+    // before the end.
+    let first_monday = start + (7 - i64::from(weekday_from_days(start))) % 7;
+    let last_monday = end - i64::from(weekday_from_days(end));
+    let weeks = ((last_monday - first_monday) / 7).max(0);
+    // The leftover days are less than a week at each end.
+    let leftover = (days - weeks * 7) as u8;
+    (if b < a { -weeks } else { weeks }, leftover)
+}
+
+/// Count the starts of ISO weeks from one date to the other.
+pub(crate) fn iso_week_boundaries_crossed<D: CalendarDurationExt>(a: D, b: D) -> i64 {
+    IsoWeek::of(a).weeks_until(IsoWeek::of(b))
+}
+
+/// Compute the total length of several date ranges, such as the periods of service of an employee
+/// who left and came back.
+///
+/// Overlapping and adjacent ranges are merged first, so no day is ever counted twice. The exact
+/// number of days covered is then expressed as a calendar duration by measuring that many days
+/// from the earliest start date. That choice of anchor matters: the same number of days can make
+/// a different number of months depending on which months it is measured across. For a single
+/// range, the result is the same as the plain difference between its end and start dates.
+#[cfg(feature = "alloc")]
+pub fn total_service<D: CalendarDurationExt>(ranges: &[DateRange<D>]) -> CalendarDuration {
+    let mut sorted = ranges.iter()
+        .filter(|r| !r.is_empty())
+        .map(|r| (r.start.to_day_number(), r.end.to_day_number()))
+        .collect::<Vec<_>>();
+    sorted.sort_unstable();
+
+    let Some(&(anchor, _)) = sorted.first() else {
+        return CalendarDuration { years: 0, months: 0, days: 0 };
+    };
+
+    let mut total = 0;
+    let mut current: Option<(i64, i64)> = None;
+    for (start, end) in sorted {
+        current = match current {
+            Some((cur_start, cur_end)) if start <= cur_end => Some((cur_start, cur_end.max(end))),
+            Some((cur_start, cur_end)) => {
+                total += cur_end - cur_start;
+                Some((start, end))
+            }
+            None => Some((start, end)),
+        };
+    }
+    if let Some((start, end)) = current {
+        total += end - start;
+    }
+
+    // The merged ranges fit between the earliest start and the latest end, so these dates do too.
+    match (D::from_day_number(anchor + total), D::from_day_number(anchor)) {
+        (Some(end), Some(start)) => duration_between(end, start),
+        _ => CalendarDuration { years: 0, months: 0, days: 0 },
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    #[cfg(any(feature = "alloc", not(feature = "strict")))]
+    use alloc::vec;
+    #[cfg(not(feature = "strict"))]
+    use alloc::vec::Vec;
+    use chrono::{Datelike, NaiveDate};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn range(a: (i32, u32, u32), b: (i32, u32, u32)) -> DateRange<NaiveDate> {
+        DateRange::new(
+            NaiveDate::from_ymd_opt(a.0, a.1, a.2).unwrap(),
+            NaiveDate::from_ymd_opt(b.0, b.1, b.2).unwrap())
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn single() {
+        let r = range((1988, 6, 16), (2020, 4, 8));
+        assert_eq!("31 years, 9 months, 23 days", total_service(&[r]).to_string());
+        assert_eq!(r.end.try_calendar_duration_from(r.start).unwrap().to_string(),
+            total_service(&[r]).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn overlapping() {
+        // Jan 1 - Apr 1 and Mar 1 - Jul 1 cover Jan 1 - Jul 1 once.
+        let ranges = [
+            range((2020, 3, 1), (2020, 7, 1)),
+            range((2020, 1, 1), (2020, 4, 1)),
+        ];
+        assert_eq!("6 months", total_service(&ranges).to_string());
+
+        // A range entirely contained in another adds nothing.
+        let ranges = [
+            range((2020, 1, 1), (2021, 1, 1)),
+            range((2020, 5, 5), (2020, 6, 6)),
+        ];
+        assert_eq!("1 year", total_service(&ranges).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn adjacent() {
+        let ranges = [
+            range((2020, 1, 1), (2020, 2, 1)),
+            range((2020, 2, 1), (2020, 3, 1)),
+        ];
+        assert_eq!("2 months", total_service(&ranges).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn gaps() {
+        // 31 days in January, then 30 days in April, are 61 days measured from January 1.
+        let ranges = [
+            range((2021, 1, 1), (2021, 2, 1)),
+            range((2021, 4, 1), (2021, 5, 1)),
+        ];
+        assert_eq!("2 months, 2 days", total_service(&ranges).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn empty() {
+        assert_eq!("same day", total_service::<NaiveDate>(&[]).to_string());
+        let backwards = range((2021, 4, 1), (2021, 1, 1));
+        assert_eq!("same day", total_service(&[backwards]).to_string());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iso_weeks_mid_week() {
+        // 2024-05-01 is a Wednesday, and 2024-05-15 is too.
+        let parts = range((2024, 5, 1), (2024, 5, 15)).split_by_iso_week();
+        assert_eq!(vec![
+            (IsoWeek { year: 2024, week: 18 }, range((2024, 5, 1), (2024, 5, 6))),
+            (IsoWeek { year: 2024, week: 19 }, range((2024, 5, 6), (2024, 5, 13))),
+            (IsoWeek { year: 2024, week: 20 }, range((2024, 5, 13), (2024, 5, 15))),
+        ], parts);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iso_weeks_aligned() {
+        let parts = range((2024, 5, 6), (2024, 5, 20)).split_by_iso_week();
+        assert_eq!(vec![
+            (IsoWeek { year: 2024, week: 19 }, range((2024, 5, 6), (2024, 5, 13))),
+            (IsoWeek { year: 2024, week: 20 }, range((2024, 5, 13), (2024, 5, 20))),
+        ], parts);
+
+        assert!(range((2024, 5, 6), (2024, 5, 6)).split_by_iso_week().is_empty());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn iso_weeks_year_boundary() {
+        // 2020-12-31 is a Thursday in week 53 of 2020, which runs until Sunday 2021-01-03.
+        let parts = range((2020, 12, 30), (2021, 1, 6)).split_by_iso_week();
+        assert_eq!(vec![
+            (IsoWeek { year: 2020, week: 53 }, range((2020, 12, 30), (2021, 1, 4))),
+            (IsoWeek { year: 2021, week: 1 }, range((2021, 1, 4), (2021, 1, 6))),
+        ], parts);
+
+        // 2024-12-30 is a Monday in week 1 of 2025.
+        let parts = range((2024, 12, 28), (2025, 1, 2)).split_by_iso_week();
+        assert_eq!(vec![
+            (IsoWeek { year: 2024, week: 52 }, range((2024, 12, 28), (2024, 12, 30))),
+            (IsoWeek { year: 2025, week: 1 }, range((2024, 12, 30), (2025, 1, 2))),
+        ], parts);
+    }
+
+    #[test]
+    fn iso_weeks_between() {
+        // Wednesday to Wednesday two weeks later has only one whole week, from Monday to Monday.
+        assert_eq!((1, 7), date(2024, 5, 1).iso_weeks_between(date(2024, 5, 15)));
+        assert_eq!((-1, 7), date(2024, 5, 15).iso_weeks_between(date(2024, 5, 1)));
+        assert_eq!((2, 0), date(2024, 5, 6).iso_weeks_between(date(2024, 5, 20)));
+        assert_eq!((0, 3), date(2024, 5, 7).iso_weeks_between(date(2024, 5, 10)));
+        assert_eq!((0, 0), date(2024, 5, 7).iso_weeks_between(date(2024, 5, 7)));
+        // Thursday to Thursday is 7 days, but not a whole week.
+        assert_eq!((0, 7), date(2024, 5, 2).iso_weeks_between(date(2024, 5, 9)));
+
+        // Week 53 of 2020 runs from 2020-12-28 to 2021-01-03. From Wednesday of that week to
+        // Wednesday of week 2 of 2021, only week 1 is whole.
+        assert_eq!((1, 7), date(2020, 12, 30).iso_weeks_between(date(2021, 1, 13)));
+        // 2024-12-30 starts week 1 of 2025.
+        assert_eq!((2, 0), date(2024, 12, 30).iso_weeks_between(date(2025, 1, 13)));
+        assert_eq!((1, 2), date(2024, 12, 28).iso_weeks_between(date(2025, 1, 6)));
+    }
+
+    #[test]
+    fn iso_week_of() {
+        assert_eq!(IsoWeek { year: 2020, week: 53 }, IsoWeek::of(date(2021, 1, 3)));
+        assert_eq!(IsoWeek { year: 2021, week: 1 }, IsoWeek::of(date(2021, 1, 4)));
+        assert_eq!(IsoWeek { year: 2025, week: 1 }, IsoWeek::of(date(2024, 12, 30)));
+        // Every day of a few years, against chrono's and time's own week dates.
+        for n in 0 .. 366 * 6 {
+            let day = date(2019, 12, 1) + chrono::Days::new(n);
+            assert_eq!(IsoWeek::from(day.iso_week()), IsoWeek::of(day), "{day}");
+            #[cfg(feature = "time")]
+            {
+                let day = time::Date::from_ymd(day.year(), day.month() as u8, day.day() as u8)
+                    .unwrap();
+                let (year, week, _) = day.to_iso_week_date();
+                assert_eq!(IsoWeek { year, week }, IsoWeek::of(day), "{day}");
+            }
+        }
+    }
+
+    #[test]
+    fn iso_week_boundaries_crossed() {
+        // Monday of week 53 of 2020 to Monday of week 1 of 2021.
+        let (a, b) = (date(2020, 12, 28), date(2021, 1, 4));
+        assert_eq!(1, a.iso_week_boundaries_crossed(b));
+        assert_eq!(-1, b.iso_week_boundaries_crossed(a));
+        assert_eq!("1 week", a.try_weeks_and_days_from(b).unwrap().to_string());
+
+        // Thursday to Monday crosses into the next week after only 4 days.
+        let a = date(2020, 12, 31);
+        assert_eq!(1, a.iso_week_boundaries_crossed(b));
+        assert_eq!("4 days", a.try_weeks_and_days_from(b).unwrap().to_string());
+        assert_eq!((0, 4), a.iso_weeks_between(b));
+
+        // Sunday to the Monday 8 days later crosses two boundaries in one elapsed week.
+        let (a, b) = (date(2024, 12, 29), date(2025, 1, 6));
+        assert_eq!(2, a.iso_week_boundaries_crossed(b));
+        assert_eq!("1 week, 1 day", a.try_weeks_and_days_from(b).unwrap().to_string());
+
+        // Within a week, none.
+        assert_eq!(0, date(2024, 5, 6).iso_week_boundaries_crossed(date(2024, 5, 12)));
+        assert_eq!(0, date(2024, 5, 6).iso_week_boundaries_crossed(date(2024, 5, 6)));
+    }
+
+    #[test]
+    fn weeks_until() {
+        let w53 = IsoWeek { year: 2020, week: 53 };
+        assert_eq!(1, w53.weeks_until(IsoWeek { year: 2021, week: 1 }));
+        assert_eq!(-52, w53.weeks_until(IsoWeek { year: 2020, week: 1 }));
+        let w1 = IsoWeek { year: 2024, week: 1 };
+        assert_eq!(52, w1.weeks_until(IsoWeek { year: 2025, week: 1 }));
+        let chrono_week = NaiveDate::from_ymd_opt(2021, 1, 3).unwrap().iso_week();
+        assert_eq!(w53, IsoWeek::from(chrono_week));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn monthly_steps() {
+        let month = CalendarDuration::from(crate::Months(1));
+        let range = DateRange::new(date(2023, 1, 31), date(2023, 5, 1));
+        assert_eq!(vec![date(2023, 1, 31), date(2023, 3, 1), date(2023, 3, 31)],
+            range.step_by(month.clone()).collect::<Vec<_>>());
+        let dates = NaiveDate::calendar_range(date(2023, 1, 31), date(2023, 6, 1), month.clone())
+            .collect::<Vec<_>>();
+        assert_eq!((5, Some(&date(2023, 5, 31))), (dates.len(), dates.last()));
+
+        let leap = NaiveDate::calendar_range(date(2024, 1, 31), date(2024, 5, 31), month);
+        assert_eq!((4, Some(4)), leap.size_hint());
+        assert_eq!(4, leap.count());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn yearly_steps_over_leap_day() {
+        let every = CalendarDuration::from(crate::Years(1));
+        let dates = NaiveDate::calendar_range(date(2024, 2, 29), date(2028, 2, 29), every.clone())
+            .collect::<Vec<_>>();
+        assert_eq!(vec![date(2024, 2, 29), date(2025, 3, 1), date(2026, 3, 1), date(2027, 3, 1)],
+            dates);
+        let dates = NaiveDate::calendar_range(date(2024, 2, 29), date(2028, 3, 1), every)
+            .collect::<Vec<_>>();
+        assert_eq!(Some(&date(2028, 2, 29)), dates.last());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn steps_size_hint() {
+        let step = CalendarDuration { years: 0, months: 1, days: 10 };
+        let mut dates = DateRange::new(date(2020, 1, 31), date(2023, 7, 4)).step_by(step);
+        let mut remaining = dates.clone().count();
+        assert_eq!(31, remaining);
+        loop {
+            assert_eq!((remaining, Some(remaining)), dates.size_hint());
+            if dates.next().is_none() {
+                break;
+            }
+            remaining -= 1;
+        }
+        assert_eq!(0, remaining);
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn empty_steps() {
+        let month = CalendarDuration::from(crate::Months(1));
+        let ranges = [(date(2023, 1, 1), date(2023, 1, 1)), (date(2023, 2, 1), date(2023, 1, 1))];
+        for (start, end) in ranges {
+            let mut dates = DateRange::new(start, end).step_by(month.clone());
+            assert_eq!((0, Some(0)), dates.size_hint());
+            assert_eq!(None, dates.next());
+        }
+        let single = DateRange::new(date(2023, 1, 1), date(2023, 1, 2)).step_by(month.clone());
+        assert_eq!(vec![date(2023, 1, 1)], single.collect::<Vec<_>>());
+        assert!(DateRange::new(date(2023, 1, 1), date(2024, 1, 1))
+            .checked_step_by(CalendarDuration::ZERO).is_none());
+
+        let end = DateRange::new(NaiveDate::MAX - crate::Days(40), NaiveDate::MAX).step_by(month);
+        assert_eq!(2, end.count());
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic(expected = "step must not be zero")]
+    fn zero_step() {
+        let _ = DateRange::new(date(2023, 1, 1), date(2024, 1, 1)).step_by(CalendarDuration::ZERO);
+    }
+}
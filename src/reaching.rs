@@ -0,0 +1,89 @@
+use crate::overflow::{duration_from, OverflowPolicy};
+use crate::{CalendarDuration, CalendarDurationExt};
+
+pub(crate) fn first_date_reaching<D: CalendarDurationExt>(
+    start: D,
+    threshold: &CalendarDuration,
+    policy: OverflowPolicy,
+) -> Option<D> {
+    let threshold = (threshold.years, threshold.months, threshold.days);
+    let reaches = |n: i64| -> Option<bool> {
+        let date = D::from_day_number(n)?;
+        let dur = duration_from(date, start, policy).ok()?;
+        Some(date >= start && (dur.years, dur.months, dur.days) >= threshold)
+    };
+
+    // Adding the threshold gets close, but the first date reaching it can be a few days either
+    // side, depending on the policy and how the days at the end of the months fall.
+    let estimate = start.add_calendar_duration(&CalendarDuration {
+        years: threshold.0,
+        months: threshold.1,
+        days: threshold.2,
+    })?;
+    let mut n = estimate.to_day_number();
+    if reaches(n)? {
+        while n > start.to_day_number() && reaches(n - 1)? {
+            n -= 1;
+        }
+    } else {
+        while !reaches(n)? {
+            n += 1;
+        }
+    }
+    D::from_day_number(n)
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn leap_day() {
+        // Ten years of service from February 29th is reached on March 1st when "February 29th" of
+        // a common year rolls forward, or on February 28th when it is clamped.
+        let start = date(2024, 2, 29);
+        assert_eq!(Some(date(2034, 3, 1)), NaiveDate::first_date_reaching(start, &dur(10, 0, 0)));
+        assert_eq!(Some(date(2034, 2, 28)), NaiveDate::first_date_reaching_with(
+            start, &dur(10, 0, 0), OverflowPolicy::ClampToEndOfMonth));
+
+        // In a leap year both are on the anniversary.
+        assert_eq!(Some(date(2028, 2, 29)), NaiveDate::first_date_reaching(start, &dur(4, 0, 0)));
+        assert_eq!(Some(date(2028, 2, 29)), NaiveDate::first_date_reaching_with(
+            start, &dur(4, 0, 0), OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    #[test]
+    fn month_end() {
+        // Adding a month to January 31st gives March 1st, but with clamping a month is already
+        // reached on February 28th.
+        let start = date(2023, 1, 31);
+        assert_eq!(Some(date(2023, 3, 1)), start.add_calendar_duration(&dur(0, 1, 0)));
+        assert_eq!(Some(date(2023, 3, 1)), NaiveDate::first_date_reaching(start, &dur(0, 1, 0)));
+        assert_eq!(Some(date(2023, 2, 28)), NaiveDate::first_date_reaching_with(
+            start, &dur(0, 1, 0), OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    #[test]
+    fn field_wise() {
+        let start = date(2023, 1, 15);
+        assert_eq!(Some(start), NaiveDate::first_date_reaching(start, &dur(0, 0, 0)));
+        assert_eq!(Some(date(2023, 1, 16)), NaiveDate::first_date_reaching(start, &dur(0, 0, 1)));
+
+        // 1 month is more than 45 days when comparing field by field.
+        assert_eq!(Some(date(2023, 2, 15)), NaiveDate::first_date_reaching(start, &dur(0, 0, 45)));
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(None, NaiveDate::first_date_reaching(NaiveDate::MAX, &dur(0, 0, 1)));
+    }
+}
@@ -0,0 +1,236 @@
+use crate::approx::{approximate_length, DAY_UNITS};
+use crate::period::scale;
+use crate::{AddOptions, CalendarDuration, CalendarDurationExt, OverflowPolicy};
+use core::iter::FusedIterator;
+
+/// Dates recurring at a fixed calendar duration from an anchor date, such as the billing dates of
+/// a monthly subscription.
+///
+/// Every occurrence is measured from the anchor, so a day of the month which doesn't exist in a
+/// short month doesn't shift the occurrences after it: monthly from January 31st comes back to
+/// March 31st. The missing day itself is resolved with the policy.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Recurrence<D> {
+    /// The first occurrence.
+    pub anchor: D,
+
+    /// The duration between occurrences.
+    pub every: CalendarDuration,
+
+    /// How a day of the month which doesn't exist in an occurrence's month is resolved.
+    pub policy: OverflowPolicy,
+}
+
+/// One cycle of a [`Recurrence`]: the time from one occurrence up to the next.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cycle<D> {
+    /// The number of the cycle, counting from 0 for the one starting on the anchor date.
+    pub index: u32,
+
+    /// The occurrence starting the cycle.
+    pub start: D,
+
+    /// The next occurrence, which is the first date after the cycle.
+    pub end: D,
+}
+
+impl<D: CalendarDurationExt> Recurrence<D> {
+    /// Make a recurrence starting on the anchor date, which moves a day that doesn't exist in an
+    /// occurrence's month forward to the next valid date.
+    pub fn new(anchor: D, every: CalendarDuration) -> Self {
+        Self { anchor, every, policy: OverflowPolicy::NextValid }
+    }
+
+    /// Use the given policy for a day of the month which doesn't exist in an occurrence's month.
+    pub fn with_policy(mut self, policy: OverflowPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Return the `n`th occurrence, counting the anchor date as the 0th, or `None` if it can't be
+    /// represented by the date type.
+    pub fn nth(&self, n: u32) -> Option<D> {
+        if n == 0 {
+            return Some(self.anchor);
+        }
+        self.anchor.add_calendar_duration_with(
+            &scale(&self.every, n)?,
+            &AddOptions::new().overflow_policy(self.policy))
+    }
+
+    /// Iterate over the occurrences, starting with the anchor date. The iteration stops at the
+    /// first occurrence which can't be represented by the date type.
+    pub fn iter(&self) -> Recurrences<D> {
+        Recurrences { recurrence: self.clone(), next: Some(0) }
+    }
+
+    /// Return the cycle containing the given date: the occurrence on or before it, and the one
+    /// after it.
+    ///
+    /// The cycle is found from the length of the duration rather than by stepping through the
+    /// occurrences, so this takes about the same time however far the date is from the anchor.
+    ///
+    /// Returns `None` if the date is before the anchor, if the duration is zero, or if the end of
+    /// the cycle can't be represented by the date type.
+    pub fn cycle_containing(&self, date: D) -> Option<Cycle<D>> {
+        let length = approximate_length(&self.every);
+        if date < self.anchor || length == 0 {
+            return None;
+        }
+
+        // Estimate the index from the average length of the duration, then correct it. The
+        // estimate is off by at most one or two cycles.
+        let days = (date.to_day_number() - self.anchor.to_day_number()) as u64;
+        let mut index = u32::try_from(days * DAY_UNITS / length).unwrap_or(u32::MAX);
+        while index > 0 && self.nth(index).is_none_or(|start| start > date) {
+            index -= 1;
+        }
+        loop {
+            let end = self.nth(index.checked_add(1)?)?;
+            if end > date {
+                // The occurrence at the index is on or before the date, or the index is 0.
+                return Some(Cycle { index, start: self.nth(index)?, end });
+            }
+            index += 1;
+        }
+    }
+}
+
+/// An iterator over the occurrences of a [`Recurrence`]. See [`Recurrence::iter`].
+#[derive(Debug, Clone)]
+pub struct Recurrences<D> {
+    recurrence: Recurrence<D>,
+    next: Option<u32>,
+}
+
+impl<D: CalendarDurationExt> Iterator for Recurrences<D> {
+    type Item = D;
+
+    fn next(&mut self) -> Option<D> {
+        let n = self.next?;
+        let date = self.recurrence.nth(n);
+        self.next = date.and(n.checked_add(1));
+        date
+    }
+}
+
+impl<D: CalendarDurationExt> FusedIterator for Recurrences<D> {}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use alloc::{vec, vec::Vec};
+    use chrono::{Datelike, NaiveDate};
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    const ONE_MONTH: CalendarDuration = CalendarDuration { years: 0, months: 1, days: 0 };
+
+    fn cycle(index: u32, start: NaiveDate, end: NaiveDate) -> Option<Cycle<NaiveDate>> {
+        Some(Cycle { index, start, end })
+    }
+
+    #[test]
+    fn month_end_clamped() {
+        let monthly = Recurrence::new(date(2023, 1, 31), ONE_MONTH)
+            .with_policy(OverflowPolicy::ClampToEndOfMonth);
+        assert_eq!(cycle(0, date(2023, 1, 31), date(2023, 2, 28)),
+            monthly.cycle_containing(date(2023, 2, 27)));
+        assert_eq!(cycle(1, date(2023, 2, 28), date(2023, 3, 31)),
+            monthly.cycle_containing(date(2023, 2, 28)));
+        assert_eq!(cycle(1, date(2023, 2, 28), date(2023, 3, 31)),
+            monthly.cycle_containing(date(2023, 3, 30)));
+        assert_eq!(cycle(2, date(2023, 3, 31), date(2023, 4, 30)),
+            monthly.cycle_containing(date(2023, 3, 31)));
+        assert_eq!(cycle(17, date(2024, 6, 30), date(2024, 7, 31)),
+            monthly.cycle_containing(date(2024, 7, 1)));
+    }
+
+    #[test]
+    fn month_end_next_valid() {
+        // "February 31st" is March 1st, even in a leap year.
+        let monthly = Recurrence::new(date(2023, 1, 31), ONE_MONTH);
+        assert_eq!(cycle(0, date(2023, 1, 31), date(2023, 3, 1)),
+            monthly.cycle_containing(date(2023, 2, 28)));
+        assert_eq!(cycle(1, date(2023, 3, 1), date(2023, 3, 31)),
+            monthly.cycle_containing(date(2023, 3, 1)));
+        assert_eq!(cycle(13, date(2024, 3, 1), date(2024, 3, 31)),
+            monthly.cycle_containing(date(2024, 3, 30)));
+    }
+
+    #[test]
+    fn consistent_with_iterator() {
+        let every = CalendarDuration { years: 0, months: 1, days: 10 };
+        for policy in [OverflowPolicy::NextValid, OverflowPolicy::ClampToEndOfMonth] {
+            let recurrence = Recurrence::new(date(2020, 1, 31), every.clone()).with_policy(policy);
+            let dates = recurrence.iter().take(60).collect::<Vec<_>>();
+            for (index, pair) in dates.windows(2).enumerate() {
+                let expected = cycle(index as u32, pair[0], pair[1]);
+                let mut day = pair[0];
+                while day < pair[1] {
+                    assert_eq!(expected, recurrence.cycle_containing(day), "{day}");
+                    day = day.succ_opt().unwrap();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        let monthly = Recurrence::new(date(2023, 1, 31), ONE_MONTH);
+        assert_eq!(None, monthly.cycle_containing(date(2023, 1, 30)));
+        let never = Recurrence::new(date(2023, 1, 31), CalendarDuration::from(crate::Days(0)));
+        assert_eq!(None, never.cycle_containing(date(2023, 2, 1)));
+        assert_eq!(None, monthly.cycle_containing(NaiveDate::MAX));
+        assert_eq!(Some(NaiveDate::MAX), Recurrence::new(NaiveDate::MAX, ONE_MONTH).iter().last());
+    }
+
+    #[test]
+    fn monthly_on_the_31st() {
+        let clamped = Recurrence::new(date(2023, 1, 31), ONE_MONTH)
+            .with_policy(OverflowPolicy::ClampToEndOfMonth)
+            .iter()
+            .take(13)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![date(2023, 1, 31), date(2023, 2, 28), date(2023, 3, 31), date(2023, 4, 30),
+            date(2023, 5, 31), date(2023, 6, 30), date(2023, 7, 31), date(2023, 8, 31),
+            date(2023, 9, 30), date(2023, 10, 31), date(2023, 11, 30), date(2023, 12, 31),
+            date(2024, 1, 31)], clamped);
+
+        // Months without a 31st give the 1st of the next month.
+        let dates = date(2023, 1, 31).recurrences(ONE_MONTH).take(13).collect::<Vec<_>>();
+        assert_eq!(vec![date(2023, 1, 31), date(2023, 3, 1), date(2023, 3, 31), date(2023, 5, 1),
+            date(2023, 5, 31), date(2023, 7, 1), date(2023, 7, 31), date(2023, 8, 31),
+            date(2023, 10, 1), date(2023, 10, 31), date(2023, 12, 1), date(2023, 12, 31),
+            date(2024, 1, 31)], dates);
+    }
+
+    #[test]
+    fn yearly_on_february_29th() {
+        let every = CalendarDuration::from(crate::Years(1));
+        let dates = date(2024, 2, 29).recurrences(every.clone())
+            .take_while(|&d| d <= date(2032, 12, 31))
+            .collect::<Vec<_>>();
+        assert_eq!(vec![date(2024, 2, 29), date(2025, 3, 1), date(2026, 3, 1), date(2027, 3, 1),
+            date(2028, 2, 29), date(2029, 3, 1), date(2030, 3, 1), date(2031, 3, 1),
+            date(2032, 2, 29)], dates);
+
+        let clamped = Recurrence::new(date(2024, 2, 29), every)
+            .with_policy(OverflowPolicy::ClampToEndOfMonth)
+            .iter()
+            .take(5)
+            .collect::<Vec<_>>();
+        assert_eq!(vec![date(2024, 2, 29), date(2025, 2, 28), date(2026, 2, 28), date(2027, 2, 28),
+            date(2028, 2, 29)], clamped);
+    }
+
+    #[test]
+    fn stops_at_end_of_range() {
+        let mut dates = date(NaiveDate::MAX.year() - 1, 12, 31).recurrences(ONE_MONTH);
+        assert_eq!(Some(NaiveDate::MAX), dates.nth(12));
+        assert_eq!(None, dates.next());
+        assert_eq!(None, dates.next());
+    }
+}
@@ -0,0 +1,130 @@
+use crate::arithmetic::step_months;
+use crate::gregorian::weekday_from_days;
+use crate::{duration_between, CalendarDuration, CalendarDurationExt};
+
+/// A coarse description of when a date is relative to a reference date ("today"), as returned by
+/// [`CalendarDurationExt::classify_relative`].
+///
+/// The buckets follow calendar boundaries rather than rolling windows: a date two days ago can be
+/// [`LastWeek`](Self::LastWeek) if a Monday came in between, and a date a week ago can still be
+/// [`ThisMonth`](Self::ThisMonth). Weeks are ISO weeks, starting on Monday.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum RelativeBucket {
+    /// The same day as the reference.
+    Today,
+
+    /// The day before the reference.
+    Yesterday,
+
+    /// Earlier in the same week as the reference.
+    ThisWeek,
+
+    /// In the week before the reference's week.
+    LastWeek,
+
+    /// Earlier in the same calendar month as the reference.
+    ThisMonth,
+
+    /// In the calendar month before the reference's month.
+    LastMonth,
+
+    /// Earlier in the same calendar year as the reference.
+    ThisYear,
+
+    /// In the calendar year before the reference's year.
+    LastYear,
+
+    /// In a calendar year at least two years before the reference's year; holds the difference
+    /// between the years.
+    YearsAgo(u32),
+
+    /// After the reference; holds the calendar duration until the date.
+    Future(CalendarDuration),
+}
+
+pub(crate) fn classify<D: CalendarDurationExt>(date: D, reference: D) -> RelativeBucket {
+    if date > reference {
+        return RelativeBucket::Future(duration_between(date, reference));
+    }
+
+    let days = date.to_day_number();
+    let ref_days = reference.to_day_number();
+    if days == ref_days {
+        return RelativeBucket::Today;
+    }
+    if days == ref_days - 1 {
+        return RelativeBucket::Yesterday;
+    }
+
+    let monday = days - i64::from(weekday_from_days(days));
+    let ref_monday = ref_days - i64::from(weekday_from_days(ref_days));
+    if monday == ref_monday {
+        return RelativeBucket::ThisWeek;
+    }
+    if monday == ref_monday - 7 {
+        return RelativeBucket::LastWeek;
+    }
+
+    let (y, m, _) = date.ymd();
+    let (ref_y, ref_m, _) = reference.ymd();
+    if (y, m) == (ref_y, ref_m) {
+        return RelativeBucket::ThisMonth;
+    }
+    if step_months::<D>(y, m, 1) == Some((ref_y, ref_m)) {
+        return RelativeBucket::LastMonth;
+    }
+    match ref_y - y {
+        0 => RelativeBucket::ThisYear,
+        1 => RelativeBucket::LastYear,
+        years => RelativeBucket::YearsAgo(years.unsigned_abs()),
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn days_and_weeks() {
+        // 2024-03-04 is a Monday.
+        let monday = date(2024, 3, 4);
+        assert_eq!(RelativeBucket::Today, monday.classify_relative(monday));
+        assert_eq!(RelativeBucket::Yesterday, date(2024, 3, 3).classify_relative(monday));
+        // Two days ago, but across the week boundary.
+        assert_eq!(RelativeBucket::LastWeek, date(2024, 3, 2).classify_relative(monday));
+        assert_eq!(RelativeBucket::LastWeek, date(2024, 2, 26).classify_relative(monday));
+
+        // The same gap within one week.
+        let thursday = date(2024, 3, 7);
+        assert_eq!(RelativeBucket::ThisWeek, date(2024, 3, 5).classify_relative(thursday));
+        assert_eq!(RelativeBucket::ThisWeek, monday.classify_relative(thursday));
+    }
+
+    #[test]
+    fn months_and_years() {
+        let reference = date(2024, 3, 20);
+        assert_eq!(RelativeBucket::ThisMonth, date(2024, 3, 1).classify_relative(reference));
+        assert_eq!(RelativeBucket::LastMonth, date(2024, 2, 1).classify_relative(reference));
+        assert_eq!(RelativeBucket::ThisYear, date(2024, 1, 31).classify_relative(reference));
+        assert_eq!(RelativeBucket::LastYear, date(2023, 12, 31).classify_relative(reference));
+        assert_eq!(RelativeBucket::LastYear, date(2023, 1, 1).classify_relative(reference));
+        assert_eq!(RelativeBucket::YearsAgo(2), date(2022, 12, 31).classify_relative(reference));
+        assert_eq!(RelativeBucket::YearsAgo(36), date(1988, 6, 16).classify_relative(reference));
+
+        // December is last month in January.
+        assert_eq!(RelativeBucket::LastMonth,
+            date(2023, 12, 1).classify_relative(date(2024, 1, 20)));
+    }
+
+    #[test]
+    fn future() {
+        let reference = date(2024, 3, 20);
+        assert_eq!(RelativeBucket::Future(CalendarDuration { years: 0, months: 0, days: 1 }),
+            date(2024, 3, 21).classify_relative(reference));
+    }
+}
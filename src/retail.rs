@@ -0,0 +1,281 @@
+use crate::gregorian::{civil_from_days, days_from_civil, month_length, weekday_from_days};
+use crate::{CalendarDurationExt, DateRange};
+
+/// How the weeks of each quarter of a [`RetailCalendar`] are grouped into its three periods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum WeekPattern {
+    /// 4 weeks, 4 weeks, then 5 weeks.
+    FourFourFive,
+
+    /// 4 weeks, 5 weeks, then 4 weeks.
+    FourFiveFour,
+
+    /// 5 weeks, 4 weeks, then 4 weeks.
+    FiveFourFour,
+}
+
+impl WeekPattern {
+    fn weeks(self) -> [u8; 3] {
+        match self {
+            WeekPattern::FourFourFive => [4, 4, 5],
+            WeekPattern::FourFiveFour => [4, 5, 4],
+            WeekPattern::FiveFourFour => [5, 4, 4],
+        }
+    }
+}
+
+/// The rule for the last day of a [`RetailCalendar`]'s fiscal year. Weekdays are numbered from 0
+/// for Monday to 6 for Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FiscalYearEnd {
+    /// The year ends on the last given day of the week in the given month, such as the last
+    /// Saturday in January.
+    LastWeekday {
+        /// The month (one-based).
+        month: u8,
+        /// The day of the week.
+        weekday: u8,
+    },
+
+    /// The year ends on the given day of the week nearest to the end of the given month, such as
+    /// the Saturday nearest the end of January, which may be in early February. This is the
+    /// National Retail Federation's rule.
+    NearestWeekday {
+        /// The month (one-based).
+        month: u8,
+        /// The day of the week.
+        weekday: u8,
+    },
+}
+
+/// A length of time in the periods and weeks of a [`RetailCalendar`]. See
+/// [`RetailCalendar::duration_between`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetailDuration {
+    /// Number of whole periods.
+    pub periods: u32,
+
+    /// Number of whole weeks in addition to the periods.
+    pub weeks: u32,
+
+    /// Number of days in addition to the periods and weeks.
+    pub days: u32,
+}
+
+/// A 4-4-5 (or 4-5-4, or 5-4-4) retail calendar, whose fiscal years are made of whole weeks.
+///
+/// Each fiscal year has twelve periods, three in each quarter, of four or five weeks according to
+/// the [`WeekPattern`]. Because the year ends on a fixed day of the week, it is usually 52 weeks
+/// long, but sometimes 53; the extra week goes at the end of the twelfth period.
+///
+/// Fiscal years are numbered by the Gregorian calendar year they end in, whatever calendar the
+/// date type uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RetailCalendar {
+    /// The grouping of weeks into periods.
+    pub pattern: WeekPattern,
+
+    /// The rule for the end of the fiscal year.
+    pub year_end: FiscalYearEnd,
+}
+
+impl RetailCalendar {
+    /// Make a retail calendar with the given pattern and year end.
+    pub fn new(pattern: WeekPattern, year_end: FiscalYearEnd) -> Self {
+        Self { pattern, year_end }
+    }
+
+    /// Return the day number of the last day of the given fiscal year.
+    fn year_end(&self, fiscal_year: i32) -> i64 {
+        let (month, weekday, nearest) = match self.year_end {
+            FiscalYearEnd::LastWeekday { month, weekday } => (month, weekday, false),
+            FiscalYearEnd::NearestWeekday { month, weekday } => (month, weekday, true),
+        };
+        let month = month.clamp(1, 12);
+        let last = days_from_civil(fiscal_year, month, month_length(fiscal_year, month));
+        let back = (i64::from(weekday_from_days(last)) - i64::from(weekday)).rem_euclid(7);
+        if nearest && back > 3 {
+            last - back + 7
+        } else {
+            last - back
+        }
+    }
+
+    /// Return the day number of the first day, and the number of weeks, of the fiscal year.
+    fn year_bounds(&self, fiscal_year: i32) -> (i64, i64) {
+        let start = self.year_end(fiscal_year - 1) + 1;
+        (start, (self.year_end(fiscal_year) + 1 - start) / 7)
+    }
+
+    /// Return the number of weeks (52 or 53) in the fiscal year.
+    pub fn weeks_in_year(&self, fiscal_year: i32) -> u8 {
+        self.year_bounds(fiscal_year).1 as u8
+    }
+
+    /// Return the first week (zero-based) of each period, and the end of the last one.
+    fn period_starts(&self, fiscal_year: i32) -> [i64; 13] {
+        let weeks = self.pattern.weeks();
+        let mut starts = [0; 13];
+        for i in 0 .. 12 {
+            starts[i + 1] = starts[i] + i64::from(weeks[i % 3]);
+        }
+        starts[12] = self.year_bounds(fiscal_year).1;
+        starts
+    }
+
+    fn fiscal_year_of(&self, day: i64) -> i32 {
+        let (y, _, _) = civil_from_days(day);
+        // The year end is within a week of its month, so the date is in one of these years.
+        (y - 1 ..= y + 1)
+            .find(|&fy| day <= self.year_end(fy))
+            .unwrap_or(y + 1)
+    }
+
+    /// Return the fiscal year, the period (1 to 12), and the week of the fiscal year (1 to 53)
+    /// of the date.
+    pub fn period_of<D: CalendarDurationExt>(&self, date: D) -> (i32, u8, u8) {
+        self.period_of_day(date.to_day_number())
+    }
+
+    fn period_of_day(&self, day: i64) -> (i32, u8, u8) {
+        let fiscal_year = self.fiscal_year_of(day);
+        let (start, _) = self.year_bounds(fiscal_year);
+        let week = (day - start) / 7;
+        let starts = self.period_starts(fiscal_year);
+        let period = starts[1 ..].iter().position(|&s| week < s).unwrap_or(11) + 1;
+        (fiscal_year, period as u8, week as u8 + 1)
+    }
+
+    /// Return the dates in the given period (1 to 12) of the fiscal year, or `None` if the period
+    /// is out of range or the dates can't be represented by the date type.
+    pub fn period_range<D: CalendarDurationExt>(&self, fiscal_year: i32, period: u8)
+        -> Option<DateRange<D>>
+    {
+        if !(1 ..= 12).contains(&period) {
+            return None;
+        }
+        let (start, _) = self.year_bounds(fiscal_year);
+        let starts = self.period_starts(fiscal_year);
+        let p = usize::from(period);
+        Some(DateRange::new(
+            D::from_day_number(start + 7 * starts[p - 1])?,
+            D::from_day_number(start + 7 * starts[p])?))
+    }
+
+    /// Return the first day of the period with the given index, counting periods from the start
+    /// of fiscal year 0.
+    fn period_start(&self, index: i64) -> (i64, i64) {
+        let fiscal_year = index.div_euclid(12) as i32;
+        let p = index.rem_euclid(12) as usize;
+        let (start, _) = self.year_bounds(fiscal_year);
+        let starts = self.period_starts(fiscal_year);
+        (start + 7 * starts[p], 7 * (starts[p + 1] - starts[p]))
+    }
+
+    /// Compute the length of time between two dates in whole retail periods, then whole weeks,
+    /// then days. The order of the dates doesn't matter.
+    ///
+    /// Like months, periods are counted from the earlier date to the same day of a later period:
+    /// the same number of days into it, or its last day if it is shorter.
+    pub fn duration_between<D: CalendarDurationExt>(&self, a: D, b: D) -> RetailDuration {
+        let (earlier, later) = if a < b { (a, b) } else { (b, a) };
+        let (earlier, later) = (earlier.to_day_number(), later.to_day_number());
+
+        let (fiscal_year, period, _) = self.period_of_day(earlier);
+        let index = i64::from(fiscal_year) * 12 + i64::from(period) - 1;
+        let offset = earlier - self.period_start(index).0;
+        let advanced = |n: i64| {
+            let (start, length) = self.period_start(index + n);
+            start + offset.min(length - 1)
+        };
+
+        let mut periods = 0;
+        while advanced(periods + 1) <= later {
+            periods += 1;
+        }
+        let days = later - advanced(periods);
+        RetailDuration {
+            periods: periods as u32,
+            weeks: (days / 7) as u32,
+            days: (days % 7) as u32,
+        }
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    /// The National Retail Federation's calendar: 4-5-4, ending on the Saturday nearest the end of
+    /// January.
+    fn nrf() -> RetailCalendar {
+        RetailCalendar::new(
+            WeekPattern::FourFiveFour,
+            FiscalYearEnd::NearestWeekday { month: 1, weekday: 5 })
+    }
+
+    #[test]
+    fn year_bounds() {
+        // The year ending 2024-02-03 started on 2023-01-29 and has 53 weeks.
+        let cal = nrf();
+        assert_eq!(53, cal.weeks_in_year(2024));
+        assert_eq!(52, cal.weeks_in_year(2023));
+        assert_eq!(52, cal.weeks_in_year(2025));
+        assert_eq!((2024, 1, 1), cal.period_of(date(2023, 1, 29)));
+        assert_eq!((2023, 12, 52), cal.period_of(date(2023, 1, 28)));
+        assert_eq!((2025, 1, 1), cal.period_of(date(2024, 2, 4)));
+    }
+
+    #[test]
+    fn final_week() {
+        let cal = nrf();
+        assert_eq!((2024, 12, 52), cal.period_of(date(2024, 1, 27)));
+        assert_eq!((2024, 12, 53), cal.period_of(date(2024, 1, 28)));
+        assert_eq!((2024, 12, 53), cal.period_of(date(2024, 2, 3)));
+
+        // The twelfth period gets the extra week.
+        let range = cal.period_range::<NaiveDate>(2024, 12).unwrap();
+        assert_eq!(date(2023, 12, 31), range.start);
+        assert_eq!(date(2024, 2, 4), range.end);
+        let range = cal.period_range::<NaiveDate>(2025, 12).unwrap();
+        assert_eq!(date(2025, 1, 5), range.start);
+        assert_eq!(date(2025, 2, 2), range.end);
+    }
+
+    #[test]
+    fn patterns() {
+        let year_end = FiscalYearEnd::LastWeekday { month: 12, weekday: 6 };
+        let cal = RetailCalendar::new(WeekPattern::FourFourFive, year_end);
+        // The last Sunday of 2022 was 2022-12-25.
+        let first = cal.period_range::<NaiveDate>(2023, 1).unwrap();
+        assert_eq!(date(2022, 12, 26), first.start);
+        assert_eq!(date(2023, 1, 23), first.end);
+        let third = cal.period_range::<NaiveDate>(2023, 3).unwrap();
+        assert_eq!(date(2023, 2, 20), third.start);
+        assert_eq!(date(2023, 3, 27), third.end);
+        assert_eq!((2023, 3, 13), cal.period_of(date(2023, 3, 26)));
+        assert_eq!((2023, 4, 14), cal.period_of(date(2023, 3, 27)));
+        assert!(cal.period_range::<NaiveDate>(2023, 0).is_none());
+        assert!(cal.period_range::<NaiveDate>(2023, 13).is_none());
+    }
+
+    #[test]
+    fn duration_between() {
+        let cal = nrf();
+        let d = |periods, weeks, days| RetailDuration { periods, weeks, days };
+        assert_eq!(d(0, 0, 0), cal.duration_between(date(2023, 5, 1), date(2023, 5, 1)));
+        // First day of period 1 to the first day of period 4, then a week and a day more.
+        assert_eq!(d(3, 1, 1), cal.duration_between(date(2023, 1, 29), date(2023, 5, 8)));
+        assert_eq!(d(3, 1, 1), cal.duration_between(date(2023, 5, 8), date(2023, 1, 29)));
+        // A whole year is twelve periods, even with 53 weeks.
+        assert_eq!(d(12, 0, 0), cal.duration_between(date(2023, 1, 29), date(2024, 2, 4)));
+        // From the last day of the 6-week period to the shorter following ones.
+        assert_eq!(d(1, 0, 0), cal.duration_between(date(2024, 2, 3), date(2024, 3, 2)));
+        assert_eq!(d(0, 3, 6), cal.duration_between(date(2024, 2, 3), date(2024, 3, 1)));
+    }
+}
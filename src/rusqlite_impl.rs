@@ -0,0 +1,93 @@
+//! Storing [`CalendarDuration`]s in SQLite with `rusqlite`, as ISO 8601 text.
+
+use crate::iso8601::parse_calendar_iso8601;
+use crate::parse::parse_shorthand;
+use crate::{CalendarDuration, ParseError};
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+
+fn parse(s: &str) -> Result<CalendarDuration, ParseError> {
+    if s.trim_start().starts_with(['P', 'p']) {
+        parse_calendar_iso8601(s)
+    } else {
+        parse_shorthand(s)
+    }
+}
+
+impl ToSql for CalendarDuration {
+    /// Store the duration as ISO 8601 text, like `P1Y2M3D`. The zero duration is `P0D`.
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.to_iso8601()))
+    }
+}
+
+impl FromSql for CalendarDuration {
+    /// Read a duration stored as ISO 8601 text, or in the older `1y 2m 3d` form. Values which
+    /// aren't text, or text which isn't a duration, are errors.
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        parse(value.as_str()?).map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn db() -> Connection {
+        let db = Connection::open_in_memory().unwrap();
+        db.execute("CREATE TABLE t (id INTEGER PRIMARY KEY, d)", ()).unwrap();
+        db
+    }
+
+    fn select(db: &Connection, id: i64) -> rusqlite::Result<CalendarDuration> {
+        db.query_row("SELECT d FROM t WHERE id = ?1", [id], |row| row.get(0))
+    }
+
+    #[test]
+    fn round_trip() {
+        let db = db();
+        let values = [dur(1, 2, 3), dur(0, 0, 0), dur(0, 18, 0), dur(0, 0, 45)];
+        for (id, d) in (0 ..).zip(&values) {
+            db.execute("INSERT INTO t VALUES (?1, ?2)", (id, d)).unwrap();
+        }
+        for (id, d) in (0 ..).zip(&values) {
+            assert_eq!(d, &select(&db, id).unwrap());
+        }
+        let text: String = db.query_row("SELECT d FROM t WHERE id = 1", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!("P0D", text);
+    }
+
+    #[test]
+    fn legacy_and_iso_text() {
+        let db = db();
+        for (id, text) in [(1, "1y 2m 3d"), (2, "0d"), (3, "P2W"), (4, " 5m ")] {
+            db.execute("INSERT INTO t VALUES (?1, ?2)", (id, text)).unwrap();
+        }
+        assert_eq!(dur(1, 2, 3), select(&db, 1).unwrap());
+        assert_eq!(dur(0, 0, 0), select(&db, 2).unwrap());
+        assert_eq!(dur(0, 0, 14), select(&db, 3).unwrap());
+        assert_eq!(dur(0, 5, 0), select(&db, 4).unwrap());
+    }
+
+    #[test]
+    fn malformed() {
+        let db = db();
+        for (id, text) in [(1, "P1DT1H"), (2, "1y 1y"), (3, "1w"), (4, ""), (5, "y")] {
+            db.execute("INSERT INTO t VALUES (?1, ?2)", (id, text)).unwrap();
+        }
+        db.execute("INSERT INTO t VALUES (6, 42)", ()).unwrap();
+
+        let message = |id| select(&db, id).unwrap_err().to_string();
+        assert!(message(1).contains("time of day \"P1DT1H\""), "{}", message(1));
+        assert!(message(2).contains("unit \"years\" given more than once"), "{}", message(2));
+        assert!(message(3).contains("unknown unit \"w\""), "{}", message(3));
+        assert!(message(4).contains("empty duration"), "{}", message(4));
+        assert!(message(5).contains("invalid number \"\""), "{}", message(5));
+        assert!(matches!(select(&db, 6), Err(rusqlite::Error::InvalidColumnType(..))));
+    }
+}
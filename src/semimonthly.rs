@@ -0,0 +1,198 @@
+use crate::business::{roll, BusinessDayRoll, HolidayCalendar, Weekend};
+use crate::gregorian::{civil_from_days, days_from_civil, month_length};
+use crate::{CalendarDurationExt, DateRange};
+use alloc::{vec, vec::Vec};
+
+/// A semi-monthly schedule, such as for payroll, with two pay days each month.
+///
+/// Each period ends on a pay day, and the next period starts the day after. With pay days on the
+/// 15th and the 31st, the periods run from the 1st to the 15th and from the 16th to the end of the
+/// month. A pay day past the end of a short month is clamped to its last day, so the 31st is paid
+/// on February 28th or 29th.
+///
+/// The pay days are in Gregorian months, whatever calendar the date type uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SemiMonthly {
+    /// The earlier pay day of the month.
+    pub first_day: u8,
+
+    /// The later pay day of the month.
+    pub second_day: u8,
+}
+
+/// A period of a [`SemiMonthly`] schedule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PayPeriod<D> {
+    /// The dates in the period. The last date in the period is the pay day.
+    pub period: DateRange<D>,
+
+    /// The date of payment for the period: the pay day, moved to a business day if rolling was
+    /// requested.
+    pub pay_date: D,
+}
+
+impl SemiMonthly {
+    /// Make a schedule with the two given pay days each month.
+    pub fn new(first_day: u8, second_day: u8) -> Self {
+        Self { first_day, second_day }
+    }
+
+    /// Return the periods whose pay days are on or after `from` and before `to`, paid on the pay
+    /// days themselves.
+    pub fn pay_periods<D: CalendarDurationExt>(&self, from: D, to: D) -> Vec<PayPeriod<D>> {
+        self.periods(from, to, Some)
+    }
+
+    /// Return the periods whose pay days are on or after `from` and before `to`, as with
+    /// [`pay_periods`](Self::pay_periods), with pay days which are not business days moved to
+    /// one in the given direction. A period is left out if there's no business day within a year
+    /// of its pay day.
+    pub fn pay_periods_rolled<D: CalendarDurationExt>(
+        &self,
+        from: D,
+        to: D,
+        direction: BusinessDayRoll,
+        weekend: Weekend,
+        holidays: &impl HolidayCalendar,
+    ) -> Vec<PayPeriod<D>> {
+        self.periods(from, to, |day| roll(day, direction, weekend, holidays))
+    }
+
+    fn periods<D: CalendarDurationExt>(
+        &self,
+        from: D,
+        to: D,
+        pay_date: impl Fn(i64) -> Option<i64>,
+    ) -> Vec<PayPeriod<D>> {
+        let (from, to) = (from.to_day_number(), to.to_day_number());
+        if from >= to {
+            return vec![];
+        }
+
+        // Start a month early to find the end of the period before the first one.
+        let (y, m, _) = civil_from_days(from);
+        let mut month = i64::from(y) * 12 + i64::from(m - 1) - 1;
+        let mut previous = None;
+        let mut periods = vec![];
+        while let Ok(y) = i32::try_from(month.div_euclid(12)) {
+            let m = month.rem_euclid(12) as u8 + 1;
+            let mut days = [self.first_day, self.second_day]
+                .map(|day| days_from_civil(y, m, day.clamp(1, month_length(y, m))));
+            days.sort_unstable();
+
+            for pay_day in days {
+                if pay_day >= to {
+                    return periods;
+                }
+                if previous == Some(pay_day) {
+                    continue;
+                }
+                if let Some(previous) = previous.filter(|_| pay_day >= from) {
+                    let start = D::from_day_number(previous + 1);
+                    let end = D::from_day_number(pay_day + 1);
+                    let pay = pay_date(pay_day).and_then(D::from_day_number);
+                    if let (Some(start), Some(end), Some(pay_date)) = (start, end, pay) {
+                        periods.push(PayPeriod { period: DateRange::new(start, end), pay_date });
+                    }
+                }
+                previous = Some(pay_day);
+            }
+            month += 1;
+        }
+        periods
+    }
+}
+
+#[cfg(all(test, feature = "chrono"))]
+mod test {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    fn period(start: NaiveDate, last: NaiveDate, pay_date: NaiveDate) -> PayPeriod<NaiveDate> {
+        PayPeriod { period: DateRange::new(start, last.succ_opt().unwrap()), pay_date }
+    }
+
+    struct NoHolidays;
+
+    impl HolidayCalendar for NoHolidays {
+        fn is_holiday(&self, _ymd: (i32, u8, u8)) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn thirty_one_day_month() {
+        let schedule = SemiMonthly::new(15, 31);
+        assert_eq!(vec![
+            period(date(2024, 1, 1), date(2024, 1, 15), date(2024, 1, 15)),
+            period(date(2024, 1, 16), date(2024, 1, 31), date(2024, 1, 31)),
+        ], schedule.pay_periods(date(2024, 1, 1), date(2024, 2, 1)));
+    }
+
+    #[test]
+    fn february() {
+        let schedule = SemiMonthly::new(15, 31);
+        assert_eq!(vec![
+            period(date(2023, 2, 1), date(2023, 2, 15), date(2023, 2, 15)),
+            period(date(2023, 2, 16), date(2023, 2, 28), date(2023, 2, 28)),
+            period(date(2023, 3, 1), date(2023, 3, 15), date(2023, 3, 15)),
+        ], schedule.pay_periods(date(2023, 2, 10), date(2023, 3, 20)));
+
+        assert_eq!(vec![
+            period(date(2024, 2, 16), date(2024, 2, 29), date(2024, 2, 29)),
+        ], schedule.pay_periods(date(2024, 2, 16), date(2024, 3, 1)));
+
+        // With pay days on the 14th and 30th, February's second period ends on its last day, and
+        // the next one starts on March 1st.
+        let schedule = SemiMonthly::new(14, 30);
+        assert_eq!(vec![
+            period(date(2023, 2, 15), date(2023, 2, 28), date(2023, 2, 28)),
+            period(date(2023, 3, 1), date(2023, 3, 14), date(2023, 3, 14)),
+        ], schedule.pay_periods(date(2023, 2, 20), date(2023, 3, 15)));
+    }
+
+    #[test]
+    fn rolled() {
+        // 2024-06-15 is a Saturday, and 2024-06-30 is a Sunday.
+        let schedule = SemiMonthly::new(15, 31);
+        let june = (date(2024, 6, 1), date(2024, 7, 1));
+        let preceding = schedule.pay_periods_rolled(june.0, june.1, BusinessDayRoll::Preceding,
+            Weekend::SaturdaySunday, &NoHolidays);
+        assert_eq!(vec![
+            period(date(2024, 6, 1), date(2024, 6, 15), date(2024, 6, 14)),
+            period(date(2024, 6, 16), date(2024, 6, 30), date(2024, 6, 28)),
+        ], preceding);
+
+        let following = schedule.pay_periods_rolled(june.0, june.1, BusinessDayRoll::Following,
+            Weekend::SaturdaySunday, &NoHolidays);
+        assert_eq!(date(2024, 6, 17), following[0].pay_date);
+        assert_eq!(date(2024, 7, 1), following[1].pay_date);
+    }
+
+    #[test]
+    fn no_business_days() {
+        struct AllHolidays;
+
+        impl HolidayCalendar for AllHolidays {
+            fn is_holiday(&self, _ymd: (i32, u8, u8)) -> bool {
+                true
+            }
+        }
+
+        let schedule = SemiMonthly::new(15, 31);
+        let rolled = schedule.pay_periods_rolled(date(2024, 6, 1), date(2024, 7, 1),
+            BusinessDayRoll::Following, Weekend::None, &AllHolidays);
+        assert!(rolled.is_empty());
+    }
+
+    #[test]
+    fn empty() {
+        let schedule = SemiMonthly::new(15, 31);
+        assert!(schedule.pay_periods(date(2024, 1, 16), date(2024, 1, 31)).is_empty());
+        assert!(schedule.pay_periods(date(2024, 2, 1), date(2024, 1, 1)).is_empty());
+    }
+}
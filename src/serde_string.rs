@@ -0,0 +1,112 @@
+//! Serializing a [`CalendarDuration`] as a string, for use with `#[serde(with = "...")]`.
+//!
+//! Durations are written in ISO 8601 form, like `"P31Y9M23D"`, and can be read back from either
+//! that or the English form of [`Display`](core::fmt::Display), like
+//! `"31 years, 9 months, 23 days"` or `"same day"`, as with [`CalendarDuration::parse`].
+//!
+//! Without this, durations are serialized as a struct of their fields, like
+//! `{"years":31,"months":9,"days":23}`.
+//!
+//! ```
+//! use calendar_duration::CalendarDuration;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Policy {
+//!     #[serde(with = "calendar_duration::serde_string")]
+//!     retention: CalendarDuration,
+//! }
+//!
+//! let policy = Policy { retention: CalendarDuration { years: 1, months: 6, days: 0 } };
+//! assert_eq!(r#"{"retention":"P1Y6M"}"#, serde_json::to_string(&policy).unwrap());
+//! ```
+
+use crate::CalendarDuration;
+use serde::de::{self, Deserializer, Visitor};
+use serde::Serializer;
+use core::fmt::{self, Formatter};
+
+/// Serialize the duration as an ISO 8601 string.
+pub fn serialize<S: Serializer>(dur: &CalendarDuration, serializer: S)
+    -> Result<S::Ok, S::Error>
+{
+    serializer.serialize_str(&dur.to_iso8601())
+}
+
+/// Deserialize a duration from an ISO 8601 or English string.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D)
+    -> Result<CalendarDuration, D::Error>
+{
+    deserializer.deserialize_str(DurationVisitor)
+}
+
+struct DurationVisitor;
+
+impl Visitor<'_> for DurationVisitor {
+    type Value = CalendarDuration;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("a calendar duration like \"P1Y6M\" or \"1 year, 6 months\"")
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<CalendarDuration, E> {
+        CalendarDuration::parse(s).map_err(E::custom)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct AsString(#[serde(with = "super")] CalendarDuration);
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn struct_form() {
+        let d = dur(31, 9, 23);
+        let json = serde_json::to_string(&d).unwrap();
+        assert_eq!(r#"{"years":31,"months":9,"days":23}"#, json);
+        assert_eq!(d, serde_json::from_str(&json).unwrap());
+        assert_eq!(dur(0, 45, 400),
+            serde_json::from_str(r#"{"years":0,"months":45,"days":400}"#).unwrap());
+    }
+
+    #[test]
+    fn struct_form_out_of_range() {
+        for json in [r#"{"years":-1,"months":0,"days":0}"#,
+            r#"{"years":0,"months":4294967296,"days":0}"#, r#"{"years":0,"months":0}"#]
+        {
+            assert!(serde_json::from_str::<CalendarDuration>(json).is_err(), "{json}");
+        }
+    }
+
+    #[test]
+    fn string_form() {
+        for d in [dur(31, 9, 23), dur(0, 18, 0), dur(0, 0, 0)] {
+            let json = serde_json::to_string(&AsString(d.clone())).unwrap();
+            assert_eq!(format!("\"{d:#}\""), json);
+            assert_eq!(AsString(d), serde_json::from_str(&json).unwrap());
+        }
+        assert_eq!(AsString(dur(31, 9, 23)),
+            serde_json::from_str(r#""31 years, 9 months, 23 days""#).unwrap());
+    }
+
+    #[test]
+    fn string_form_same_day() {
+        assert_eq!(AsString(dur(0, 0, 0)), serde_json::from_str(r#""same day""#).unwrap());
+    }
+
+    #[test]
+    fn string_form_errors() {
+        let error = |json| serde_json::from_str::<AsString>(json).unwrap_err().to_string();
+        assert!(error(r#""2 weeks""#).starts_with("unknown unit \"weeks\""), "{}",
+            error(r#""2 weeks""#));
+        assert!(error("31").starts_with("invalid type: integer `31`, expected a calendar duration"),
+            "{}", error("31"));
+    }
+}
@@ -0,0 +1,128 @@
+use crate::CalendarDuration;
+#[cfg(feature = "alloc")]
+use crate::ParseError;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+
+/// A [`CalendarDuration`] with a direction: either into the past or into the future.
+///
+/// The zero duration has no direction, and is never negative.
+///
+/// The [`Display`] implementation formats past durations like "2 months, 3 days ago", future ones
+/// like "in 2 months, 3 days", and the zero duration as "same day".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SignedCalendarDuration {
+    negative: bool,
+    duration: CalendarDuration,
+}
+
+impl SignedCalendarDuration {
+    /// Make a signed duration from a magnitude and a direction. If the duration is zero, the
+    /// result is not negative regardless of `negative`.
+    pub fn new(duration: CalendarDuration, negative: bool) -> Self {
+        let is_zero = duration.years == 0 && duration.months == 0 && duration.days == 0;
+        Self { negative: negative && !is_zero, duration }
+    }
+
+    /// Whether the duration points into the past.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// The length of the duration, without its direction.
+    pub fn magnitude(&self) -> &CalendarDuration {
+        &self.duration
+    }
+
+    /// Parse a relative expression like "2 years ago", "in 3 months", or "3 weeks from now".
+    ///
+    /// "ago" makes the duration negative, and "in ..." or "... from now" make it positive. The
+    /// duration itself is parsed with [`CalendarDuration::parse_english`], so it may use digits or
+    /// number words. "now", "just now", and "today" give the zero duration. An expression with no
+    /// direction is an error ([`ParseError::MissingDirection`]) rather than defaulting to either
+    /// one. Parsing ignores case.
+    #[cfg(feature = "alloc")]
+    pub fn parse_relative(s: &str) -> Result<Self, ParseError> {
+        let lower = s.trim().to_lowercase();
+        let words = lower.split_whitespace().collect::<Vec<_>>();
+        let (negative, words) = match words.as_slice() {
+            [] => return Err(ParseError::Empty),
+            ["now"] | ["just", "now"] | ["today"] => {
+                return Ok(Self::new(CalendarDuration { years: 0, months: 0, days: 0 }, false));
+            }
+            [rest @ .., "ago"] => (true, rest),
+            ["in", rest @ ..] => (false, rest),
+            [rest @ .., "from", "now"] => (false, rest),
+            _ => return Err(ParseError::MissingDirection),
+        };
+        let duration = CalendarDuration::parse_english(&words.join(" "))?;
+        Ok(Self::new(duration, negative))
+    }
+}
+
+impl Display for SignedCalendarDuration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let d = &self.duration;
+        if d.years == 0 && d.months == 0 && d.days == 0 {
+            f.write_str("same day")
+        } else if self.negative {
+            write!(f, "{d} ago")
+        } else {
+            write!(f, "in {d}")
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod test {
+    use super::*;
+    use alloc::{borrow::ToOwned, string::ToString};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn past() {
+        let d = SignedCalendarDuration::parse_relative("2 years ago").unwrap();
+        assert!(d.is_negative());
+        assert_eq!(&dur(2, 0, 0), d.magnitude());
+        assert_eq!("2 years ago", d.to_string());
+
+        let d = SignedCalendarDuration::parse_relative("A year and a day ago").unwrap();
+        assert_eq!(SignedCalendarDuration::new(dur(1, 0, 1), true), d);
+    }
+
+    #[test]
+    fn future() {
+        let d = SignedCalendarDuration::parse_relative("in 3 months").unwrap();
+        assert!(!d.is_negative());
+        assert_eq!(&dur(0, 3, 0), d.magnitude());
+        assert_eq!("in 3 months", d.to_string());
+
+        assert_eq!(Ok(SignedCalendarDuration::new(dur(0, 0, 21), false)),
+            SignedCalendarDuration::parse_relative("3 weeks from now"));
+        assert_eq!(Ok(SignedCalendarDuration::new(dur(0, 2, 0), false)),
+            SignedCalendarDuration::parse_relative("in two months"));
+    }
+
+    #[test]
+    fn zero() {
+        for s in ["just now", "now", "Today", "0 days ago", "in 0 days"] {
+            let d = SignedCalendarDuration::parse_relative(s).unwrap();
+            assert!(!d.is_negative());
+            assert_eq!("same day", d.to_string());
+        }
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(Err(ParseError::MissingDirection),
+            SignedCalendarDuration::parse_relative("3 months"));
+        assert_eq!(Err(ParseError::Empty), SignedCalendarDuration::parse_relative("  "));
+        assert_eq!(Err(ParseError::Empty), SignedCalendarDuration::parse_relative("ago"));
+        assert_eq!(Err(ParseError::InvalidNumber("soon".to_owned())),
+            SignedCalendarDuration::parse_relative("in soon"));
+    }
+}
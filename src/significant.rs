@@ -0,0 +1,150 @@
+use crate::CalendarDuration;
+use core::fmt::{self, Display, Formatter};
+
+impl CalendarDuration {
+    /// Return the duration limited to its `n` largest nonzero units, for display like "31 years,
+    /// 10 months" instead of "31 years, 9 months, 23 days".
+    ///
+    /// The last unit shown is rounded using the first nonzero unit dropped, counting a year as
+    /// 12 months or 365 days, and a month as 30 days: 6 months or more rounds up to the next year,
+    /// and 15 days or more rounds up to the next month. Rounding up to 12 months carries into the
+    /// years, so 1 year, 11 months, and 20 days is "2 years" with one unit or two.
+    ///
+    /// An `n` of zero is taken as one. With as many units as the duration has, it is written the
+    /// same as by [`Display`].
+    ///
+    /// ```
+    /// # use calendar_duration::CalendarDuration;
+    /// let member_for = CalendarDuration { years: 31, months: 9, days: 23 };
+    /// assert_eq!("31 years, 10 months", member_for.significant(2).to_string());
+    /// assert_eq!("32 years", member_for.significant(1).to_string());
+    /// ```
+    pub fn significant(&self, n: usize) -> SignificantDisplay {
+        let mut parts = [u64::from(self.years), u64::from(self.months), u64::from(self.days)];
+        let mut nonzero = (0 .. parts.len()).filter(|&i| parts[i] != 0);
+        let Some(last) = nonzero.by_ref().nth(n.max(1) - 1) else {
+            return SignificantDisplay { parts };
+        };
+        let Some(dropped) = nonzero.next() else {
+            return SignificantDisplay { parts };
+        };
+
+        let [unit, remainder] = match (last, dropped) {
+            (0, 1) => [12, parts[1]],
+            (0, _) => [365, parts[2]],
+            _ => [30, parts[2]],
+        };
+        let round_up = (remainder + unit / 2) / unit;
+        if last == 1 && parts[1] < 12 && parts[1] + round_up == 12 {
+            parts[0] += 1;
+            parts[1] = 0;
+        } else {
+            parts[last] += round_up;
+        }
+        for part in &mut parts[last + 1 ..] {
+            *part = 0;
+        }
+        SignificantDisplay { parts }
+    }
+}
+
+/// A [`CalendarDuration`] limited to its most significant units, as given by
+/// [`CalendarDuration::significant`]. It implements [`Display`] in English, in the same form as
+/// [`CalendarDuration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SignificantDisplay {
+    parts: [u64; 3],
+}
+
+impl Display for SignificantDisplay {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let names = [("year", "years"), ("month", "months"), ("day", "days")];
+        let mut any = false;
+        for (n, (singular, plural)) in self.parts.into_iter().zip(names) {
+            if n == 0 {
+                continue;
+            }
+            if any {
+                f.write_str(", ")?;
+            }
+            write!(f, "{n} {}", if n == 1 { singular } else { plural })?;
+            any = true;
+        }
+        if !any {
+            f.write_str("same day")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::{format, string::{String, ToString}};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn significant(d: CalendarDuration, n: usize) -> String {
+        d.significant(n).to_string()
+    }
+
+    #[test]
+    fn each_n() {
+        let d = dur(31, 9, 23);
+        assert_eq!("32 years", significant(d.clone(), 0));
+        assert_eq!("32 years", significant(d.clone(), 1));
+        assert_eq!("31 years, 10 months", significant(d.clone(), 2));
+        assert_eq!("31 years, 9 months, 23 days", significant(d.clone(), 3));
+        assert_eq!("31 years, 9 months, 23 days", significant(d, 4));
+
+        let d = dur(2, 5, 14);
+        assert_eq!("2 years", significant(d.clone(), 1));
+        assert_eq!("2 years, 5 months", significant(d.clone(), 2));
+        assert_eq!("2 years, 5 months, 14 days", significant(d, 3));
+    }
+
+    #[test]
+    fn full_display() {
+        for d in [dur(0, 0, 0), dur(1, 1, 1), dur(0, 18, 400), dur(u32::MAX, u32::MAX, u32::MAX),
+            dur(1, 0, 3), dur(0, 2, 0)]
+        {
+            assert_eq!(d.to_string(), significant(d.clone(), 3));
+        }
+        assert_eq!("1 year, 3 days", significant(dur(1, 0, 3), 2));
+        assert_eq!("same day", significant(dur(0, 0, 0), 1));
+    }
+
+    #[test]
+    fn carry() {
+        let d = dur(1, 11, 20);
+        assert_eq!("2 years", significant(d.clone(), 1));
+        assert_eq!("2 years", significant(d.clone(), 2));
+        assert_eq!("1 year, 11 months, 20 days", significant(d, 3));
+        assert_eq!("1 year", significant(dur(0, 11, 15), 1));
+        assert_eq!("11 months", significant(dur(0, 11, 14), 1));
+        assert_eq!(format!("{} years", u64::from(u32::MAX) + 1),
+            significant(dur(u32::MAX, 11, 20), 2));
+    }
+
+    #[test]
+    fn skipped_units() {
+        // With no months, the days round the years.
+        assert_eq!("1 year", significant(dur(1, 0, 182), 1));
+        assert_eq!("2 years", significant(dur(1, 0, 183), 1));
+        assert_eq!("1 year", significant(dur(1, 0, 3), 1));
+        assert_eq!("3 months", significant(dur(0, 3, 0), 1));
+        assert_eq!("20 days", significant(dur(0, 0, 20), 1));
+    }
+
+    #[test]
+    fn denormalized() {
+        // 18 months is a year and a half, so rounds the years up. Only rounding up to exactly 12
+        // months carries.
+        assert_eq!("3 years", significant(dur(1, 18, 0), 1));
+        assert_eq!("18 months", significant(dur(0, 18, 10), 1));
+        assert_eq!("20 months", significant(dur(0, 18, 45), 1));
+        assert_eq!("13 months", significant(dur(0, 11, 45), 1));
+    }
+}
@@ -0,0 +1,101 @@
+use crate::parse::Unit;
+use crate::{CalendarDuration, ParseError};
+use alloc::{borrow::ToOwned, format, string::String};
+
+/// The number of digits in each field, enough for any `u32`.
+const WIDTH: usize = 10;
+
+impl CalendarDuration {
+    /// Encode the duration as a string whose lexicographic order is the same as the order of
+    /// (years, months, days), like `0000000031-0000000009-0000000023`, for storing in places which
+    /// can only sort strings, such as key-value stores.
+    ///
+    /// This format is a stable storage contract, and won't change in future versions: each
+    /// component is written in ASCII decimal, zero-padded to ten digits, in the order years,
+    /// months, days, separated by `-`. Every value fits, including denormalized ones. Use
+    /// [`from_sortable_string`](Self::from_sortable_string) to decode it. For display, see
+    /// [`format_fixed`](Self::format_fixed) instead.
+    pub fn to_sortable_string(&self) -> String {
+        format!("{:0WIDTH$}-{:0WIDTH$}-{:0WIDTH$}", self.years, self.months, self.days)
+    }
+
+    /// Decode a duration encoded by [`to_sortable_string`](Self::to_sortable_string).
+    ///
+    /// Only the exact format is accepted: every component must have exactly ten digits.
+    pub fn from_sortable_string(s: &str) -> Result<Self, ParseError> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut parts = s.splitn(3, '-');
+        let mut field = |unit: Unit| -> Result<u32, ParseError> {
+            let part = parts.next().ok_or_else(|| ParseError::InvalidNumber(s.to_owned()))?;
+            if part.len() != WIDTH || !part.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ParseError::InvalidNumber(part.to_owned()));
+            }
+            part.parse().map_err(|_| ParseError::OutOfRange(unit.plural().to_owned()))
+        };
+        Ok(CalendarDuration {
+            years: field(Unit::Years)?,
+            months: field(Unit::Months)?,
+            days: field(Unit::Days)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::vec::Vec;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!("0000000031-0000000009-0000000023", dur(31, 9, 23).to_sortable_string());
+        assert_eq!("0000000000-0000000000-0000000000", dur(0, 0, 0).to_sortable_string());
+        assert_eq!("4294967295-0000000018-0000000400",
+            dur(u32::MAX, 18, 400).to_sortable_string());
+    }
+
+    #[test]
+    fn round_trip() {
+        for d in [dur(31, 9, 23), dur(0, 0, 0), dur(u32::MAX, u32::MAX, u32::MAX), dur(0, 0, 45)] {
+            let s = d.to_sortable_string();
+            assert_eq!(Ok(d), CalendarDuration::from_sortable_string(&s));
+        }
+    }
+
+    #[test]
+    fn ordering() {
+        let shuffled = [
+            dur(1, 0, 0), dur(0, 11, 30), dur(10, 0, 0), dur(0, 0, 100), dur(2, 1, 3),
+            dur(0, 0, 9), dur(1, 0, 1), dur(0, 100, 0), dur(u32::MAX, 0, 0), dur(0, 0, 0),
+            dur(2, 0, 31), dur(0, 1, 0), dur(9, 12, 0),
+        ];
+        let mut by_fields = shuffled.to_vec();
+        by_fields.sort_by_key(|d| (d.years, d.months, d.days));
+        let mut by_strings = shuffled.iter().map(|d| d.to_sortable_string()).collect::<Vec<_>>();
+        by_strings.sort();
+        let decoded = by_strings.iter()
+            .map(|s| CalendarDuration::from_sortable_string(s).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(by_fields, decoded);
+    }
+
+    #[test]
+    fn errors() {
+        let parse = CalendarDuration::from_sortable_string;
+        assert_eq!(Err(ParseError::Empty), parse(""));
+        assert_eq!(Err(ParseError::InvalidNumber("31".to_owned())), parse("31-09-23"));
+        assert_eq!(Err(ParseError::InvalidNumber("0000000031-0000000009".to_owned())),
+            parse("0000000031-0000000009"));
+        assert_eq!(Err(ParseError::InvalidNumber("0000000023-".to_owned())),
+            parse("0000000031-0000000009-0000000023-"));
+        assert_eq!(Err(ParseError::InvalidNumber("+000000031".to_owned())),
+            parse("+000000031-0000000009-0000000023"));
+        assert_eq!(Err(ParseError::OutOfRange("years".to_owned())),
+            parse("5000000000-0000000009-0000000023"));
+    }
+}
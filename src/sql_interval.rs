@@ -0,0 +1,116 @@
+use crate::CalendarDuration;
+use alloc::{borrow::ToOwned, format, string::String};
+use core::fmt::{self, Display, Formatter};
+
+/// Error returned by [`CalendarDuration::to_sql_interval`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SqlIntervalError {
+    /// The duration has both a year-month part and a day part. SQL-standard intervals are either
+    /// year-month intervals or day-time intervals, so a single literal can't hold both. Use
+    /// [`CalendarDuration::to_sql_interval_parts`] to get one literal for each.
+    MixedYearMonthAndDay,
+}
+
+impl Display for SqlIntervalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SqlIntervalError::MixedYearMonthAndDay => {
+                f.write_str("SQL interval literals can't mix year-month and day fields")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SqlIntervalError {}
+
+/// Return the SQL leading field precision suffix needed for the number, if it has more than the
+/// default of two digits.
+fn precision(n: u64) -> String {
+    let digits = n.checked_ilog10().unwrap_or(0) + 1;
+    if digits > 2 {
+        format!("({digits})")
+    } else {
+        String::new()
+    }
+}
+
+impl CalendarDuration {
+    /// Format the duration as an SQL-standard interval literal, like
+    /// `INTERVAL '1-2' YEAR TO MONTH` or `INTERVAL '23' DAY`.
+    ///
+    /// Durations with only years and/or months use a year-month interval, and durations with only
+    /// days use a day interval. The zero duration is `INTERVAL '0' DAY`. If the leading field has
+    /// more than two digits, its precision is given explicitly, as in `INTERVAL '100' YEAR(3)`.
+    ///
+    /// The standard has no interval type with both months and days, so durations with both a
+    /// year-month part and a day part give [`SqlIntervalError::MixedYearMonthAndDay`].
+    pub fn to_sql_interval(&self) -> Result<String, SqlIntervalError> {
+        match self.to_sql_interval_parts() {
+            (Some(_), Some(_)) => Err(SqlIntervalError::MixedYearMonthAndDay),
+            (Some(year_month), None) => Ok(year_month),
+            (None, Some(day)) => Ok(day),
+            (None, None) => Ok("INTERVAL '0' DAY".to_owned()),
+        }
+    }
+
+    /// Format the duration as separate SQL-standard interval literals for the year-month part and
+    /// the day part, either of which is `None` if it is zero. Adding the two intervals to a date in
+    /// that order gives the same result as
+    /// [`add_calendar_duration`](crate::CalendarDurationExt::add_calendar_duration).
+    ///
+    /// See [`to_sql_interval`](Self::to_sql_interval) for the format of the literals.
+    pub fn to_sql_interval_parts(&self) -> (Option<String>, Option<String>) {
+        // The months field of a year-month interval must be less than 12, so carry whole years of
+        // months over.
+        let years = u64::from(self.years) + u64::from(self.months / 12);
+        let year_month = match (years, self.months % 12) {
+            (0, 0) => None,
+            (y, 0) => Some(format!("INTERVAL '{y}' YEAR{}", precision(y))),
+            (0, m) => Some(format!("INTERVAL '{m}' MONTH")),
+            (y, m) => Some(format!("INTERVAL '{y}-{m}' YEAR{} TO MONTH", precision(y))),
+        };
+        let day = match self.days {
+            0 => None,
+            d => Some(format!("INTERVAL '{d}' DAY{}", precision(u64::from(d)))),
+        };
+        (year_month, day)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    #[test]
+    fn year_month() {
+        assert_eq!(Ok("INTERVAL '1-2' YEAR TO MONTH".to_owned()), dur(1, 2, 0).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '31' YEAR".to_owned()), dur(31, 0, 0).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '9' MONTH".to_owned()), dur(0, 9, 0).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '100-6' YEAR(3) TO MONTH".to_owned()),
+            dur(100, 6, 0).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '1-6' YEAR TO MONTH".to_owned()), dur(0, 18, 0).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '3' YEAR".to_owned()), dur(1, 24, 0).to_sql_interval());
+    }
+
+    #[test]
+    fn day() {
+        assert_eq!(Ok("INTERVAL '23' DAY".to_owned()), dur(0, 0, 23).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '200' DAY(3)".to_owned()), dur(0, 0, 200).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '45' DAY".to_owned()), dur(0, 0, 45).to_sql_interval());
+        assert_eq!(Ok("INTERVAL '0' DAY".to_owned()), dur(0, 0, 0).to_sql_interval());
+    }
+
+    #[test]
+    fn mixed() {
+        assert_eq!(Err(SqlIntervalError::MixedYearMonthAndDay), dur(1, 0, 1).to_sql_interval());
+        assert_eq!(Err(SqlIntervalError::MixedYearMonthAndDay), dur(0, 9, 23).to_sql_interval());
+        let (year_month, day) = dur(31, 9, 23).to_sql_interval_parts();
+        assert_eq!(Some("INTERVAL '31-9' YEAR TO MONTH"), year_month.as_deref());
+        assert_eq!(Some("INTERVAL '23' DAY"), day.as_deref());
+        assert_eq!((None, None), dur(0, 0, 0).to_sql_interval_parts());
+    }
+}
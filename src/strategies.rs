@@ -0,0 +1,142 @@
+//! `proptest` strategies for generating [`CalendarDuration`]s and dates.
+//!
+//! ```
+//! use calendar_duration::strategies::{calendar_duration_up_to, date_in};
+//! use calendar_duration::{CalendarDurationExt, PlainDate};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn add_then_measure(start in date_in::<PlainDate>(1900 ..= 2100),
+//!         dur in calendar_duration_up_to(100))
+//!     {
+//!         let end = start.add_calendar_duration(&dur).unwrap();
+//!         prop_assert!(end >= start);
+//!     }
+//! }
+//! # add_then_measure();
+//! ```
+
+use crate::gregorian::month_length;
+use crate::{CalendarDuration, CalendarDurationExt};
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+use proptest::prelude::*;
+
+/// Generate normalized durations, like those given by
+/// [`calendar_duration_from`](CalendarDurationExt::calendar_duration_from): fewer than 12 months
+/// and 31 days, and any number of years.
+pub fn calendar_duration() -> impl Strategy<Value = CalendarDuration> {
+    calendar_duration_up_to(u32::MAX)
+}
+
+/// Generate normalized durations, as with [`calendar_duration`], of at most the given number of
+/// years, so that they can be added to dates without going out of range.
+pub fn calendar_duration_up_to(max_years: u32) -> impl Strategy<Value = CalendarDuration> {
+    (0 ..= max_years, 0 .. 12_u32, 0 .. 31_u32)
+        .prop_map(|(years, months, days)| CalendarDuration { years, months, days })
+}
+
+/// Generate valid Gregorian (year, month, day) triples, with the year in the given range.
+pub fn ymd_in(years: RangeInclusive<i32>) -> impl Strategy<Value = (i32, u8, u8)> {
+    (years, 1 ..= 12_u8).prop_flat_map(|(y, m)| (Just(y), Just(m), 1 ..= month_length(y, m)))
+}
+
+/// Generate valid Gregorian (year, month, day) triples from the year 1 through 9999, which every
+/// date type supports, including `chrono::NaiveDate` and `time::Date` in their default
+/// configurations.
+pub fn ymd() -> impl Strategy<Value = (i32, u8, u8)> {
+    ymd_in(1 ..= 9999)
+}
+
+/// Generate dates of any type implementing [`CalendarDurationExt`], with the year in the given
+/// range. Dates the type can't represent are skipped.
+pub fn date_in<D: CalendarDurationExt + Debug>(years: RangeInclusive<i32>)
+    -> impl Strategy<Value = D>
+{
+    ymd_in(years).prop_filter_map("date out of range", |(y, m, d)| D::from_ymd(y, m, d))
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::PlainDate;
+    use core::cmp::Ordering;
+
+    /// Order three dates.
+    fn sorted<D: Ord>(a: D, b: D, c: D) -> (D, D, D) {
+        let mut dates = [a, b, c];
+        dates.sort();
+        let [a, b, c] = dates;
+        (a, b, c)
+    }
+
+    fn check_monotonic<D: CalendarDurationExt>(a: D, b: D, c: D) -> Result<(), TestCaseError> {
+        let (a, b, c) = sorted(a, b, c);
+        let ab = b.try_calendar_duration_from(a).unwrap();
+        let ac = c.try_calendar_duration_from(a).unwrap();
+        prop_assert_ne!(Ordering::Less, ac.cmp_at(&ab, a), "{:?} < {:?}", ac, ab);
+        prop_assert!(ac.checked_total_days_from(a) >= ab.checked_total_days_from(a));
+        // Both are counted up from the same date, so they're ordered field by field too.
+        prop_assert!((ac.years, ac.months, ac.days) >= (ab.years, ab.months, ab.days),
+            "{:?} < {:?}", ac, ab);
+        Ok(())
+    }
+
+    proptest! {
+        #[test]
+        fn generated_durations_are_normalized(d in calendar_duration()) {
+            prop_assert!(d.months < 12 && d.days < 31);
+        }
+
+        #[test]
+        fn ymd_is_valid(ymd in ymd()) {
+            let (y, m, d) = ymd;
+            prop_assert!(PlainDate::new(y, m, d).is_some());
+            #[cfg(feature = "chrono")]
+            prop_assert!(<chrono::NaiveDate as CalendarDurationExt>::from_ymd(y, m, d).is_some());
+            #[cfg(feature = "time")]
+            prop_assert!(<time::Date as CalendarDurationExt>::from_ymd(y, m, d).is_some());
+        }
+
+        #[test]
+        fn symmetric(a in date_in::<PlainDate>(-9999 ..= 9999),
+            b in date_in::<PlainDate>(-9999 ..= 9999))
+        {
+            prop_assert_eq!(a.try_calendar_duration_from(b), b.try_calendar_duration_from(a));
+        }
+
+        #[test]
+        fn monotonic(a in date_in::<PlainDate>(1900 ..= 2100),
+            b in date_in::<PlainDate>(1900 ..= 2100),
+            c in date_in::<PlainDate>(1900 ..= 2100))
+        {
+            check_monotonic(a, b, c)?;
+        }
+
+        #[test]
+        fn monotonic_near(a in date_in::<PlainDate>(2000 ..= 2001), b in 0 .. 120_i64,
+            c in 0 .. 120_i64)
+        {
+            let later = |days| PlainDate::from_day_number(a.to_day_number() + days).unwrap();
+            check_monotonic(a, later(b), later(c))?;
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    proptest! {
+        #[test]
+        fn symmetric_chrono(a in date_in::<chrono::NaiveDate>(1 ..= 9999),
+            b in date_in::<chrono::NaiveDate>(1 ..= 9999))
+        {
+            prop_assert_eq!(a.try_calendar_duration_from(b), b.try_calendar_duration_from(a));
+        }
+
+        #[test]
+        fn monotonic_chrono(a in date_in::<chrono::NaiveDate>(1900 ..= 2100),
+            b in date_in::<chrono::NaiveDate>(1900 ..= 2100),
+            c in date_in::<chrono::NaiveDate>(1900 ..= 2100))
+        {
+            check_monotonic(a, b, c)?;
+        }
+    }
+}
@@ -0,0 +1,206 @@
+//! Conversions to and from the JavaScript [Temporal] API's `Temporal.PlainDate` and
+//! `Temporal.Duration`, for use on `wasm32` targets with `wasm-bindgen`.
+//!
+//! Enabled by the `temporal-js` feature. Temporal dates carry a calendar, and only dates in the
+//! ISO 8601 calendar can be converted.
+//!
+//! [Temporal]: https://tc39.es/proposal-temporal/docs/
+
+use crate::{CalendarDuration, PlainDate};
+use core::fmt::{self, Display, Formatter};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A JavaScript `Temporal.PlainDate`.
+    #[wasm_bindgen(js_namespace = Temporal, js_name = PlainDate)]
+    pub type TemporalPlainDate;
+
+    #[wasm_bindgen(constructor, js_namespace = Temporal, js_class = PlainDate)]
+    fn new(year: i32, month: u8, day: u8) -> TemporalPlainDate;
+
+    #[wasm_bindgen(method, getter)]
+    fn year(this: &TemporalPlainDate) -> i32;
+
+    #[wasm_bindgen(method, getter)]
+    fn month(this: &TemporalPlainDate) -> u8;
+
+    #[wasm_bindgen(method, getter)]
+    fn day(this: &TemporalPlainDate) -> u8;
+
+    #[wasm_bindgen(method, getter, js_name = calendarId)]
+    fn calendar_id(this: &TemporalPlainDate) -> String;
+
+    /// A JavaScript `Temporal.Duration`.
+    #[wasm_bindgen(js_namespace = Temporal, js_name = Duration)]
+    pub type TemporalDuration;
+
+    #[wasm_bindgen(constructor, js_namespace = Temporal, js_class = Duration)]
+    fn new(years: f64, months: f64, weeks: f64, days: f64) -> TemporalDuration;
+
+    #[wasm_bindgen(method, getter)]
+    fn sign(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn years(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn months(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn weeks(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn days(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn hours(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn minutes(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn seconds(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn milliseconds(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn microseconds(this: &TemporalDuration) -> f64;
+
+    #[wasm_bindgen(method, getter)]
+    fn nanoseconds(this: &TemporalDuration) -> f64;
+}
+
+/// An error from converting a Temporal object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum TemporalError {
+    /// The date is in a calendar other than ISO 8601, given by its identifier.
+    NonIsoCalendar(String),
+
+    /// The duration has hours, minutes, or smaller units, which a [`CalendarDuration`] can't hold.
+    TimeComponents,
+
+    /// The duration is negative.
+    Negative,
+
+    /// A part of the duration doesn't fit in a [`CalendarDuration`].
+    OutOfRange,
+}
+
+impl Display for TemporalError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            TemporalError::NonIsoCalendar(id) => write!(f, "date is in the {id:?} calendar"),
+            TemporalError::TimeComponents => f.write_str("duration has time components"),
+            TemporalError::Negative => f.write_str("duration is negative"),
+            TemporalError::OutOfRange => f.write_str("duration out of range"),
+        }
+    }
+}
+
+impl core::error::Error for TemporalError {}
+
+impl TryFrom<&TemporalPlainDate> for PlainDate {
+    type Error = TemporalError;
+
+    fn try_from(date: &TemporalPlainDate) -> Result<Self, TemporalError> {
+        let calendar = date.calendar_id();
+        if calendar != "iso8601" {
+            return Err(TemporalError::NonIsoCalendar(calendar));
+        }
+        // Temporal only makes valid dates, so this can't fail.
+        PlainDate::new(date.year(), date.month(), date.day()).ok_or(TemporalError::OutOfRange)
+    }
+}
+
+impl From<PlainDate> for TemporalPlainDate {
+    /// Make a date in the ISO 8601 calendar.
+    ///
+    /// Panics (by throwing a JavaScript exception) if the date is outside of the range Temporal
+    /// supports, which is about 275,000 years either side of 1970.
+    fn from(date: PlainDate) -> Self {
+        TemporalPlainDate::new(date.year(), date.month(), date.day())
+    }
+}
+
+impl TryFrom<&TemporalDuration> for CalendarDuration {
+    type Error = TemporalError;
+
+    /// Convert the duration, counting its weeks as 7 days each.
+    fn try_from(dur: &TemporalDuration) -> Result<Self, TemporalError> {
+        if dur.sign() < 0. {
+            return Err(TemporalError::Negative);
+        }
+        let time = [dur.hours(), dur.minutes(), dur.seconds(), dur.milliseconds(),
+            dur.microseconds(), dur.nanoseconds()];
+        if time.iter().any(|&n| n != 0.) {
+            return Err(TemporalError::TimeComponents);
+        }
+        // The parts are whole numbers, so this only fails if they're too big.
+        let part = |n: f64| {
+            if n <= f64::from(u32::MAX) {
+                Ok(n as u32)
+            } else {
+                Err(TemporalError::OutOfRange)
+            }
+        };
+        let days = dur.weeks() * 7. + dur.days();
+        Ok(CalendarDuration {
+            years: part(dur.years())?,
+            months: part(dur.months())?,
+            days: part(days)?,
+        })
+    }
+}
+
+impl From<&CalendarDuration> for TemporalDuration {
+    fn from(dur: &CalendarDuration) -> Self {
+        TemporalDuration::new(f64::from(dur.years), f64::from(dur.months), 0.,
+            f64::from(dur.days))
+    }
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod test {
+    use super::*;
+    use wasm_bindgen_test::*;
+
+    wasm_bindgen_test_configure!(run_in_browser);
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = ["Temporal", "Duration"], js_name = from)]
+        fn duration_from(s: &str) -> TemporalDuration;
+
+        #[wasm_bindgen(js_namespace = ["Temporal", "PlainDate"], js_name = from)]
+        fn plain_date_from(s: &str) -> TemporalPlainDate;
+    }
+
+    #[wasm_bindgen_test]
+    fn duration_round_trip() {
+        let dur = CalendarDuration { years: 31, months: 9, days: 23 };
+        let temporal = TemporalDuration::from(&dur);
+        assert_eq!(Ok(dur), CalendarDuration::try_from(&temporal));
+        assert_eq!(Ok(CalendarDuration { years: 1, months: 0, days: 17 }),
+            CalendarDuration::try_from(&duration_from("P1Y2W3D")));
+    }
+
+    #[wasm_bindgen_test]
+    fn duration_rejected() {
+        assert_eq!(Err(TemporalError::TimeComponents),
+            CalendarDuration::try_from(&duration_from("P1DT2H")));
+        assert_eq!(Err(TemporalError::Negative),
+            CalendarDuration::try_from(&duration_from("-P1D")));
+    }
+
+    #[wasm_bindgen_test]
+    fn date_round_trip() {
+        let date = PlainDate::new(2024, 2, 29).unwrap();
+        let temporal = TemporalPlainDate::from(date);
+        assert_eq!(Ok(date), PlainDate::try_from(&temporal));
+        assert_eq!(Ok(date), PlainDate::try_from(&plain_date_from("2024-02-29")));
+        assert_eq!(Err(TemporalError::NonIsoCalendar("hebrew".to_owned())),
+            PlainDate::try_from(&plain_date_from("2024-02-29[u-ca=hebrew]")));
+    }
+}
@@ -0,0 +1,176 @@
+//! Checks for implementations of [`CalendarDurationExt`] outside of this crate.
+//!
+//! Enabled by the `testkit` feature. Call [`check_implementation`] from a test for your date type:
+//!
+//! ```ignore
+//! #[test]
+//! fn conforms() {
+//!     calendar_duration::testkit::check_implementation::<MyDate>(1999 ..= 2001);
+//! }
+//! ```
+
+use crate::gregorian::{days_from_civil, month_length};
+use crate::{CalendarDurationExt, PlainDate};
+use core::fmt::Debug;
+use core::ops::RangeInclusive;
+
+/// The numbers of days between the pairs of dates whose durations are compared with the
+/// reference implementation: around a month or two, and around a year or more.
+const OFFSETS: [i64; 12] = [1, 27, 28, 29, 30, 31, 59, 61, 92, 365, 366, 1000];
+
+/// Check that an implementation of [`CalendarDurationExt`] for a proleptic Gregorian date type
+/// follows the contract of the trait for every date in the given range of years, panicking with a
+/// description of the first problem found.
+///
+/// This checks that:
+///   - `from_ymd` accepts exactly the valid dates, and `ymd` gives back the same year, month, and
+///     day;
+///   - `checked_succ` gives the next day, which compares greater;
+///   - `to_day_number` counts days the same as the crate's Gregorian calendar tables, and
+///     `from_day_number` gives the date back;
+///   - `min_value` and `max_value` are valid dates in order, with no day after `max_value`;
+///   - `checked_from_ymd_or_next` agrees with the crate's own Gregorian calendar tables;
+///   - the durations between pairs of dates a selection of distances apart match the crate's own
+///     reference implementation.
+///
+/// The sample range may extend past `min_value` and `max_value`, in which case `from_ymd` is
+/// checked to reject the dates outside of them.
+pub fn check_implementation<D: CalendarDurationExt + Debug>(sample_range: RangeInclusive<i32>) {
+    let (min, max) = (D::min_value(), D::max_value());
+    assert!(min < max, "min_value() {min:?} isn't less than max_value() {max:?}");
+    for bound in [min, max] {
+        let (y, m, d) = bound.ymd();
+        assert_eq!(Some(bound), D::from_ymd(y, m, d), "bound {bound:?} doesn't round trip");
+    }
+    assert_eq!(None, max.checked_succ(), "checked_succ() of max_value() {max:?} gave a date");
+
+    let supported = min.ymd() ..= max.ymd();
+    let mut dates = vec![];
+    for y in sample_range {
+        for m in 1 ..= 12 {
+            for d in 1 ..= 31 {
+                let valid = d <= month_length(y, m) && supported.contains(&(y, m, d));
+                let date = D::from_ymd(y, m, d);
+                assert_eq!(valid, date.is_some(),
+                    "from_ymd({y}, {m}, {d}) gave {date:?}");
+                check_or_next::<D>(y, m, d);
+                if let Some(date) = date {
+                    check_date(date, (y, m, d));
+                    dates.push(date);
+                }
+            }
+        }
+    }
+
+    for &a in &dates {
+        let n = day_number_of(a);
+        for offset in OFFSETS {
+            let Some(b) = dates.get(dates.partition_point(|&d| day_number_of(d) < n + offset))
+            else {
+                continue;
+            };
+            if day_number_of(*b) != n + offset {
+                continue;
+            }
+            let reference = PlainDate::from_epoch_day(n).zip(PlainDate::from_epoch_day(n + offset))
+                .map(|(ra, rb)| ra.try_calendar_duration_from(rb));
+            let actual = a.try_calendar_duration_from(*b);
+            assert_eq!(reference, Some(actual.clone()),
+                "duration between {a:?} and {b:?} was {actual:?}");
+            assert_eq!(actual, b.try_calendar_duration_from(a),
+                "duration between {a:?} and {b:?} depends on the order");
+        }
+    }
+}
+
+fn day_number_of<D: CalendarDurationExt>(date: D) -> i64 {
+    let (y, m, d) = date.ymd();
+    days_from_civil(y, m, d)
+}
+
+fn check_date<D: CalendarDurationExt + Debug>(date: D, ymd: (i32, u8, u8)) {
+    assert_eq!(ymd, date.ymd(), "ymd() of {date:?} constructed from {ymd:?}");
+    assert_eq!(day_number_of(date), date.to_day_number(), "to_day_number() of {date:?}");
+    assert_eq!(Some(date), D::from_day_number(date.to_day_number()),
+        "from_day_number() of {date:?}'s day number");
+    if let Some(next) = date.checked_succ() {
+        assert_eq!(day_number_of(date) + 1, day_number_of(next),
+            "checked_succ() of {date:?} gave {next:?}");
+        assert!(next > date, "checked_succ() of {date:?} gave {next:?}, which isn't greater");
+    }
+}
+
+fn check_or_next<D: CalendarDurationExt + Debug>(y: i32, m: u8, d: u8) {
+    let expected = PlainDate::checked_from_ymd_or_next(y, m, d).map(|r| r.ymd());
+    let actual = D::checked_from_ymd_or_next(y, m, d);
+    // The date type may not be able to represent the date, but it shouldn't give a different one.
+    if let Some(actual) = actual {
+        assert_eq!(expected, Some(actual.ymd()),
+            "checked_from_ymd_or_next({y}, {m}, {d}) gave {actual:?}");
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono() {
+        use chrono::Datelike;
+        check_implementation::<chrono::NaiveDate>(1999 ..= 2001);
+        check_implementation::<chrono::NaiveDate>(-1 ..= 0);
+        let (min, max) = (chrono::NaiveDate::MIN.year(), chrono::NaiveDate::MAX.year());
+        check_implementation::<chrono::NaiveDate>(min - 1 ..= min);
+        check_implementation::<chrono::NaiveDate>(max ..= max + 1);
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time() {
+        check_implementation::<time::Date>(1999 ..= 2001);
+        check_implementation::<time::Date>(9999 ..= 9999);
+        let (min, max) = (time::Date::MIN.year(), time::Date::MAX.year());
+        check_implementation::<time::Date>(min - 1 ..= min);
+        check_implementation::<time::Date>(max ..= max + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "checked_succ()")]
+    fn broken() {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+        struct Broken(PlainDate);
+
+        impl CalendarDurationExt for Broken {
+            fn ymd(self) -> (i32, u8, u8) {
+                self.0.ymd()
+            }
+
+            fn from_ymd(y: i32, m: u8, d: u8) -> Option<Self> {
+                PlainDate::from_ymd(y, m, d).map(Broken)
+            }
+
+            #[cfg(not(feature = "strict"))]
+            fn succ(self) -> Self {
+                self.checked_succ().unwrap()
+            }
+
+            fn min_value() -> Self {
+                Broken(PlainDate::min_value())
+            }
+
+            fn max_value() -> Self {
+                Broken(PlainDate::max_value())
+            }
+
+            // Skips the last day of every month.
+            fn checked_succ(self) -> Option<Self> {
+                let (y, m, d) = self.ymd();
+                Self::from_ymd(y, m, d + 1).or_else(|| PlainDate::from_epoch_day(
+                    days_from_civil(y, m, d) + 2).map(Broken))
+            }
+        }
+
+        check_implementation::<Broken>(2001 ..= 2001);
+    }
+}
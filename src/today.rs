@@ -0,0 +1,136 @@
+use crate::{duration_between, CalendarDuration, CalendarDurationExt};
+
+/// Date types which can give the current date, for measuring durations to today without fetching
+/// it at every call site.
+///
+/// This is implemented for `chrono::NaiveDate` and `time::Date` with the `clock` feature.
+pub trait CalendarDurationToday: CalendarDurationExt {
+    /// Return today's date in the local time zone.
+    fn today() -> Self;
+
+    /// Return today's date in UTC.
+    fn today_utc() -> Self;
+
+    /// Compute the calendar duration between this date and today's date in the local time zone.
+    /// As with [`calendar_duration_from`](CalendarDurationExt::calendar_duration_from), the order
+    /// of the dates doesn't matter.
+    fn calendar_duration_to_today(self) -> CalendarDuration {
+        duration_to(self, Self::today())
+    }
+
+    /// Compute the calendar duration between this date and today's date in UTC.
+    fn calendar_duration_to_today_utc(self) -> CalendarDuration {
+        duration_to(self, Self::today_utc())
+    }
+}
+
+/// Compute the age of someone born on the given date, as of today's date in the local time zone,
+/// like "31 years, 9 months, 23 days".
+///
+/// Someone born on February 29th has a birthday on March 1st in common years, the same as for
+/// [`age_on`](CalendarDurationExt::age_on). Returns the zero duration if the birth date is after
+/// today.
+pub fn age_from_birthdate<D: CalendarDurationToday>(birth: D) -> CalendarDuration {
+    age_as_of(birth, D::today())
+}
+
+fn duration_to<D: CalendarDurationExt>(date: D, today: D) -> CalendarDuration {
+    duration_between(today, date)
+}
+
+fn age_as_of<D: CalendarDurationExt>(birth: D, today: D) -> CalendarDuration {
+    if today < birth {
+        return CalendarDuration::ZERO;
+    }
+    duration_between(today, birth)
+}
+
+#[cfg(feature = "chrono")]
+impl CalendarDurationToday for chrono::NaiveDate {
+    fn today() -> Self {
+        chrono::Local::now().date_naive()
+    }
+
+    fn today_utc() -> Self {
+        chrono::Utc::now().date_naive()
+    }
+}
+
+#[cfg(feature = "time")]
+impl CalendarDurationToday for time::Date {
+    /// Return today's date in the local time zone, or in UTC if the local offset can't be
+    /// determined, which `time` refuses to do in multi-threaded programs on some platforms.
+    fn today() -> Self {
+        time::OffsetDateTime::now_local()
+            .unwrap_or_else(|_| time::OffsetDateTime::now_utc())
+            .date()
+    }
+
+    fn today_utc() -> Self {
+        time::OffsetDateTime::now_utc().date()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::PlainDate;
+
+    fn date(y: i32, m: u8, d: u8) -> PlainDate {
+        PlainDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn duration_to_today() {
+        let today = date(2020, 5, 9);
+        assert_eq!(CalendarDuration::new(31, 9, 23), duration_to(date(1988, 7, 16), today));
+        assert_eq!(CalendarDuration::new(0, 1, 0), duration_to(date(2020, 6, 9), today));
+        assert_eq!(CalendarDuration::ZERO, duration_to(today, today));
+    }
+
+    #[test]
+    fn age() {
+        let birth = date(1988, 7, 16);
+        assert_eq!(CalendarDuration::new(31, 9, 23), age_as_of(birth, date(2020, 5, 9)));
+        assert_eq!(CalendarDuration::new(32, 0, 0), age_as_of(birth, date(2020, 7, 16)));
+        assert_eq!(CalendarDuration::new(31, 11, 29), age_as_of(birth, date(2020, 7, 15)));
+        assert_eq!(CalendarDuration::ZERO, age_as_of(birth, birth));
+        assert_eq!(CalendarDuration::ZERO, age_as_of(birth, date(1988, 7, 15)));
+    }
+
+    #[test]
+    fn born_on_leap_day() {
+        let birth = date(2024, 2, 29);
+        assert_eq!(CalendarDuration::new(0, 11, 30), age_as_of(birth, date(2025, 2, 28)));
+        assert_eq!(CalendarDuration::new(1, 0, 0), age_as_of(birth, date(2025, 3, 1)));
+        assert_eq!(CalendarDuration::new(3, 11, 30), age_as_of(birth, date(2028, 2, 28)));
+        assert_eq!(CalendarDuration::new(4, 0, 0), age_as_of(birth, date(2028, 2, 29)));
+        for as_of in [date(2025, 2, 28), date(2025, 3, 1), date(2028, 2, 29)] {
+            assert_eq!(birth.age_on(as_of), age_as_of(birth, as_of).years);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_today() {
+        use chrono::NaiveDate;
+        let (local, utc) = (NaiveDate::today(), NaiveDate::today_utc());
+        assert!((local - utc).num_days().abs() <= 1, "{local} {utc}");
+        let birth = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
+        assert!(birth.calendar_duration_to_today_utc().years >= 25);
+        assert!(age_from_birthdate(birth).years >= 25);
+        assert_eq!(CalendarDuration::ZERO, age_from_birthdate(NaiveDate::MAX));
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_today() {
+        use time::Date;
+        let (local, utc) = (Date::today(), Date::today_utc());
+        assert!((local - utc).whole_days().abs() <= 1, "{local} {utc}");
+        let birth = Date::from_calendar_date(2000, time::Month::January, 1).unwrap();
+        assert!(birth.calendar_duration_to_today().years >= 25);
+        assert!(age_from_birthdate(birth).years >= 25);
+        assert_eq!(CalendarDuration::ZERO, age_from_birthdate(Date::MAX));
+    }
+}
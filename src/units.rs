@@ -0,0 +1,285 @@
+use crate::CalendarDuration;
+#[cfg(not(feature = "strict"))]
+use crate::{CalendarDurationExt, HijriDate, PlainDate};
+#[cfg(not(feature = "strict"))]
+use core::ops::{Add, Sub};
+
+/// A number of years, for adding to and subtracting from dates: `date + Years(2)`.
+///
+/// Adding years to February 29th resolves the missing day with the default [`OverflowPolicy`].
+/// Use [`add_years_with`](crate::CalendarDurationExt::add_years_with) for a different policy.
+///
+/// [`OverflowPolicy`]: crate::OverflowPolicy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Years(pub u32);
+
+/// A number of months, for adding to and subtracting from dates: `date + Months(3)`.
+///
+/// Adding months to a day which doesn't exist in the resulting month resolves it with the default
+/// [`OverflowPolicy`], so January 31st plus `Months(1)` is March 1st (for "February 31st"). Use
+/// [`add_months_with`](crate::CalendarDurationExt::add_months_with) for a different policy.
+///
+/// [`OverflowPolicy`]: crate::OverflowPolicy
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Months(pub u32);
+
+/// A number of weeks, for adding to and subtracting from dates: `date + Weeks(2)`. A week is
+/// always 7 days.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Weeks(pub u32);
+
+/// A number of days, for adding to and subtracting from dates: `date + Days(10)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Days(pub u32);
+
+impl Years {
+    /// Convert the years to 12 months each, returning `None` if the number of months overflows.
+    pub fn checked_to_months(self) -> Option<Months> {
+        self.0.checked_mul(12).map(Months)
+    }
+}
+
+impl Weeks {
+    /// Convert the weeks to 7 days each, returning `None` if the number of days overflows.
+    pub fn checked_to_days(self) -> Option<Days> {
+        self.0.checked_mul(7).map(Days)
+    }
+}
+
+impl From<Years> for CalendarDuration {
+    fn from(Years(years): Years) -> Self {
+        CalendarDuration { years, months: 0, days: 0 }
+    }
+}
+
+impl From<Months> for CalendarDuration {
+    fn from(Months(months): Months) -> Self {
+        CalendarDuration { years: 0, months, days: 0 }
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl From<Weeks> for CalendarDuration {
+    /// Convert the weeks to 7 days each.
+    ///
+    /// Panics if the number of days overflows.
+    fn from(weeks: Weeks) -> Self {
+        Days::from(weeks).into()
+    }
+}
+
+impl From<Days> for CalendarDuration {
+    fn from(Days(days): Days) -> Self {
+        CalendarDuration { years: 0, months: 0, days }
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl From<Years> for Months {
+    /// Convert the years to 12 months each, as with
+    /// [`checked_to_months`](Years::checked_to_months).
+    ///
+    /// Panics if the number of months overflows.
+    fn from(years: Years) -> Self {
+        years.checked_to_months().expect("overflow when converting years to months")
+    }
+}
+
+#[cfg(not(feature = "strict"))]
+impl From<Weeks> for Days {
+    /// Convert the weeks to 7 days each, as with [`checked_to_days`](Weeks::checked_to_days).
+    ///
+    /// Panics if the number of days overflows.
+    fn from(weeks: Weeks) -> Self {
+        weeks.checked_to_days().expect("overflow when converting weeks to days")
+    }
+}
+
+/// Implement addition between units, giving the finer of the two.
+macro_rules! add_units {
+    ($($a:ident + $b:ident => $out:ident),* $(,)?) => {
+        $(
+            #[cfg(not(feature = "strict"))]
+            impl Add<$b> for $a {
+                type Output = $out;
+
+                /// Add the units, converting them to the finer one if they differ.
+                ///
+                /// Panics if the result overflows.
+                fn add(self, other: $b) -> $out {
+                    let ($out(a), $out(b)) = ($out::from(self), $out::from(other));
+                    $out(a.checked_add(b).expect("overflow when adding durations"))
+                }
+            }
+        )*
+    };
+}
+
+add_units! {
+    Years + Years => Years,
+    Months + Months => Months,
+    Years + Months => Months,
+    Months + Years => Months,
+    Weeks + Weeks => Weeks,
+    Days + Days => Days,
+    Weeks + Days => Days,
+    Days + Weeks => Days,
+}
+
+/// Implement adding a unit to a [`CalendarDuration`], giving a combined duration.
+macro_rules! add_to_duration {
+    ($($unit:ident),*) => {
+        $(
+            #[cfg(not(feature = "strict"))]
+            impl Add<$unit> for CalendarDuration {
+                type Output = CalendarDuration;
+
+                /// Add the unit to the matching part of the duration.
+                ///
+                /// Panics if the part overflows.
+                fn add(self, unit: $unit) -> CalendarDuration {
+                    let other = CalendarDuration::from(unit);
+                    let add = |a: u32, b| a.checked_add(b).expect("overflow when adding durations");
+                    CalendarDuration {
+                        years: add(self.years, other.years),
+                        months: add(self.months, other.months),
+                        days: add(self.days, other.days),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+add_to_duration!(Years, Months, Weeks, Days);
+
+/// Implement `date + unit` and `date - unit` for a date type, with the default overflow policy.
+macro_rules! date_ops {
+    ($date:ty) => {
+        date_ops!($date, Years, Months, Weeks, Days);
+    };
+    ($date:ty, $($unit:ident),*) => {
+        $(
+            #[cfg(not(feature = "strict"))]
+            impl Add<$unit> for $date {
+                type Output = $date;
+
+                /// Add the unit to the date, as with
+                /// [`add_calendar_duration`](CalendarDurationExt::add_calendar_duration).
+                ///
+                /// Panics if the result is out of range for the date type.
+                fn add(self, unit: $unit) -> $date {
+                    self.add_calendar_duration(&unit.into()).expect("date out of range")
+                }
+            }
+
+            #[cfg(not(feature = "strict"))]
+            impl Sub<$unit> for $date {
+                type Output = $date;
+
+                /// Subtract the unit from the date, as with
+                /// [`sub_calendar_duration`](CalendarDurationExt::sub_calendar_duration).
+                ///
+                /// Panics if the result is out of range for the date type.
+                fn sub(self, unit: $unit) -> $date {
+                    self.sub_calendar_duration(&unit.into()).expect("date out of range")
+                }
+            }
+        )*
+    };
+}
+
+date_ops!(PlainDate);
+date_ops!(HijriDate);
+#[cfg(feature = "chrono")]
+date_ops!(chrono::NaiveDate);
+#[cfg(feature = "time")]
+date_ops!(time::Date);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    #[cfg(not(feature = "strict"))]
+    use crate::OverflowPolicy;
+
+    #[cfg(not(feature = "strict"))]
+    fn date(y: i32, m: u8, d: u8) -> PlainDate {
+        PlainDate::new(y, m, d).unwrap()
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn months() {
+        assert_eq!(date(2023, 3, 1), date(2023, 1, 31) + Months(1));
+        assert_eq!(date(2024, 3, 1), date(2024, 1, 31) + Months(1));
+        assert_eq!(Some(date(2023, 2, 28)),
+            date(2023, 1, 31).add_months_with(Months(1), OverflowPolicy::ClampToEndOfMonth));
+        assert_eq!(date(2023, 3, 1), date(2023, 3, 31) - Months(1));
+        assert_eq!(Some(date(2023, 2, 28)),
+            date(2023, 3, 31).sub_months_with(Months(1), OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn years() {
+        assert_eq!(date(2025, 3, 1), date(2024, 2, 29) + Years(1));
+        assert_eq!(Some(date(2025, 2, 28)),
+            date(2024, 2, 29).add_years_with(Years(1), OverflowPolicy::ClampToEndOfMonth));
+        assert_eq!(Some(date(2023, 2, 28)),
+            date(2024, 2, 29).sub_years_with(Years(1), OverflowPolicy::ClampToEndOfMonth));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn weeks_and_days() {
+        let start = date(2024, 12, 25);
+        assert_eq!(start + Days(14), start + Weeks(2));
+        assert_eq!(date(2025, 1, 8), start + Weeks(2));
+        assert_eq!(date(2024, 12, 11), start - Weeks(2));
+        assert_eq!(CalendarDuration::from(Days(14)), CalendarDuration::from(Weeks(2)));
+        assert_eq!(Days(10), Weeks(1) + Days(3));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn mixed_units() {
+        assert_eq!(Months(14), Years(1) + Months(2));
+        let combined = CalendarDuration::from(Years(1)) + Months(2) + Weeks(1) + Days(3);
+        assert_eq!(CalendarDuration { years: 1, months: 2, days: 10 }, combined);
+        assert_eq!(Some(date(2025, 3, 25)), date(2024, 1, 15).add_calendar_duration(&combined));
+    }
+
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    #[should_panic(expected = "date out of range")]
+    fn out_of_range() {
+        let _ = PlainDate::max_value() + Days(1);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn chrono() {
+        let d = |y, m, d| chrono::NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        assert_eq!(d(2023, 3, 1), d(2023, 1, 31) + Months(1));
+        assert_eq!(d(2023, 1, 31), d(2023, 1, 17) + Weeks(2));
+    }
+
+    #[cfg(feature = "time")]
+    #[cfg(not(feature = "strict"))]
+    #[test]
+    fn time() {
+        let d = |y, m: u8, d| time::Date::from_calendar_date(y, m.try_into().unwrap(), d).unwrap();
+        assert_eq!(d(2023, 3, 1), d(2023, 1, 31) + Months(1));
+        assert_eq!(d(2022, 12, 31), d(2023, 1, 31) - Days(31));
+    }
+
+    #[test]
+    fn checked_conversions() {
+        assert_eq!(Some(Months(24)), Years(2).checked_to_months());
+        assert_eq!(Some(Months(u32::MAX / 12 * 12)), Years(u32::MAX / 12).checked_to_months());
+        assert_eq!(None, Years(u32::MAX / 12 + 1).checked_to_months());
+        assert_eq!(Some(Days(14)), Weeks(2).checked_to_days());
+        assert_eq!(None, Weeks(u32::MAX / 7 + 1).checked_to_days());
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{CalendarDuration, CalendarDurationError, CalendarDurationExt};
+use core::fmt::{self, Display, Formatter};
+
+/// A calendar duration with its days split into whole weeks and the days left over, for things
+/// which are usually counted in weeks, like "2 months, 1 week, 4 days".
+///
+/// Get one from a [`CalendarDuration`] with [`CalendarDuration::with_weeks`], or count only weeks
+/// and days between two dates, with no years or months, with
+/// [`CalendarDurationExt::weeks_and_days_from`].
+///
+/// Its [`Display`] implementation writes it in English like that of [`CalendarDuration`]:
+///
+/// ```
+/// # use calendar_duration::CalendarDuration;
+/// let dur = CalendarDuration { years: 0, months: 2, days: 11 };
+/// assert_eq!("2 months, 1 week, 4 days", dur.with_weeks().to_string());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarDurationWithWeeks {
+    /// Number of years.
+    pub years: u32,
+
+    /// Number of months.
+    pub months: u32,
+
+    /// Number of whole weeks.
+    pub weeks: u32,
+
+    /// Number of days left over after the whole weeks, which is less than 7.
+    pub days: u32,
+}
+
+impl CalendarDuration {
+    /// Split the days of the duration into whole weeks and the days left over.
+    pub fn weeks_and_days(&self) -> (u32, u32) {
+        (self.days / 7, self.days % 7)
+    }
+
+    /// Return the duration with its days split into whole weeks and the days left over.
+    ///
+    /// The years and months are the same, so for a duration computed from two dates, this is the
+    /// calendar duration between them as usual, with at most 4 weeks.
+    pub fn with_weeks(&self) -> CalendarDurationWithWeeks {
+        let (weeks, days) = self.weeks_and_days();
+        CalendarDurationWithWeeks { years: self.years, months: self.months, weeks, days }
+    }
+}
+
+impl From<CalendarDuration> for CalendarDurationWithWeeks {
+    fn from(dur: CalendarDuration) -> Self {
+        dur.with_weeks()
+    }
+}
+
+impl From<CalendarDurationWithWeeks> for CalendarDuration {
+    /// Convert the weeks back to 7 days each.
+    ///
+    /// Panics if the number of days overflows.
+    fn from(dur: CalendarDurationWithWeeks) -> Self {
+        CalendarDuration { years: dur.years, months: dur.months, days: dur.weeks * 7 + dur.days }
+    }
+}
+
+impl Display for CalendarDurationWithWeeks {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let components = [
+            (self.years, "year", "years"),
+            (self.months, "month", "months"),
+            (self.weeks, "week", "weeks"),
+            (self.days, "day", "days"),
+        ];
+
+        let mut any = false;
+        for (n, singular, plural) in components {
+            if n == 0 {
+                continue;
+            }
+            if any {
+                f.write_str(", ")?;
+            }
+            if n > 1 {
+                write!(f, "{n} {plural}")?;
+            } else {
+                write!(f, "1 {singular}")?;
+            }
+            any = true;
+        }
+
+        if !any {
+            f.write_str("same day")?;
+        }
+        Ok(())
+    }
+}
+
+/// Count the whole weeks and days between two dates, in either order.
+pub(crate) fn weeks_and_days_between<D: CalendarDurationExt>(a: D, b: D)
+    -> Result<CalendarDurationWithWeeks, CalendarDurationError>
+{
+    let days = u32::try_from(a.days_since(b).unsigned_abs())
+        .map_err(|_| CalendarDurationError::OutOfRange)?;
+    Ok(CalendarDuration { years: 0, months: 0, days }.with_weeks())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use alloc::string::ToString;
+    use crate::{duration_between, PlainDate};
+
+    fn weeks(years: u32, months: u32, weeks: u32, days: u32) -> CalendarDurationWithWeeks {
+        CalendarDurationWithWeeks { years, months, weeks, days }
+    }
+
+    fn date(y: i32, m: u8, d: u8) -> PlainDate {
+        PlainDate::new(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn split() {
+        let dur = |days| CalendarDuration { years: 1, months: 2, days };
+        assert_eq!((0, 0), dur(0).weeks_and_days());
+        assert_eq!((0, 6), dur(6).weeks_and_days());
+        assert_eq!((1, 0), dur(7).weeks_and_days());
+        assert_eq!((4, 2), dur(30).weeks_and_days());
+        assert_eq!(weeks(1, 2, 4, 2), dur(30).with_weeks());
+        assert_eq!(dur(30), CalendarDuration::from(dur(30).with_weeks()));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!("2 months, 1 week, 4 days", weeks(0, 2, 1, 4).to_string());
+        assert_eq!("1 year, 3 weeks", weeks(1, 0, 3, 0).to_string());
+        assert_eq!("2 weeks", weeks(0, 0, 2, 0).to_string());
+        assert_eq!("1 week, 1 day", weeks(0, 0, 1, 1).to_string());
+        assert_eq!("6 days", weeks(0, 0, 0, 6).to_string());
+        assert_eq!("31 years, 9 months", weeks(31, 9, 0, 0).to_string());
+        assert_eq!("same day", weeks(0, 0, 0, 0).to_string());
+    }
+
+    #[test]
+    fn between_dates() {
+        let due = date(2024, 10, 7);
+        let start = date(2024, 1, 1);
+        assert_eq!("9 months, 6 days", duration_between(due, start).with_weeks().to_string());
+        assert_eq!("2 months, 1 week, 4 days",
+            duration_between(date(2024, 3, 12), start).with_weeks().to_string());
+        assert_eq!(Ok(weeks(0, 0, 40, 0)), weeks_and_days_between(due, start));
+        assert_eq!("38 weeks, 2 days",
+            weeks_and_days_between(date(2024, 9, 25), start).unwrap().to_string());
+        assert_eq!("6 weeks, 3 days",
+            weeks_and_days_between(start, date(2024, 2, 15)).unwrap().to_string());
+        assert_eq!("same day", weeks_and_days_between(start, start).unwrap().to_string());
+    }
+}
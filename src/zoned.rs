@@ -0,0 +1,71 @@
+//! Calendar durations between time zone aware `chrono` date-times.
+
+use crate::{duration_between, CalendarDuration};
+use chrono::{DateTime, TimeZone};
+
+/// Compute the calendar duration between the local dates of two [`DateTime`]s. The order of the
+/// date-times doesn't matter, and their times of day are ignored.
+///
+/// Each date-time is converted to a [`NaiveDate`](chrono::NaiveDate) using its own offset, so a
+/// daylight saving time transition between them doesn't change the number of days, as it can if
+/// they're compared in UTC. If the two are in different time zones, or have different offsets
+/// from the same one, each is still on the date of its own local calendar: 23:00 on the 1st in
+/// New York and 00:00 on the 3rd in Tokyo are 2 days apart, though they're less than a day apart.
+///
+/// ```
+/// use calendar_duration::{calendar_duration_between_zoned, CalendarDuration};
+/// use chrono::DateTime;
+///
+/// let before = DateTime::parse_from_rfc3339("2024-03-09T18:30:00-05:00").unwrap();
+/// let after = DateTime::parse_from_rfc3339("2024-03-10T20:30:00-04:00").unwrap();
+/// assert_eq!(CalendarDuration { years: 0, months: 0, days: 1 },
+///     calendar_duration_between_zoned(&before, &after));
+/// ```
+pub fn calendar_duration_between_zoned<Tz: TimeZone>(a: &DateTime<Tz>, b: &DateTime<Tz>)
+    -> CalendarDuration
+{
+    duration_between(a.date_naive(), b.date_naive())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate, Utc};
+
+    fn dur(years: u32, months: u32, days: u32) -> CalendarDuration {
+        CalendarDuration { years, months, days }
+    }
+
+    fn at(offset_hours: i32, y: i32, m: u32, d: u32, h: u32, min: u32)
+        -> DateTime<FixedOffset>
+    {
+        FixedOffset::east_opt(offset_hours * 3600).unwrap()
+            .from_local_datetime(&NaiveDate::from_ymd_opt(y, m, d).unwrap()
+                .and_hms_opt(h, min, 0).unwrap())
+            .unwrap()
+    }
+
+    #[test]
+    fn spring_forward() {
+        // US Eastern time moved from UTC-5 to UTC-4 at 02:00 on 2024-03-10.
+        let before = at(-5, 2024, 3, 9, 18, 30);
+        let after = at(-4, 2024, 3, 10, 20, 30);
+        assert_eq!(dur(0, 0, 1), calendar_duration_between_zoned(&before, &after));
+        assert_eq!(dur(0, 0, 1), calendar_duration_between_zoned(&after, &before));
+
+        // In UTC these are on the 9th and the 11th.
+        let (before, after) = (before.with_timezone(&Utc), after.with_timezone(&Utc));
+        assert_eq!(dur(0, 0, 2), calendar_duration_between_zoned(&before, &after));
+    }
+
+    #[test]
+    fn different_offsets() {
+        let new_york = at(-4, 2024, 5, 1, 23, 0);
+        let tokyo = at(9, 2024, 5, 3, 0, 0);
+        assert_eq!(12 * 3600, (tokyo - new_york).num_seconds());
+        assert_eq!(dur(0, 0, 2), calendar_duration_between_zoned(&new_york, &tokyo));
+
+        let sydney = at(10, 2025, 6, 30, 8, 0);
+        assert_eq!(dur(1, 1, 29), calendar_duration_between_zoned(&sydney, &new_york));
+    }
+}